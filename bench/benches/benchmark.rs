@@ -1,5 +1,8 @@
 use ::std::hint::black_box;
-use alphabet_detector::{ch_norm, fulltext, fulltext_filter_max, fulltext_filter_with_margin};
+use alphabet_detector::{
+    ch_norm, fulltext, fulltext_counts_filter_with_margin, fulltext_filter_max,
+    fulltext_filter_with_margin,
+};
 use criterion::{criterion_group, criterion_main, Criterion};
 
 const SENTENCES: &[&str] = &[
@@ -40,6 +43,14 @@ fn benchmark(c: &mut Criterion) {
             });
         });
     });
+    group1.bench_function("fulltext_counts_filter_with_margin", |bencher| {
+        bencher.iter(|| {
+            SENTENCES.iter().for_each(|sentence| {
+                let data = fulltext_counts_filter_with_margin::<95>(sentence.char_indices());
+                let _ = black_box(data);
+            });
+        });
+    });
     group1.bench_function("fulltext_filter_max", |bencher| {
         bencher.iter(|| {
             SENTENCES.iter().for_each(|sentence| {
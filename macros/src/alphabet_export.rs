@@ -0,0 +1,263 @@
+use crate::helper::letter_tier;
+use ahash::AHashSet;
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::collections::BTreeMap;
+use syn::{
+    spanned::Spanned, Attribute, Error, Expr, ExprArray, ExprMatch, ExprTuple, ItemFn, Lit, LitStr,
+    Stmt,
+};
+
+const LEVELS: &[&str] = &[
+    "Unverified",
+    "Internet",
+    "FrequencyAnalysis",
+    "NativeSpeaker",
+];
+
+struct AlphabetTable {
+    key: Expr,
+    chars: Vec<char>,
+    /// Not core letters, with `LetterTier` variant name
+    tiered: Vec<(char, &'static str)>,
+    verification: Ident,
+    /// `Level?`
+    doubtful: bool,
+    sources: Vec<LitStr>,
+}
+
+/// `Level` or `Level?` (doubtful)
+fn parse_verification(attr: &Attribute) -> syn::Result<(Ident, bool)> {
+    let tokens: Vec<TokenTree> = attr
+        .meta
+        .require_list()?
+        .tokens
+        .clone()
+        .into_iter()
+        .collect();
+    let (level, doubtful) = match tokens.as_slice() {
+        [TokenTree::Ident(level)] => (level, false),
+        [TokenTree::Ident(level), TokenTree::Punct(p)] if p.as_char() == '?' => (level, true),
+        _ => {
+            return Err(Error::new(
+                attr.span(),
+                "Expected `#[verification(Level)]` or `#[verification(Level?)]`",
+            ))
+        }
+    };
+    if !LEVELS.iter().any(|l| level == l) {
+        return Err(Error::new(
+            level.span(),
+            format!("Unknown verification level, expected one of: {LEVELS:?}"),
+        ));
+    }
+    Ok((level.clone(), doubtful))
+}
+
+/// Collects tables of an `alphabet_match!` input, skipping unknown (path) keys and empty tables.
+fn alphabet_match_tables(input: ExprArray) -> syn::Result<Vec<AlphabetTable>> {
+    let mut res = Vec::new();
+    for tuple in input.elems {
+        let Expr::Tuple(ExprTuple { attrs, elems, .. }) = tuple else {
+            continue;
+        };
+        let mut verification = None;
+        let mut sources = Vec::new();
+        for attr in &attrs {
+            if attr.path().is_ident("verification") {
+                verification = Some(parse_verification(attr)?);
+            } else if attr.path().is_ident("source") {
+                sources.push(attr.parse_args::<LitStr>()?);
+            } else {
+                return Err(Error::new(attr.span(), "Unexpected attribute"));
+            }
+        }
+        let Expr::Array(ExprArray { elems: values, .. }) = &elems[1] else {
+            return Err(Error::new(elems[1].span(), "Expected an array of values"));
+        };
+        let mut tier = "Core";
+        let mut chars = Vec::with_capacity(values.len());
+        let mut tiered = Vec::new();
+        for val in values {
+            if let Some(t) = letter_tier(val) {
+                tier = t;
+                continue;
+            }
+            let ch = match val {
+                Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    Lit::Char(lit_char) => lit_char.value(),
+                    v => return Err(Error::new(v.span(), "Expected char literal for value")),
+                },
+                v => return Err(Error::new(v.span(), "Expected char literal for value")),
+            };
+            chars.push(ch);
+            if tier != "Core" {
+                tiered.push((ch, tier));
+            }
+        }
+        if chars.is_empty() {
+            continue;
+        }
+        let (verification, doubtful) = verification.ok_or_else(|| {
+            Error::new(
+                elems[0].span(),
+                "No `#[verification(Level)]` provided for the alphabet",
+            )
+        })?;
+        res.push(AlphabetTable {
+            key: elems[0].clone(),
+            chars,
+            tiered,
+            verification,
+            doubtful,
+            sources,
+        });
+    }
+    Ok(res)
+}
+
+pub(super) fn alphabet_export_inner(item: ItemFn) -> syn::Result<TokenStream> {
+    let Some(Stmt::Expr(Expr::Match(ExprMatch { arms, .. }), _)) = item.block.stmts.last() else {
+        return Err(Error::new(
+            item.sig.ident.span(),
+            "Expected a function ending with `match script`",
+        ));
+    };
+
+    let mut keys = AHashSet::new();
+    let mut match_to_alphabet = Vec::new();
+    let mut match_to_verification = Vec::new();
+    let mut doubtful_keys = Vec::new();
+    let mut match_to_sources = Vec::new();
+    let mut match_to_tiered = Vec::new();
+    for arm in arms {
+        let Expr::Macro(mac) = &*arm.body else {
+            continue;
+        };
+        if !mac.mac.path.is_ident("alphabet_match") {
+            continue;
+        }
+        let cfgs: Vec<&Attribute> = arm
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("cfg"))
+            .collect();
+        let mut char_to_tiered: BTreeMap<char, Vec<TokenStream>> = BTreeMap::new();
+        for AlphabetTable {
+            key,
+            chars,
+            tiered,
+            verification,
+            doubtful,
+            sources,
+        } in alphabet_match_tables(mac.mac.parse_body()?)?
+        {
+            for (ch, tier) in tiered {
+                let tier = Ident::new(tier, Span::call_site());
+                char_to_tiered
+                    .entry(ch)
+                    .or_default()
+                    .push(quote! { (#key, LetterTier::#tier) });
+            }
+            if !keys.insert(key.to_token_stream().to_string()) {
+                return Err(Error::new(key.span(), "Alphabet was already exported"));
+            }
+            match_to_alphabet.push(quote! {
+                #(#cfgs)*
+                #key => ::core::option::Option::Some(&[#(#chars),*]),
+            });
+            match_to_verification.push(quote! {
+                #(#cfgs)*
+                #key => Verification::#verification,
+            });
+            if doubtful {
+                doubtful_keys.push(quote! {
+                    #(#cfgs)*
+                    #key => true,
+                });
+            }
+            if !sources.is_empty() {
+                match_to_sources.push(quote! {
+                    #(#cfgs)*
+                    #key => &[#(#sources),*],
+                });
+            }
+        }
+        if !char_to_tiered.is_empty() {
+            let pat = &arm.pat;
+            let arms = char_to_tiered
+                .iter()
+                .map(|(ch, slangs)| quote! { #ch => &[#(#slangs),*], });
+            match_to_tiered.push(quote! {
+                #(#cfgs)*
+                #pat => match ch {
+                    #(#arms)*
+                    _ => &[],
+                },
+            });
+        }
+    }
+
+    Ok(quote! {
+        #item
+
+        /// `ScriptLanguage`s, for which the char is not a core letter, with its `LetterTier`.
+        ///
+        /// These `ScriptLanguage`s are not returned by `script_char_to_slangs`, the letters
+        /// are counted only by `WordIterator::tier_weighted`.
+        pub fn script_char_to_tiered_slangs(
+            script: UcdScript,
+            ch: char,
+        ) -> &'static [(ScriptLanguage, LetterTier)] {
+            use UcdScript::*;
+            #[allow(unreachable_patterns)]
+            match script {
+                #(#match_to_tiered)*
+                _ => &[],
+            }
+        }
+
+        impl ScriptLanguage {
+            /// Letters (both cases) listed for this `ScriptLanguage`, in the listed order.
+            ///
+            /// `None`, if the letters are not listed, so any letter of the script
+            /// detects it ([`alphabet_contains`](#method.alphabet_contains)).
+            pub const fn alphabet(self) -> ::core::option::Option<&'static [char]> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#match_to_alphabet)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// How the [`alphabet`](#method.alphabet) was verified.
+            ///
+            /// `WholeScript`, if the alphabet is not listed.
+            pub const fn alphabet_verification(self) -> Verification {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#match_to_verification)*
+                    _ => Verification::WholeScript,
+                }
+            }
+
+            /// The [`alphabet_verification`](#method.alphabet_verification) level is doubtful.
+            pub const fn alphabet_verification_doubtful(self) -> bool {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#doubtful_keys)*
+                    _ => false,
+                }
+            }
+
+            /// Source URLs of the [`alphabet`](#method.alphabet).
+            pub const fn alphabet_sources(self) -> &'static [&'static str] {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#match_to_sources)*
+                    _ => &[],
+                }
+            }
+        }
+    })
+}
@@ -1,3 +1,4 @@
+use crate::helper::letter_tier;
 use ahash::AHashMap;
 #[cfg(debug_assertions)]
 use ahash::AHashSet;
@@ -6,6 +7,8 @@ use syn::{spanned::Spanned, Error, Expr, ExprArray, ExprTuple, Lit};
 
 pub(super) fn alphabet_match_inner(input: ExprArray) -> syn::Result<proc_macro2::TokenStream> {
     let mut value_to_keys = AHashMap::new();
+    // extended and loanword letters, looked up separately by `script_char_to_tiered_slangs`
+    let mut tiered_chars = Vec::new();
 
     let mut keys_all = Vec::new();
     let mut unknown_lang = None;
@@ -26,7 +29,12 @@ pub(super) fn alphabet_match_inner(input: ExprArray) -> syn::Result<proc_macro2:
                         #[cfg(debug_assertions)]
                         let mut values_set: AHashSet<char> = AHashSet::new();
 
+                        let mut tiered = false;
                         for val in values {
+                            if let Some(tier) = letter_tier(val) {
+                                tiered = tier != "Core";
+                                continue;
+                            }
                             let value = match val {
                                 Expr::Lit(expr_lit) => match &expr_lit.lit {
                                     Lit::Char(lit_char) => lit_char.value(),
@@ -58,6 +66,10 @@ pub(super) fn alphabet_match_inner(input: ExprArray) -> syn::Result<proc_macro2:
                                 }
                             }
 
+                            if tiered {
+                                tiered_chars.push(value);
+                                continue;
+                            }
                             value_to_keys
                                 .entry(value)
                                 .or_insert_with(Vec::new)
@@ -101,15 +113,20 @@ pub(super) fn alphabet_match_inner(input: ExprArray) -> syn::Result<proc_macro2:
             quote! { #(#chars_other)|* => &[#(#keys_all),*], }
         };
 
+        // combining marks (matras, points) are shared by the alphabets, not unknown letters
         quote! {
             #others
+            ch if ::unicode_normalization::char::is_combining_mark(ch) => &[#(#keys_all),*],
             _ => &[#l]
         }
     } else {
         quote! { _ => &[#(#keys_all),*] }
     };
 
-    let chars = value_to_keys.keys().map(|c| quote! { #c });
+    let chars = value_to_keys
+        .keys()
+        .chain(tiered_chars.iter())
+        .map(|c| quote! { #c });
     // Generate the entire match block
     let expanded = quote! {{
         #[cfg(all(debug_assertions, feature = "test_chars"))]
@@ -10,3 +10,32 @@ pub(crate) fn skip_eq(i: &Ident, tt_iter: &mut impl Iterator<Item = TokenTree>)
         None => Err(syn::Error::new_spanned(i, "expected: equal sign \"=\"")),
     }
 }
+
+/// "ArabicEgyptian" -> "Arabic Egyptian"
+pub(crate) fn ident_to_words(ident: &str) -> String {
+    let mut res = String::with_capacity(ident.len() + 4);
+    for ch in ident.chars() {
+        if ch.is_uppercase() && !res.is_empty() {
+            res.push(' ');
+        }
+        res.push(ch);
+    }
+    res
+}
+
+/// `core`, `extended` or `loanword` marker in an alphabet, returns `LetterTier` variant name.
+pub(crate) fn letter_tier(expr: &syn::Expr) -> Option<&'static str> {
+    let syn::Expr::Path(p) = expr else {
+        return None;
+    };
+    let ident = p.path.get_ident()?;
+    if ident == "core" {
+        Some("Core")
+    } else if ident == "extended" {
+        Some("Extended")
+    } else if ident == "loanword" {
+        Some("Loanword")
+    } else {
+        None
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::helper::skip_eq;
+use crate::helper::{ident_to_words, skip_eq};
 use proc_macro2::{Literal, Span, TokenTree};
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Fields, Meta};
@@ -22,6 +22,8 @@ pub(super) fn language_derive_inner(input: DeriveInput) -> syn::Result<proc_macr
 
     let mut match_to_str = Vec::with_capacity(variants.len());
     let mut match_to_2letter = Vec::with_capacity(variants.len());
+    let mut match_to_english_name = Vec::with_capacity(variants.len());
+    let mut match_to_native_name = Vec::with_capacity(variants.len());
     let mut match_to_code = Vec::with_capacity(variants.len());
     let mut match_from_code = Vec::with_capacity(variants.len() + variants.len() / 2);
     let mut match_from_bytes = Vec::with_capacity(variants.len() + variants.len() / 2);
@@ -32,6 +34,8 @@ pub(super) fn language_derive_inner(input: DeriveInput) -> syn::Result<proc_macr
         let mut short = None;
         let mut old_shorts = Vec::new();
         let mut shortest = None;
+        let mut english_name = None;
+        let mut native_name = None;
 
         let tokens = variant
             .attrs
@@ -103,6 +107,25 @@ pub(super) fn language_derive_inner(input: DeriveInput) -> syn::Result<proc_macr
                                 }
                             }
                         }
+                        "name" | "native" => {
+                            skip_eq(&i, &mut tokens)?;
+                            match tokens.next() {
+                                Some(TokenTree::Literal(v)) => {
+                                    if i == "name" {
+                                        english_name = Some(v);
+                                    } else {
+                                        native_name = Some(v);
+                                    }
+                                }
+                                Some(tt) => {
+                                    return Err(Error::new(
+                                        tt.span(),
+                                        format!("Unexpected \"{tt}\""),
+                                    ))
+                                }
+                                _ => return Err(Error::new(i.span(), "No name provided")),
+                            }
+                        }
                         "shortest" => {
                             skip_eq(&i, &mut tokens)?;
                             match tokens.next() {
@@ -201,6 +224,17 @@ pub(super) fn language_derive_inner(input: DeriveInput) -> syn::Result<proc_macr
                 #name::#ident #params => ::core::option::Option::Some(#s)
             });
         }
+        let english_name =
+            english_name.unwrap_or_else(|| Literal::string(&ident_to_words(&ident.to_string())));
+        match_to_english_name.push(quote! {
+            #name::#ident #params => #english_name
+        });
+        if let Some(native_name) = native_name {
+            match_to_native_name.push(quote! {
+                #name::#ident #params => ::core::option::Option::Some(#native_name)
+            });
+        }
+
         str_variants.push(quote! { #short });
     }
 
@@ -233,6 +267,21 @@ pub(super) fn language_derive_inner(input: DeriveInput) -> syn::Result<proc_macr
                     _ => None
                 }
             }
+            /// English name
+            #[inline]
+            pub const fn english_name(self) -> &'static str {
+                match self {
+                    #(#match_to_english_name),*
+                }
+            }
+            /// Native name (autonym)
+            #[inline]
+            pub const fn native_name(self) -> Option<&'static str> {
+                match self {
+                    #(#match_to_native_name,)*
+                    _ => None
+                }
+            }
             /// 20-bit code (compacted ISO 639-3 code)
             #[inline]
             pub const fn from_code(v: u32) -> Option<Self> {
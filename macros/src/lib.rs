@@ -1,11 +1,14 @@
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+mod alphabet_export;
 mod alphabet_match;
 mod helper;
 mod language_derive;
 mod script_derive;
 mod script_lang_derive;
+mod slang_features;
+mod slang_unknown;
 
 #[proc_macro]
 pub fn alphabet_match(input: TokenStream) -> TokenStream {
@@ -16,6 +19,19 @@ pub fn alphabet_match(input: TokenStream) -> TokenStream {
     TokenStream::from(tokens)
 }
 
+/// Generates from all `alphabet_match!` tables of the function's last `match` expression:
+/// `script_char_to_tiered_slangs()` (extended and loanword letters) and the `ScriptLanguage`
+/// methods `alphabet()`, `alphabet_verification()` (`#[verification(Level)]` of each table),
+/// `alphabet_verification_doubtful()` (`Level?`) and `alphabet_sources()` (`#[source(url)]`s).
+#[proc_macro_attribute]
+pub fn alphabet_export(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item);
+    let tokens =
+        alphabet_export::alphabet_export_inner(item).unwrap_or_else(|err| err.to_compile_error());
+
+    TokenStream::from(tokens)
+}
+
 /// only to use rust-analyzer expansion
 #[test]
 fn expand() {
@@ -23,6 +39,28 @@ fn expand() {
     alphabet_match!([(SomeEnum::A, ['a', 'b', 'c']), (SomeEnum::B, ['a', 'c']),]);
 }
 
+/// Gates every `ScriptLanguage` variant behind the cargo feature
+/// of its `#[slang(script = ..)]` (like `script_old_italic`).
+#[proc_macro_attribute]
+pub fn slang_features(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item);
+    let tokens =
+        slang_features::slang_features_inner(item).unwrap_or_else(|err| err.to_compile_error());
+
+    TokenStream::from(tokens)
+}
+
+/// Generates `Unknown<Script>` variant for every listed script,
+/// must be placed before `#[slang_features]`.
+#[proc_macro_attribute]
+pub fn slang_unknown(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item);
+    let tokens = slang_unknown::slang_unknown_inner(attr.into(), item)
+        .unwrap_or_else(|err| err.to_compile_error());
+
+    TokenStream::from(tokens)
+}
+
 #[proc_macro_derive(ScriptLanguage, attributes(slang))]
 pub fn script_lang_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
@@ -1,5 +1,8 @@
-use crate::helper::skip_eq;
-use proc_macro2::{Span, TokenTree};
+use crate::{
+    helper::{ident_to_words, skip_eq},
+    slang_features::script_cfg,
+};
+use proc_macro2::{Literal, Span, TokenTree};
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Fields, Meta};
 
@@ -20,14 +23,17 @@ pub(super) fn script_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2
 
     let mut match_to_str = Vec::with_capacity(variants.len());
     let mut match_to_code = Vec::with_capacity(variants.len());
+    let mut match_to_english_name = Vec::with_capacity(variants.len());
     let mut match_from_code = Vec::with_capacity(variants.len() + 1);
     let mut match_from_bytes = Vec::with_capacity(variants.len() + 1);
     let mut str_variants = Vec::with_capacity(variants.len());
+    let mut match_to_enabled = Vec::with_capacity(variants.len());
 
     for variant in variants {
         let ident = variant.ident;
         let mut short = None;
         let mut code = None;
+        let mut english_name = None;
 
         let tokens = variant
             .attrs
@@ -73,6 +79,21 @@ pub(super) fn script_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2
                                 _ => return Err(Error::new(i.span(), "No code provided")),
                             }
                         }
+                        "name" => {
+                            skip_eq(&i, &mut tokens)?;
+                            match tokens.next() {
+                                Some(TokenTree::Literal(v)) => {
+                                    english_name = Some(v);
+                                }
+                                Some(tt) => {
+                                    return Err(Error::new(
+                                        tt.span(),
+                                        format!("Unexpected \"{tt}\""),
+                                    ))
+                                }
+                                _ => return Err(Error::new(i.span(), "No name provided")),
+                            }
+                        }
                         v => {
                             return Err(Error::new(i.span(), format!("Unexpected \"{v}\"")));
                         }
@@ -111,7 +132,21 @@ pub(super) fn script_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2
         match_from_bytes.push(quote! {
             v if ::concat_const::eq_bytes(v, #short.as_bytes()) => ::core::option::Option::Some(#name::#ident #params)
         });
+        let english_name =
+            english_name.unwrap_or_else(|| Literal::string(&ident_to_words(&ident.to_string())));
+        match_to_english_name.push(quote! {
+            #name::#ident #params => #english_name
+        });
         str_variants.push(quote! { #short });
+        // `Common` and `Inherited` chars are shared by all scripts
+        let ident_str = ident.to_string();
+        if ident_str != "Common" && ident_str != "Inherited" {
+            let cfg = script_cfg(&ident_str);
+            match_to_enabled.push(quote! {
+                #cfg
+                #name::#ident #params => true,
+            });
+        }
     }
 
     match_from_code.push(quote! { _ => ::core::option::Option::None });
@@ -135,6 +170,23 @@ pub(super) fn script_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2
                     #(#match_to_str),*
                 }
             }
+            /// English name
+            #[inline]
+            pub const fn english_name(self) -> &'static str {
+                match self {
+                    #(#match_to_english_name),*
+                }
+            }
+            /// Is enabled by its `script_*` cargo feature, `Common` and `Inherited` always are
+            #[inline]
+            pub const fn enabled(self) -> bool {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#match_to_enabled)*
+                    #name::Common | #name::Inherited => true,
+                    _ => false,
+                }
+            }
             /// 10-bit code (ISO 15924 number)
             #[inline]
             pub const fn from_code(v: u16) -> Option<Self> {
@@ -26,12 +26,14 @@ pub(super) fn script_lang_derive_inner(
     let mut match_to_parts = Vec::with_capacity(variants.len());
     let mut match_to_str = Vec::with_capacity(variants.len());
     let mut match_to_code = Vec::with_capacity(variants.len());
+    let mut match_to_display_name = Vec::with_capacity(variants.len());
     let mut match_from_code = Vec::with_capacity(variants.len() + 1);
     let mut match_from_parts = Vec::with_capacity(variants.len() + 1);
     let mut match_from_bytes = Vec::with_capacity(variants.len() + 1);
     let mut str_variants = Vec::with_capacity(variants.len());
     let mut lang_to_script_langs: AHashMap<String, Vec<_>> =
         AHashMap::with_capacity(variants.len());
+    let mut match_to_unknown = Vec::new();
 
     for variant in variants {
         let ident = variant.ident;
@@ -101,7 +103,7 @@ pub(super) fn script_lang_derive_inner(
         };
 
         lang_to_script_langs
-            .entry(language)
+            .entry(language.clone())
             .or_default()
             .push(quote! { #name::#ident #params });
 
@@ -110,6 +112,11 @@ pub(super) fn script_lang_derive_inner(
         });
 
         let script = script.ok_or_else(|| Error::new(ident.span(), "No script provided"))?;
+        if language == "Unknown" {
+            match_to_unknown.push(quote! {
+                Script::#script => ::core::option::Option::Some(#name::#ident #params)
+            });
+        }
         match_to_script.push(quote! {
             #name::#ident #params => Script::#script
         });
@@ -122,6 +129,14 @@ pub(super) fn script_lang_derive_inner(
                 Script::#script.into_str()
             )
         });
+        match_to_display_name.push(quote! {
+            #name::#ident #params => ::concat_const::concat!(
+                Language::#lang.english_name(),
+                " (",
+                Script::#script.english_name(),
+                ")"
+            )
+        });
         match_to_code.push(quote! {
             #name::#ident #params => (Language::#lang.into_code() << 10) | Script::#script.into_code() as u32
         });
@@ -149,6 +164,7 @@ pub(super) fn script_lang_derive_inner(
         let l = Ident::new(&lang, Span::call_site());
         quote! { Language::#l => &[#(#v),*] }
     });
+    match_to_unknown.push(quote! { _ => ::core::option::Option::None });
     match_from_code.push(quote! { _ => ::core::option::Option::None });
     match_from_parts.push(quote! { _ => ::core::option::Option::None });
     match_from_bytes.push(quote! { _ => ::core::option::Option::None });
@@ -165,8 +181,10 @@ pub(super) fn script_lang_derive_inner(
         impl #impl_generics From<Language> for &'static [#name #ty_generics] #where_clause {
             #[inline]
             fn from(l: Language) -> Self {
+                #[allow(unreachable_patterns)]
                 match l {
-                    #(#match_lang_to_script_langs),*
+                    #(#match_lang_to_script_langs,)*
+                    _ => &[],
                 }
             }
         }
@@ -194,6 +212,13 @@ pub(super) fn script_lang_derive_inner(
                     #(#match_to_str),*
                 }
             }
+            /// English names of the language and the script: "Serbian (Cyrillic)"
+            #[inline]
+            pub const fn display_name(self) -> &'static str {
+                match self {
+                    #(#match_to_display_name),*
+                }
+            }
             /// 30-bit code (compacted ISO 639-3 code, ISO 15924 number)
             #[inline]
             pub const fn into_code(self) -> u32 {
@@ -208,6 +233,13 @@ pub(super) fn script_lang_derive_inner(
                     #(#match_from_code),*
                 }
             }
+            /// `Language::Unknown` variant of the script, exists if unclaimed letters of the script have a fallback
+            #[inline]
+            pub const fn unknown(script: Script) -> Option<Self> {
+                match script {
+                    #(#match_to_unknown),*
+                }
+            }
             #[inline]
             pub const fn from_parts(v: (Language, Script)) -> Option<Self> {
                 match v {
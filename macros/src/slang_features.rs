@@ -0,0 +1,71 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{parse_quote, Attribute, Error, ItemEnum, Meta};
+
+const NAME: &str = "slang";
+
+/// "OldItalic" -> "script_old_italic"
+fn script_feature(script: &str) -> String {
+    let mut res = String::from("script");
+    for ch in script.chars() {
+        if ch.is_uppercase() {
+            res.push('_');
+        }
+        res.push(ch.to_ascii_lowercase());
+    }
+    res
+}
+
+/// `cfg` of the script, some scripts (`Script`, not `UcdScript`) are written in multiple `UcdScript`s
+pub(super) fn script_cfg(script: &str) -> Attribute {
+    match script {
+        "Han" | "HanSimplified" | "HanTraditional" => parse_quote! {
+            #[cfg(feature = "script_han")]
+        },
+        "Japanese" => parse_quote! {
+            #[cfg(any(
+                feature = "script_han",
+                feature = "script_hiragana",
+                feature = "script_katakana"
+            ))]
+        },
+        "Korean" => parse_quote! {
+            #[cfg(any(feature = "script_han", feature = "script_hangul"))]
+        },
+        script => {
+            let feature = script_feature(script);
+            parse_quote! { #[cfg(feature = #feature)] }
+        }
+    }
+}
+
+/// Script of `#[slang(script = Script, ..)]`
+pub(super) fn slang_script(attrs: &[Attribute]) -> Option<String> {
+    let Meta::List(list) = &attrs.iter().find(|a| a.path().is_ident(NAME))?.meta else {
+        return None;
+    };
+    let mut tokens = list.tokens.clone().into_iter();
+    while let Some(tt) = tokens.next() {
+        if matches!(&tt, TokenTree::Ident(i) if i == "script") {
+            tokens.next(); // =
+            if let Some(TokenTree::Ident(script)) = tokens.next() {
+                return Some(script.to_string());
+            }
+        }
+    }
+    None
+}
+
+pub(super) fn slang_features_inner(mut item: ItemEnum) -> syn::Result<TokenStream> {
+    for variant in item.variants.iter_mut() {
+        let Some(script) = slang_script(&variant.attrs) else {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "No `#[slang(script = ..)]` provided",
+            ));
+        };
+        variant.attrs.insert(0, script_cfg(&script));
+    }
+
+    Ok(quote! { #item })
+}
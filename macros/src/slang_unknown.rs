@@ -0,0 +1,39 @@
+use crate::slang_features::slang_script;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse::Parser, parse_quote, punctuated::Punctuated, Error, ItemEnum, Token, Variant};
+
+/// Inserts `Unknown<Script>` variant after the last variant of every listed script
+pub(super) fn slang_unknown_inner(
+    attr: TokenStream,
+    mut item: ItemEnum,
+) -> syn::Result<TokenStream> {
+    let scripts = Punctuated::<Ident, Token![,]>::parse_terminated.parse2(attr)?;
+
+    for script in scripts {
+        let name = script.to_string();
+        let Some(pos) = item
+            .variants
+            .iter()
+            .rposition(|v| slang_script(&v.attrs).as_deref() == Some(name.as_str()))
+        else {
+            return Err(Error::new_spanned(
+                &script,
+                format!("No variants of script {name}"),
+            ));
+        };
+
+        let doc = format!(" Letters of the {name} script, which no alphabet claims");
+        let ident = Ident::new(&format!("Unknown{name}"), Span::call_site());
+        let variant: Variant = parse_quote! {
+            #[doc = #doc]
+            #[slang(script = #script, lang = Unknown)]
+            #ident
+        };
+        let mut variants = item.variants.into_iter().collect::<Vec<_>>();
+        variants.insert(pos + 1, variant);
+        item.variants = variants.into_iter().collect();
+    }
+
+    Ok(quote! { #item })
+}
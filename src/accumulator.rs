@@ -0,0 +1,211 @@
+use crate::{
+    filter_max, filter_with_margin, filter_with_margin_sorted, slang_arr_default,
+    words::{self, WordBuf},
+    ScriptLanguage, ScriptLanguageArr, Word,
+};
+use ::core::ops::{Add, AddAssign};
+use debug_unsafe::slice::SliceGetter;
+use serde::{Deserialize, Serialize};
+
+/// Incremental summing up of words detection, can be queried at any point.
+///
+/// Can be fed by chunks of text or by [`Word`](words/struct.Word.html)s,
+/// and merged with other accumulators (`+=`), e.g. in map-reduce jobs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulltextAccumulator {
+    words_count: u64,
+    /// Only non zero counts are serialized.
+    #[serde(with = "langs_count_serde")]
+    langs_count: ScriptLanguageArr<u32>,
+}
+
+impl Default for FulltextAccumulator {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            words_count: 0,
+            langs_count: slang_arr_default(),
+        }
+    }
+}
+
+impl FulltextAccumulator {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Splits the chunk to words, and adds them.
+    ///
+    /// Chunks must be split at word boundaries (e.g. at whitespace),
+    /// otherwise a word on the border will be counted as two words.
+    pub fn feed(&mut self, char_indices: impl Iterator<Item = (usize, char)>) {
+        for word in words::from_ch_ind::<bool>(char_indices) {
+            self.add_word(&word);
+        }
+    }
+
+    #[inline]
+    pub fn add_word<B: WordBuf>(&mut self, word: &Word<B>) {
+        self.add_langs_count(&word.langs_cnt);
+        self.words_count += 1;
+    }
+
+    /// Counts saturate at `u32::MAX`, so merging large accumulators doesn't overflow.
+    #[inline]
+    pub fn add_langs_count(&mut self, langs_cnt: &ScriptLanguageArr<u32>) {
+        for (lang, &cnt) in langs_cnt.iter().enumerate() {
+            let acc_cnt = self.langs_count.get_safe_unchecked_mut(lang);
+            *acc_cnt = acc_cnt.saturating_add(cnt);
+        }
+    }
+
+    /// Merges `other` into `self`, same as `+=`.
+    #[inline]
+    pub fn merge(&mut self, other: &Self) {
+        self.add_langs_count(&other.langs_count);
+        self.words_count += other.words_count;
+    }
+
+    /// Resets to zero, without a reallocation.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.langs_count.fill(0);
+        self.words_count = 0;
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words_count == 0
+    }
+
+    #[inline]
+    pub fn words_count(&self) -> u64 {
+        self.words_count
+    }
+
+    #[inline]
+    pub fn langs_count(&self) -> &ScriptLanguageArr<u32> {
+        &self.langs_count
+    }
+
+    #[inline]
+    pub fn into_langs_count(self) -> ScriptLanguageArr<u32> {
+        self.langs_count
+    }
+
+    /// See [`filter_max`](fn.filter_max.html).
+    #[inline]
+    pub fn filter_max(&self) -> (impl Iterator<Item = ScriptLanguage> + Clone, u32) {
+        filter_max(self.langs_count)
+    }
+
+    /// See [`filter_with_margin`](fn.filter_with_margin.html).
+    #[inline]
+    pub fn filter_with_margin<const PERCENT: u32>(
+        &self,
+    ) -> (impl Iterator<Item = (ScriptLanguage, u32)> + Clone, u32) {
+        filter_with_margin::<PERCENT>(self.langs_count)
+    }
+
+    /// See [`filter_with_margin_sorted`](fn.filter_with_margin_sorted.html).
+    #[inline]
+    pub fn filter_with_margin_sorted<const PERCENT: u32>(
+        &self,
+    ) -> (Vec<(ScriptLanguage, u32)>, u32) {
+        filter_with_margin_sorted::<PERCENT>(self.langs_count)
+    }
+}
+
+impl<B: WordBuf> Extend<Word<B>> for FulltextAccumulator {
+    #[inline]
+    fn extend<T: IntoIterator<Item = Word<B>>>(&mut self, iter: T) {
+        for word in iter {
+            self.add_word(&word);
+        }
+    }
+}
+
+impl<'a, B: WordBuf> Extend<&'a Word<B>> for FulltextAccumulator {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a Word<B>>>(&mut self, iter: T) {
+        for word in iter {
+            self.add_word(word);
+        }
+    }
+}
+
+impl AddAssign<&FulltextAccumulator> for FulltextAccumulator {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.merge(other);
+    }
+}
+
+impl AddAssign for FulltextAccumulator {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.merge(&other);
+    }
+}
+
+impl Add for FulltextAccumulator {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: Self) -> Self {
+        self.merge(&other);
+        self
+    }
+}
+
+/// `ScriptLanguageArr<u32>` as a map of non zero counts: `{"engLatn": 5}`
+mod langs_count_serde {
+    use crate::{slang_arr_default, ScriptLanguage, ScriptLanguageArr};
+    use debug_unsafe::slice::SliceGetter;
+    use serde::{
+        de::{MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserializer, Serializer,
+    };
+    use strum::IntoEnumIterator;
+
+    pub(super) fn serialize<S: Serializer>(
+        langs_cnt: &ScriptLanguageArr<u32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let len = langs_cnt.iter().filter(|&&cnt| cnt > 0).count();
+        let mut map = serializer.serialize_map(Some(len))?;
+        for (lang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+            if cnt > 0 {
+                map.serialize_entry(&lang, &cnt)?;
+            }
+        }
+        map.end()
+    }
+
+    struct LangsCountVisitor;
+
+    impl<'de> Visitor<'de> for LangsCountVisitor {
+        type Value = ScriptLanguageArr<u32>;
+
+        fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            formatter.write_str("map of ScriptLanguage to count")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut langs_cnt: ScriptLanguageArr<u32> = slang_arr_default();
+            while let Some((lang, cnt)) = access.next_entry::<ScriptLanguage, u32>()? {
+                let acc_cnt = langs_cnt.get_safe_unchecked_mut(lang as usize);
+                *acc_cnt = acc_cnt.saturating_add(cnt);
+            }
+            Ok(langs_cnt)
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ScriptLanguageArr<u32>, D::Error> {
+        deserializer.deserialize_map(LangsCountVisitor)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    Language, LanguageArr, Script, ScriptArr, ScriptLanguage, ScriptLanguageArr, UcdScript,
+    UcdScriptArr,
+};
+use debug_unsafe::slice::SliceGetter;
+use strum::{EnumCount, IntoEnumIterator};
+
+// One letter is counted for every `ScriptLanguage` which alphabet contains it,
+// so counts of the same `UcdScript` are never summed up, only the max is taken.
+
+/// Counts per `UcdScript` (max count of its `ScriptLanguage`s).
+pub fn slangs_count_by_ucd_script(langs_cnt: &ScriptLanguageArr<u32>) -> UcdScriptArr<u32> {
+    let mut res = [0; UcdScript::COUNT];
+    for (slang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+        let script_cnt = res.get_safe_unchecked_mut(UcdScript::from(slang) as usize);
+        *script_cnt = (*script_cnt).max(cnt);
+    }
+    res
+}
+
+/// Counts per `Script` (max count of its `ScriptLanguage`s).
+pub fn slangs_count_by_script(langs_cnt: &ScriptLanguageArr<u32>) -> ScriptArr<u32> {
+    let mut res = [0; Script::COUNT];
+    for (slang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+        let script_cnt = res.get_safe_unchecked_mut(Script::from(slang) as usize);
+        *script_cnt = (*script_cnt).max(cnt);
+    }
+    res
+}
+
+/// Count of a `Language` within one `UcdScript` (max count of its `ScriptLanguage`s).
+#[inline]
+fn language_count_in_script(
+    langs_cnt: &ScriptLanguageArr<u32>,
+    slangs: &[ScriptLanguage],
+    script: UcdScript,
+) -> u32 {
+    slangs
+        .iter()
+        .filter(|&&sl| UcdScript::from(sl) == script)
+        .map(|&sl| *langs_cnt.get_safe_unchecked(sl as usize))
+        .max()
+        .unwrap_or_default()
+}
+
+/// Counts per `Language` (using [`into_parts`](enum.ScriptLanguage.html#method.into_parts)).
+///
+/// `ScriptLanguage`s of different `UcdScript`s (like Cyrillic and Latin) are summed up,
+/// `ScriptLanguage`s of the same `UcdScript` (like simplified and traditional Han) are not.
+pub fn slangs_count_by_language(langs_cnt: &ScriptLanguageArr<u32>) -> LanguageArr<u32> {
+    let mut res = [0; Language::COUNT];
+    for (slang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+        if cnt == 0 {
+            continue;
+        }
+        let (lang, _) = slang.into_parts();
+        let script = UcdScript::from(slang);
+        let slangs: &'static [ScriptLanguage] = lang.into();
+        // counted once per `UcdScript`, by its first `ScriptLanguage`
+        if slangs.iter().take_while(|&&sl| sl != slang).any(|&sl| {
+            UcdScript::from(sl) == script && *langs_cnt.get_safe_unchecked(sl as usize) > 0
+        }) {
+            continue;
+        }
+        *res.get_safe_unchecked_mut(lang as usize) +=
+            language_count_in_script(langs_cnt, slangs, script);
+    }
+    res
+}
+
+/// Languages of one `UcdScript`, see [`slangs_count_hierarchy`](fn.slangs_count_hierarchy.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptLanguagesCount {
+    pub script: UcdScript,
+    pub count: u32,
+    /// Sorted by count, from the biggest.
+    pub languages: Vec<(Language, u32)>,
+}
+
+/// Dominant `UcdScript`s first, then `Language`s within each `UcdScript`.
+///
+/// Zero counts are skipped.
+pub fn slangs_count_hierarchy(langs_cnt: &ScriptLanguageArr<u32>) -> Vec<ScriptLanguagesCount> {
+    let scripts_cnt = slangs_count_by_ucd_script(langs_cnt);
+
+    let mut res: Vec<ScriptLanguagesCount> = UcdScript::iter()
+        .zip(scripts_cnt)
+        .filter(|(_, cnt)| *cnt > 0)
+        .map(|(script, count)| ScriptLanguagesCount {
+            script,
+            count,
+            languages: Vec::new(),
+        })
+        .collect();
+
+    for script_count in res.iter_mut() {
+        let script = script_count.script;
+        for (slang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+            if cnt == 0 || UcdScript::from(slang) != script {
+                continue;
+            }
+            let (lang, _) = slang.into_parts();
+            if script_count.languages.iter().any(|(l, _)| *l == lang) {
+                continue;
+            }
+            let slangs: &'static [ScriptLanguage] = lang.into();
+            script_count
+                .languages
+                .push((lang, language_count_in_script(langs_cnt, slangs, script)));
+        }
+        script_count
+            .languages
+            .sort_by_key(|&(_, cnt)| ::core::cmp::Reverse(cnt));
+    }
+    res.sort_by_key(|v| ::core::cmp::Reverse(v.count));
+
+    res
+}
@@ -8,23 +8,56 @@ use ::std::{
 };
 use ahash::{AHashMap, AHashSet};
 use alphabet_detector::{
-    ch_norm, reader::ReadChunks, script_char_to_slangs, slang_arr_default, slang_arr_default_nc,
-    ucd::BY_NAME, CharData, Language, Script, ScriptLanguage, ScriptLanguageArr, UcdScript,
+    ch_norm, distinguishing_chars, reader::ReadChunks, script_char_to_slangs, slang_arr_default,
+    slang_arr_default_nc, ucd::BY_NAME, CharData, Language, Script, ScriptLanguage,
+    ScriptLanguageArr, UcdScript,
 };
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use debug_unsafe::slice::SliceGetter;
 
 #[derive(Parser)]
-#[command(version, about)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
 struct Args {
     #[arg(short = 'd', required = true)]
-    dir: String,
+    dir: Option<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints chars, which distinguish the `ScriptLanguage`s (codes like "bosLatn")
+    Distinguish {
+        #[arg(required = true, num_args = 2..)]
+        slangs: Vec<String>,
+    },
 }
 
-const THREADS: usize = 8;
 fn main() {
     let args = Args::parse();
-    let paths = fs::read_dir(&args.dir).unwrap();
+    match args.command {
+        Some(Command::Distinguish { slangs }) => distinguish(&slangs),
+        None => intersection(&args.dir.unwrap()),
+    }
+}
+
+fn distinguish(codes: &[String]) {
+    let slangs: Vec<ScriptLanguage> = codes
+        .iter()
+        .map(|code| {
+            ScriptLanguage::from_str(code).unwrap_or_else(|| panic!("Not found lang: {code}"))
+        })
+        .collect();
+
+    for (ch, kept) in distinguishing_chars(&slangs) {
+        let kept: Vec<_> = kept.iter().map(|sl| sl.into_str()).collect();
+        println!("{ch}\tU+{:04X}\t{}", ch as u32, kept.join(" "));
+    }
+}
+
+const THREADS: usize = 8;
+fn intersection(dir: &str) {
+    let paths = fs::read_dir(dir).unwrap();
     let pool = threadpool::ThreadPool::new(THREADS);
     let langs_seen = Arc::new(Mutex::new(slang_arr_default::<bool>()));
 
@@ -0,0 +1,152 @@
+use crate::{
+    slangs_count_max,
+    words::{self, next_word_boundary},
+    FulltextAccumulator, ScriptLanguage, ScriptLanguageArr, ScriptLanguageSet,
+};
+use ::core::cell::Cell;
+use strum::IntoEnumIterator;
+
+/// When to stop the detection early, see [`fulltext_counts_budget`](fn.fulltext_counts_budget.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FulltextBudget {
+    /// Stops after the word, on which this many chars were read.
+    Chars(usize),
+    /// Stops after this many words.
+    Words(usize),
+    /// Stops once the top `ScriptLanguage`s ([`filter_with_margin`](fn.filter_with_margin.html))
+    /// haven't changed for this many words.
+    Stable(usize),
+}
+
+/// How much of the input was consumed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FulltextConsumed {
+    /// Chars read (can be a few chars ahead of the last word).
+    pub chars: usize,
+    /// Byte index of the last word end (for sampling: sum of the windows lengths).
+    pub bytes: usize,
+    pub words: u64,
+    /// Stopped before the end of the input.
+    pub budget_reached: bool,
+}
+
+/// Top `ScriptLanguage`s ([`filter_with_margin`](fn.filter_with_margin.html)), without an allocation
+#[inline]
+fn slangs_top<const PERCENT: u32>(langs_cnt: &ScriptLanguageArr<u32>) -> ScriptLanguageSet {
+    assert!(PERCENT < 100);
+    let langs_count_margin = slangs_count_max(langs_cnt) * PERCENT / 100;
+    let mut top = ScriptLanguageSet::new();
+    for (slang, &cnt) in ScriptLanguage::iter().zip(langs_cnt.iter()) {
+        if cnt > langs_count_margin {
+            top.insert(slang);
+        }
+    }
+    top
+}
+
+#[inline]
+fn feed_budget<const PERCENT: u32>(
+    acc: &mut FulltextAccumulator,
+    char_indices: impl Iterator<Item = (usize, char)>,
+    budget: FulltextBudget,
+    consumed: &mut FulltextConsumed,
+) {
+    let chars = Cell::new(consumed.chars);
+    let mut top = match budget {
+        FulltextBudget::Stable(_) => slangs_top::<PERCENT>(acc.langs_count()),
+        _ => ScriptLanguageSet::EMPTY,
+    };
+    let mut stable_words = 0;
+
+    let char_indices = char_indices.inspect(|_| chars.set(chars.get() + 1));
+    let mut word_iter = words::from_ch_ind::<bool>(char_indices);
+    while let Some(word) = word_iter.next() {
+        acc.add_word(&word);
+        consumed.words += 1;
+        consumed.bytes = word.range.end;
+
+        let budget_hit = match budget {
+            FulltextBudget::Chars(max_chars) => chars.get() >= max_chars,
+            FulltextBudget::Words(max_words) => consumed.words >= max_words as u64,
+            FulltextBudget::Stable(max_words) => {
+                let new_top = slangs_top::<PERCENT>(acc.langs_count());
+                if new_top == top {
+                    stable_words += 1;
+                } else {
+                    top = new_top;
+                    stable_words = 0;
+                }
+                stable_words >= max_words
+            }
+        };
+        if budget_hit {
+            // the budget is reached only if there is a word left
+            let chars_read = chars.get();
+            consumed.budget_reached = word_iter.next().is_some();
+            chars.set(chars_read);
+            break;
+        }
+    }
+    consumed.chars = chars.get();
+}
+
+/// Words detection summed up, until the `budget` is reached.
+///
+/// `PERCENT` is used only for [`FulltextBudget::Stable`](enum.FulltextBudget.html#variant.Stable),
+/// `PERCENT` = 95 is recommended.
+pub fn fulltext_counts_budget<const PERCENT: u32>(
+    char_indices: impl Iterator<Item = (usize, char)>,
+    budget: FulltextBudget,
+) -> (ScriptLanguageArr<u32>, FulltextConsumed) {
+    let mut acc = FulltextAccumulator::new();
+    let mut consumed = FulltextConsumed::default();
+    feed_budget::<PERCENT>(&mut acc, char_indices, budget, &mut consumed);
+
+    (acc.into_langs_count(), consumed)
+}
+
+/// Words detection summed up from `windows` evenly spread windows
+/// of about `window_len` bytes (split at word boundaries), instead of only the beginning.
+///
+/// `budget` is applied to each window.
+/// The whole `text` is read, if it's not longer than all of the windows.
+pub fn fulltext_counts_sampled<const PERCENT: u32>(
+    text: &str,
+    windows: usize,
+    window_len: usize,
+    budget: FulltextBudget,
+) -> (ScriptLanguageArr<u32>, FulltextConsumed) {
+    if windows.saturating_mul(window_len) >= text.len() {
+        return fulltext_counts_budget::<PERCENT>(text.char_indices(), budget);
+    }
+
+    let mut acc = FulltextAccumulator::new();
+    let mut consumed = FulltextConsumed::default();
+    let mut bytes = 0;
+    let mut prev_end = 0;
+    for window in 0..windows {
+        let mut start = text.len() / windows * window;
+        if window > 0 {
+            match next_word_boundary(text, start.max(prev_end)) {
+                Some(boundary) => start = boundary,
+                None => break,
+            }
+        }
+        let end = next_word_boundary(text, start + window_len).unwrap_or(text.len());
+
+        let char_indices = text[start..end]
+            .char_indices()
+            .map(|(i, ch)| (i + start, ch));
+        let mut window_consumed = FulltextConsumed::default();
+        feed_budget::<PERCENT>(&mut acc, char_indices, budget, &mut window_consumed);
+
+        consumed.chars += window_consumed.chars;
+        consumed.words += window_consumed.words;
+        bytes += window_consumed.bytes.saturating_sub(start);
+        prev_end = end;
+    }
+    consumed.bytes = bytes;
+    consumed.budget_reached = prev_end < text.len();
+
+    (acc.into_langs_count(), consumed)
+}
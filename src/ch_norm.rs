@@ -1,4 +1,5 @@
-use crate::lang::{char_compose_custom, UcdScript};
+use crate::lang::{char_compose_custom, char_decompose_custom, UcdScript};
+use ::std::borrow::Cow;
 use array_buf::ArrayDequePlain;
 use icu_normalizer::{properties::CanonicalCompositionBorrowed, DecomposingNormalizerBorrowed};
 use unicode_normalization::char::canonical_combining_class;
@@ -41,11 +42,14 @@ fn char_compose(
     composer: &CanonicalCompositionBorrowed<'static>,
     ch: char,
     mark: char,
+    compose_custom: bool,
 ) -> Option<char> {
     if let Some(v) = composer.compose(ch, mark) {
         Some(v)
-    } else {
+    } else if compose_custom {
         char_compose_custom(ch, mark)
+    } else {
+        None
     }
 }
 
@@ -66,32 +70,307 @@ fn char_decompose(ch: char) -> (char, Option<char>, Option<char>) {
     }
 }
 
+/// Standard Unicode sequence (lowercase, NFC) of a custom private use area char,
+/// which is composed by [`CharNormalizingIterator`](struct.CharNormalizingIterator.html)
+/// for letters without a precomposed Unicode char (see
+/// [`NormalizationOptions::compose_custom`](struct.NormalizationOptions.html#method.compose_custom)).
+///
+/// ```
+/// use alphabet_detector::ch_norm;
+///
+/// assert_eq!(ch_norm::decompose_custom('\u{f0c5b}'), Some(&['ɛ', '\u{30c}'][..]));
+/// assert_eq!(ch_norm::decompose_custom('ɛ'), None);
+/// ```
+#[inline]
+pub fn decompose_custom(ch: char) -> Option<&'static [char]> {
+    char_decompose_custom(ch)
+}
+
+/// Text with custom private use area chars replaced by standard Unicode sequences
+/// ([`decompose_custom`](fn.decompose_custom.html)), for display or storage of words.
+pub fn decompose_custom_str(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(|ch| decompose_custom(ch).is_some()) {
+        return Cow::Borrowed(s);
+    }
+    let mut res = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match decompose_custom(ch) {
+            Some(chars) => res.extend(chars),
+            None => res.push(ch),
+        }
+    }
+    Cow::Owned(res)
+}
+
+/// Steps of [`CharNormalizingIterator`](struct.CharNormalizingIterator.html),
+/// all are on by default.
+///
+/// ```
+/// use alphabet_detector::{ch_norm, NormalizationOptions};
+///
+/// let options = NormalizationOptions::new()
+///     .decompose_ligatures(false)
+///     .map('ſ', 's');
+/// let chars: String = ch_norm::from_ch_ind_with("ﬁſh".char_indices(), options)
+///     .map(|c| c.ch)
+///     .collect();
+/// assert_eq!(chars, "ﬁsh");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalizationOptions {
+    decompose_ligatures: bool,
+    fold_apostrophes: bool,
+    fold_hyphens: bool,
+    fold_arabic_sukun: bool,
+    decompose_arabic_presentation_forms: bool,
+    compose_custom: bool,
+    fold_compatibility: bool,
+    mappings: Vec<(char, char)>,
+}
+
+impl Default for NormalizationOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NormalizationOptions {
+    /// All steps on, no custom mappings
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            decompose_ligatures: true,
+            fold_apostrophes: true,
+            fold_hyphens: true,
+            fold_arabic_sukun: true,
+            decompose_arabic_presentation_forms: true,
+            compose_custom: true,
+            fold_compatibility: true,
+            mappings: Vec::new(),
+        }
+    }
+
+    /// All steps off, only canonical reordering and composition of combining marks
+    #[inline]
+    pub const fn none() -> Self {
+        Self {
+            decompose_ligatures: false,
+            fold_apostrophes: false,
+            fold_hyphens: false,
+            fold_arabic_sukun: false,
+            decompose_arabic_presentation_forms: false,
+            compose_custom: false,
+            fold_compatibility: false,
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Latin ligatures: "ﬁ" -> "fi"
+    #[inline]
+    pub const fn decompose_ligatures(mut self, on: bool) -> Self {
+        self.decompose_ligatures = on;
+        self
+    }
+
+    /// '’', 'ʼ' -> '\''
+    #[inline]
+    pub const fn fold_apostrophes(mut self, on: bool) -> Self {
+        self.fold_apostrophes = on;
+        self
+    }
+
+    /// '‐', '‑' -> '-'
+    #[inline]
+    pub const fn fold_hyphens(mut self, on: bool) -> Self {
+        self.fold_hyphens = on;
+        self
+    }
+
+    /// '\u{6e1}' (small high dotless head of khah) -> '\u{652}' (sukūn)
+    #[inline]
+    pub const fn fold_arabic_sukun(mut self, on: bool) -> Self {
+        self.fold_arabic_sukun = on;
+        self
+    }
+
+    /// Arabic Presentation Forms A & B
+    #[inline]
+    pub const fn decompose_arabic_presentation_forms(mut self, on: bool) -> Self {
+        self.decompose_arabic_presentation_forms = on;
+        self
+    }
+
+    /// Composes letters without a precomposed Unicode char (like "Ɛ̌")
+    /// into custom (PUA) chars used by the alphabets.
+    #[inline]
+    pub const fn compose_custom(mut self, on: bool) -> Self {
+        self.compose_custom = on;
+        self
+    }
+
+    /// Compatibility letter forms to the base letters (before the script is found):
+    /// fullwidth "Ａ", halfwidth "ｶ", mathematical "𝐁", circled "Ⓒ", superscript "ʳ" and similar.
+    #[inline]
+    pub const fn fold_compatibility(mut self, on: bool) -> Self {
+        self.fold_compatibility = on;
+        self
+    }
+
+    /// Maps a raw char `from` to `to` before all of the other steps,
+    /// the last mapping of the same `from` wins.
+    #[inline]
+    pub fn map(mut self, from: char, to: char) -> Self {
+        self.mappings.retain(|&(f, _)| f != from);
+        self.mappings.push((from, to));
+        self
+    }
+
+    #[inline]
+    fn map_char(mappings: &[(char, char)], ch: char) -> char {
+        mappings
+            .iter()
+            .find(|&&(from, _)| from == ch)
+            .map_or(ch, |&(_, to)| to)
+    }
+}
+
+/// Folds compatibility letter forms (styled, fullwidth, halfwidth, circled, superscript)
+/// to the base letters, other chars are returned as is.
+///
+/// Only single char NFKD decompositions of the selected ranges are used,
+/// full NFKD is not suitable for this crate.
+#[inline]
+fn char_fold_compat(decomposer: &DecomposingNormalizerBorrowed<'static>, ch: char) -> char {
+    if (ch as u32) < 0x2b0 {
+        return ch;
+    }
+    match ch {
+        // negative circled and negative squared Latin capital letters, no decomposition
+        '\u{1f150}'..='\u{1f169}' => char::from_u32(ch as u32 - 0x1f150 + 'A' as u32).unwrap_or(ch),
+        '\u{1f170}'..='\u{1f189}' => char::from_u32(ch as u32 - 0x1f170 + 'A' as u32).unwrap_or(ch),
+        // modifier letters
+        '\u{2b0}'..='\u{2b8}'
+        | '\u{2e0}'..='\u{2e4}'
+        // phonetic extensions superscripts
+        | '\u{1d2c}'..='\u{1d6a}'
+        | '\u{1d78}'
+        | '\u{1d9b}'..='\u{1dbf}'
+        // superscripts and subscripts
+        | '\u{2071}'
+        | '\u{207f}'
+        | '\u{2090}'..='\u{209c}'
+        // letterlike symbols
+        | '\u{2102}'..='\u{2149}'
+        // circled letters
+        | '\u{24b6}'..='\u{24e9}'
+        // fullwidth Latin
+        | '\u{ff21}'..='\u{ff3a}'
+        | '\u{ff41}'..='\u{ff5a}'
+        // halfwidth Katakana and Hangul
+        | '\u{ff66}'..='\u{ffdc}'
+        // mathematical alphanumeric symbols
+        | '\u{1d400}'..='\u{1d7ff}'
+        // squared Latin capital letters
+        | '\u{1f130}'..='\u{1f149}' => {
+            let mut decomp = decomposer.normalize_iter([ch].into_iter());
+            match (decomp.next(), decomp.next()) {
+                (Some(c), None) => c,
+                _ => ch,
+            }
+        }
+        _ => ch,
+    }
+}
+
+/// Max count of combining marks (`ccc` > 0) after a char, others are dropped.
+///
+/// Same as the Stream-Safe Text Format limit (UAX #15),
+/// so it's reached only by an abnormal text, like "Zalgo".
+pub const MAX_COMBINING_MARKS: usize = 30;
+
+/// Chars dropped by [`CharNormalizingIterator`](struct.CharNormalizingIterator.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizationAnomaly {
+    /// More than [`MAX_COMBINING_MARKS`](constant.MAX_COMBINING_MARKS.html) combining marks in a row,
+    /// `idx` of the first dropped mark
+    CombiningMarksOverflow { idx: usize, dropped: usize },
+    /// Decomposition of a presentation form is too long, `idx` of the presentation form
+    DecompositionOverflow { idx: usize, dropped: usize },
+}
+
+impl NormalizationAnomaly {
+    /// Index of the (first) dropped char in the text
+    #[inline]
+    pub const fn idx(&self) -> usize {
+        match *self {
+            Self::CombiningMarksOverflow { idx, .. } | Self::DecompositionOverflow { idx, .. } => {
+                idx
+            }
+        }
+    }
+
+    /// Count of the dropped chars
+    #[inline]
+    pub const fn dropped(&self) -> usize {
+        match *self {
+            Self::CombiningMarksOverflow { dropped, .. }
+            | Self::DecompositionOverflow { dropped, .. } => dropped,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct CharData {
     pub script: UcdScript,
     pub ccc: u8,
     pub idx: usize,
+    /// Byte length of the source char(s) in the text, `ch` can be folded or composed
+    pub len: usize,
     pub ch: char,
 }
 
 pub struct CharNormalizingIterator<I: Iterator<Item = CharData>> {
     iter: I,
     /// Chars are not normalized, raw.
-    /// Bounded, so it would not be possible to eat all of the memory.
-    buf: ArrayDequePlain<CharData, 32>,
+    /// Bounded, so it would not be possible to eat all of the memory:
+    /// `MAX_COMBINING_MARKS` + 2 from `char_decompose`.
+    buf: ArrayDequePlain<CharData, { MAX_COMBINING_MARKS + 2 }>,
+    anomalies: Vec<NormalizationAnomaly>,
     decomposer: DecomposingNormalizerBorrowed<'static>,
     composer: CanonicalCompositionBorrowed<'static>,
+    /// without `mappings`, they are applied to `iter`
+    options: NormalizationOptions,
 }
 
 #[inline]
 pub fn from_ch_ind(
     char_indices: impl Iterator<Item = (usize, char)>,
 ) -> CharNormalizingIterator<impl Iterator<Item = CharData>> {
-    let mut iter = char_indices.map(|(ch_idx, ch)| CharData {
-        script: UcdScript::find(ch),
-        ccc: canonical_combining_class(ch),
-        idx: ch_idx,
-        ch,
+    from_ch_ind_with(char_indices, NormalizationOptions::new())
+}
+
+/// Same as [`from_ch_ind`](fn.from_ch_ind.html), with selected normalization steps
+#[inline]
+pub fn from_ch_ind_with(
+    char_indices: impl Iterator<Item = (usize, char)>,
+    mut options: NormalizationOptions,
+) -> CharNormalizingIterator<impl Iterator<Item = CharData>> {
+    let mappings = ::core::mem::take(&mut options.mappings);
+    let fold_compatibility = options.fold_compatibility;
+    let decomposer = DecomposingNormalizerBorrowed::new_nfkd();
+    let mut iter = char_indices.map(move |(ch_idx, ch_src)| {
+        let mut ch = NormalizationOptions::map_char(&mappings, ch_src);
+        if fold_compatibility {
+            ch = char_fold_compat(&decomposer, ch);
+        }
+        CharData {
+            script: UcdScript::find(ch),
+            ccc: canonical_combining_class(ch),
+            idx: ch_idx,
+            len: ch_src.len_utf8(),
+            ch,
+        }
     });
 
     let mut next_char = iter.next();
@@ -110,17 +389,25 @@ pub fn from_ch_ind(
     CharNormalizingIterator {
         iter,
         buf,
+        anomalies: Vec::new(),
         decomposer: DecomposingNormalizerBorrowed::new_nfkd(),
         composer: CanonicalCompositionBorrowed::new(),
+        options,
     }
 }
 
 impl<I: Iterator<Item = CharData>> CharNormalizingIterator<I> {
-    /// Not normalized, raw.
+    /// Not normalized, raw (only custom mappings are applied).
     #[inline(always)]
     pub fn peek_next_char(&self) -> Option<CharData> {
         self.buf.first().copied()
     }
+
+    /// Dropped chars so far, in text order
+    #[inline]
+    pub fn anomalies(&self) -> &[NormalizationAnomaly] {
+        &self.anomalies
+    }
 }
 
 impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
@@ -131,14 +418,20 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
         let CharData {
             script,
             ccc,
-            mut idx,
+            idx,
+            mut len,
             mut ch,
         } = self.buf.pop_first()?;
-        debug_assert!(self.buf.len() < self.buf.capacity() - 2);
+        // leaves space for 2 from `char_decompose`
+        debug_assert!(self.buf.len() <= self.buf.capacity() - 2);
 
         let next_char;
         let after_next_char;
-        (ch, next_char, after_next_char) = char_decompose(ch);
+        (ch, next_char, after_next_char) = if self.options.decompose_ligatures {
+            char_decompose(ch)
+        } else {
+            (ch, None, None)
+        };
 
         if let Some(c) = next_char {
             if let Some(c2) = after_next_char {
@@ -147,6 +440,7 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
                         script,
                         ccc,
                         idx,
+                        len,
                         ch: c2,
                     })
                 };
@@ -157,59 +451,62 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
                     script,
                     ccc,
                     idx,
+                    len,
                     ch: c,
                 })
             };
         } else {
-            if ['’', 'ʼ'].contains(&ch) {
+            if self.options.fold_apostrophes && ['’', 'ʼ'].contains(&ch) {
                 ch = '\'';
-            } else if ['‐', '‑'].contains(&ch) {
+            } else if self.options.fold_hyphens && ['‐', '‑'].contains(&ch) {
                 ch = '-';
-            } else if ch == '\u{6e1}' {
+            } else if self.options.fold_arabic_sukun && ch == '\u{6e1}' {
                 ch = '\u{652}'; // Arabic Sukūn
-            } else if ('\u{FB50}'..='\u{FDFF}').contains(&ch)
-                || ('\u{FE70}'..='\u{FEFF}').contains(&ch)
+            } else if self.options.decompose_arabic_presentation_forms
+                && (('\u{FB50}'..='\u{FDFF}').contains(&ch)
+                    || ('\u{FE70}'..='\u{FEFF}').contains(&ch))
             {
-                // decomposes Arabic Presentation Forms A & B
+                // decomposes Arabic Presentation Forms A & B,
+                // only the next char can be loaded, else it's left as is
                 if self.buf.len() < 2 {
                     let mut decomp = self.decomposer.normalize_iter([ch].into_iter());
-                    if let Some(c) = decomp.next() {
-                        if UcdScript::find(c) == script {
-                            ch = c;
+                    if let Some(c) = decomp.next().filter(|&c| UcdScript::find(c) == script) {
+                        ch = c;
 
-                            if let Some(c2) = decomp.next() {
-                                let last_loaded_char = self.buf.first().copied();
-                                self.buf.clear();
+                        if let Some(c2) = decomp.next() {
+                            let last_loaded_char = self.buf.first().copied();
+                            self.buf.clear();
 
-                                for ci in [c2].into_iter().chain(decomp) {
-                                    let cd = CharData {
+                            let mut dropped = 0;
+                            for ci in [c2].into_iter().chain(decomp) {
+                                // leaves space for `last_loaded_char`
+                                if self.buf.len() >= MAX_COMBINING_MARKS {
+                                    dropped += 1;
+                                    continue;
+                                }
+                                unsafe {
+                                    self.buf.push_last_unchecked(CharData {
                                         script: UcdScript::find(ci),
                                         ccc: canonical_combining_class(ci),
                                         idx,
+                                        len,
                                         ch: ci,
-                                    };
-                                    if let Err(_e) = self.buf.push_last(cd) {
-                                        #[cfg(debug_assertions)]
-                                        panic!("Ch norm {}", _e);
-                                    }
-                                }
+                                    })
+                                };
+                            }
+                            if dropped > 0 {
+                                self.anomalies
+                                    .push(NormalizationAnomaly::DecompositionOverflow {
+                                        idx,
+                                        dropped,
+                                    });
+                            }
 
-                                if let Some(cd) = last_loaded_char {
-                                    if let Err(_e) = self.buf.push_last(cd) {
-                                        #[cfg(debug_assertions)]
-                                        panic!("Ch norm {}", _e);
-                                    }
-                                }
-                                unsafe { ::core::hint::assert_unchecked(!self.buf.is_empty()) };
+                            if let Some(cd) = last_loaded_char {
+                                unsafe { self.buf.push_last_unchecked(cd) };
                             }
                         }
-                    } else {
-                        #[cfg(debug_assertions)]
-                        unreachable!();
                     }
-                } else {
-                    #[cfg(debug_assertions)]
-                    unreachable!();
                 }
             }
 
@@ -229,6 +526,7 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
                 let mut last_loaded_char = None;
                 if self.buf.len() == 1 {
                     self.buf.linearize_one();
+                    let mut overflow: Option<(usize, usize)> = None;
                     // load all chars with `ccc` > 0
                     for c in self.iter.by_ref() {
                         if c.ccc == 0 {
@@ -241,11 +539,17 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
                         }
 
                         // leaves space for `last_loaded_char` - next `pop_first` + 2 from `char_decompose`
-                        if self.buf.len() < self.buf.capacity() - 2 {
+                        if self.buf.len() < MAX_COMBINING_MARKS {
                             unsafe { self.buf.push_last_unchecked(c) };
+                        } else {
+                            overflow.get_or_insert((c.idx, 0)).1 += 1;
                         }
                     }
                     debug_assert!(last_loaded_char.is_some() || self.iter.next().is_none());
+                    if let Some((idx, dropped)) = overflow {
+                        self.anomalies
+                            .push(NormalizationAnomaly::CombiningMarksOverflow { idx, dropped });
+                    }
 
                     // reorder chars by ccc
                     unsafe { self.buf.as_mut_slice() }.sort_by(|a, b| a.ccc.cmp(&b.ccc));
@@ -256,15 +560,19 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
                     script: UcdScript::Inherited,
                     ccc: cc,
                     idx: i,
+                    len: l,
                     ch: c,
                 }) = self.buf.first().copied()
                 {
                     debug_assert!(cc > 0);
 
-                    if let Some(ch_new) = char_compose(&self.composer, ch, c) {
+                    if let Some(ch_new) =
+                        char_compose(&self.composer, ch, c, self.options.compose_custom)
+                    {
                         unsafe { self.buf.pop_first_unchecked() };
                         ch = ch_new;
-                        idx = idx.max(i); // looks like it needs range?
+                        // composed char spans from the base char to the last mark
+                        len = len.max(i + l - idx);
                     } else {
                         break;
                     }
@@ -280,6 +588,7 @@ impl<I: Iterator<Item = CharData>> Iterator for CharNormalizingIterator<I> {
             script,
             ccc,
             idx,
+            len,
             ch,
         })
     }
@@ -0,0 +1,117 @@
+use crate::{slangs_count_max, words, FulltextAccumulator, ScriptLanguage, UcdScript};
+use ::core::ops::Range;
+use debug_unsafe::slice::SliceGetter;
+
+/// Why a word is listed in the [`Explanation`](struct.Explanation.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordReason {
+    /// Split from the previous word without a separator,
+    /// because their alphabets (or scripts) do not intersect.
+    Split,
+    /// Skipped, because it's written in other `UcdScript`.
+    OtherScript(UcdScript),
+    /// Skipped, because none of its letters are in the alphabet.
+    NotInAlphabet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordExplanation {
+    pub range: Range<usize>,
+    /// Lowercased, as it was matched.
+    pub word: String,
+    /// Letters of the word, which supported the `ScriptLanguage`.
+    pub supporting_letters: u32,
+    pub reasons: Vec<WordReason>,
+}
+
+/// Why a `ScriptLanguage` was kept or rejected, see [`explain`](fn.explain.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub slang: ScriptLanguage,
+    /// Letters (of all words), which supported the `ScriptLanguage`.
+    pub supporting_letters: u32,
+    /// Letters count of the top `ScriptLanguage`s, for comparison.
+    pub max_letters: u32,
+    /// Chars (with their byte index) of the same `UcdScript`
+    /// (or `UcdScript::Common` connectors), which are missing from the alphabet.
+    pub missing_chars: Vec<(usize, char)>,
+    /// Words which were split or skipped.
+    pub words: Vec<WordExplanation>,
+}
+
+impl Explanation {
+    /// Is it among the top `ScriptLanguage`s ([`filter_max`](fn.filter_max.html)).
+    #[inline]
+    pub fn is_top(&self) -> bool {
+        self.supporting_letters == self.max_letters
+    }
+}
+
+/// Explains why `slang` was kept or rejected for the `text`,
+/// using the `script_char_to_slangs` lookups recorded during the detection.
+pub fn explain(text: &str, slang: ScriptLanguage) -> Explanation {
+    let slang_script = UcdScript::from(slang);
+
+    let mut word_iter = words::from_ch_ind::<String>(text.char_indices()).record_lookups();
+    let found_words: Vec<_> = word_iter.by_ref().collect();
+    let lookups = word_iter.take_lookups();
+
+    let missing_chars = lookups
+        .iter()
+        .filter(|l| {
+            (l.script == slang_script || l.script == UcdScript::Common)
+                && l.ch != '-'
+                && !l.langs.contains(&slang)
+                && (l.script != UcdScript::Common || !l.langs.is_empty())
+        })
+        .map(|l| (l.idx, l.ch))
+        .collect();
+
+    let mut acc = FulltextAccumulator::new();
+    let mut words = Vec::new();
+    // lookups are in the text order, same as the words
+    let mut lookups_rest = lookups.as_slice();
+    for word in found_words {
+        acc.add_word(&word);
+        let supporting_letters = *word.langs_cnt.get_safe_unchecked(slang as usize);
+        let start = lookups_rest.partition_point(|l| l.idx < word.range.start);
+        let len = lookups_rest[start..].partition_point(|l| l.idx < word.range.end);
+        let word_lookups = &lookups_rest[start..start + len];
+        lookups_rest = &lookups_rest[start + len..];
+        let mut reasons = Vec::new();
+
+        if word_lookups.first().is_some_and(|l| l.split) {
+            reasons.push(WordReason::Split);
+        }
+        if supporting_letters == 0 {
+            let word_script = word_lookups
+                .iter()
+                .map(|l| l.script)
+                .find(|&s| s != UcdScript::Common)
+                .unwrap_or(UcdScript::Common);
+            reasons.push(if word_script != slang_script {
+                WordReason::OtherScript(word_script)
+            } else {
+                WordReason::NotInAlphabet
+            });
+        }
+
+        if !reasons.is_empty() {
+            words.push(WordExplanation {
+                range: word.range,
+                word: word.buf,
+                supporting_letters,
+                reasons,
+            });
+        }
+    }
+
+    let langs_cnt = acc.langs_count();
+    Explanation {
+        slang,
+        supporting_letters: *langs_cnt.get_safe_unchecked(slang as usize),
+        max_letters: slangs_count_max(langs_cnt),
+        missing_chars,
+        words,
+    }
+}
@@ -1,27 +1,124 @@
 use crate::{
-    filter_max, filter_with_margin, filter_with_margin_sorted, slang_arr_default,
+    filter_max, filter_with_margin, filter_with_margin_sorted,
+    no_diacritics::DiacriticsStats,
     words::{self, WordBuf},
-    ScriptLanguage, ScriptLanguageArr, Word,
+    CharData, FulltextAccumulator, ScriptLanguage, ScriptLanguageArr, Word, WordIterator,
 };
-use debug_unsafe::slice::SliceGetter;
 
 /// All words detection summed up.
 pub fn fulltext<B: WordBuf>(
     char_indices: impl Iterator<Item = (usize, char)>,
 ) -> (Vec<Word<B>>, ScriptLanguageArr<u32>) {
     let mut words = Vec::new();
-    let mut langs_count: ScriptLanguageArr<u32> = slang_arr_default();
+    let mut acc = FulltextAccumulator::new();
 
     let found_words = words::from_ch_ind(char_indices);
     for wld in found_words {
         // let (langs, count_max) = filter_max(wld.langs_cnt); // worse at detecting
-        for (lang, cnt) in wld.langs_cnt.iter().enumerate() {
-            *langs_count.get_safe_unchecked_mut(lang) += cnt;
-        }
+        acc.add_word(&wld);
         words.push(wld);
     }
 
-    (words, langs_count)
+    (words, acc.into_langs_count())
+}
+
+/// Options of [`fulltext_with`](fn.fulltext_with.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FulltextOptions {
+    no_diacritics: bool,
+    tier_weights: bool,
+}
+
+impl FulltextOptions {
+    /// Same detection as [`fulltext`](fn.fulltext.html)
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            no_diacritics: false,
+            tier_weights: false,
+        }
+    }
+
+    /// Letters are counted by their [`LetterTier::weight`](enum.LetterTier.html#method.weight)
+    /// instead of 1, see [`WordIterator::tier_weighted`](struct.WordIterator.html#method.tier_weighted).
+    #[inline]
+    pub const fn tier_weights(mut self, on: bool) -> Self {
+        self.tier_weights = on;
+        self
+    }
+
+    #[inline]
+    fn words<B: WordBuf>(
+        self,
+        char_indices: impl Iterator<Item = (usize, char)>,
+    ) -> WordIterator<impl Iterator<Item = CharData>, B> {
+        let word_iter = words::from_ch_ind(char_indices);
+        if self.tier_weights {
+            word_iter.tier_weighted()
+        } else {
+            word_iter
+        }
+    }
+
+    /// For text typed without accents (Vietnamese, Romanian, Polish, Turkish, etc.).
+    ///
+    /// If the text has ASCII letters, but none with diacritics, and the top languages
+    /// (a tie or a single language) have Latin languages, which need diacritics,
+    /// then those are scored by the base-letter projections of their alphabets
+    /// ([`diacritic_base`](fn.diacritic_base.html)): the text letters, which could have been stripped,
+    /// are added to their counts, and the result is flagged
+    /// ([`FulltextFlags::no_diacritics`](struct.FulltextFlags.html#structfield.no_diacritics)).
+    /// ASCII-only text fits any projection, so it's scored only if frequent words
+    /// with the diacritics stripped ("dziękuję" -> "dziekuje") are found for one of them:
+    /// Vietnamese, Romanian, Polish, Turkish or Czech. Their letters are added too.
+    /// Words counts are not changed.
+    #[inline]
+    pub const fn no_diacritics(mut self, on: bool) -> Self {
+        self.no_diacritics = on;
+        self
+    }
+}
+
+/// Flags of the [`fulltext_with`](fn.fulltext_with.html) result
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FulltextFlags {
+    /// Scored as text typed without diacritics, see
+    /// [`FulltextOptions::no_diacritics`](struct.FulltextOptions.html#method.no_diacritics).
+    pub no_diacritics: bool,
+}
+
+/// All words detection summed up, with `options`.
+pub fn fulltext_with<B: WordBuf>(
+    char_indices: impl Iterator<Item = (usize, char)>,
+    options: FulltextOptions,
+) -> (Vec<Word<B>>, ScriptLanguageArr<u32>, FulltextFlags) {
+    let mut words = Vec::new();
+    let mut acc = FulltextAccumulator::new();
+
+    if !options.no_diacritics {
+        for wld in options.words(char_indices) {
+            acc.add_word(&wld);
+            words.push(wld);
+        }
+        return (words, acc.into_langs_count(), FulltextFlags::default());
+    }
+
+    let mut stats = DiacriticsStats::default();
+    for wld in options.words::<(B, DiacriticsStats)>(char_indices) {
+        acc.add_word(&wld);
+        stats.merge(&wld.buf.1);
+        words.push(Word {
+            buf: wld.buf.0,
+            range: wld.range,
+            langs_cnt: wld.langs_cnt,
+        });
+    }
+
+    let mut langs_count = acc.into_langs_count();
+    let flags = FulltextFlags {
+        no_diacritics: stats.apply(&mut langs_count),
+    };
+    (words, langs_count, flags)
 }
 
 /// All words detection summed up, then filtered by max ([`filter_max`](fn.filter_max.html)).
@@ -71,3 +168,83 @@ pub fn fulltext_filter_with_margin_sorted<B: WordBuf, const PERCENT: u32>(
 
     (words, langs, langs_count_margin)
 }
+
+/// Calls `f` for each found word, without collecting them.
+#[inline]
+pub fn for_each_word<B: WordBuf>(
+    char_indices: impl Iterator<Item = (usize, char)>,
+    f: impl FnMut(Word<B>),
+) {
+    words::from_ch_ind(char_indices).for_each(f);
+}
+
+/// All words detection summed up, without collecting words.
+#[inline]
+pub fn fulltext_counts(
+    char_indices: impl Iterator<Item = (usize, char)>,
+) -> ScriptLanguageArr<u32> {
+    let mut acc = FulltextAccumulator::new();
+    acc.feed(char_indices);
+    acc.into_langs_count()
+}
+
+/// All words detection summed up without collecting words, with `options`.
+pub fn fulltext_counts_with(
+    char_indices: impl Iterator<Item = (usize, char)>,
+    options: FulltextOptions,
+) -> (ScriptLanguageArr<u32>, FulltextFlags) {
+    let mut acc = FulltextAccumulator::new();
+
+    if !options.no_diacritics {
+        for wld in options.words::<bool>(char_indices) {
+            acc.add_word(&wld);
+        }
+        return (acc.into_langs_count(), FulltextFlags::default());
+    }
+
+    let mut stats = DiacriticsStats::default();
+    for wld in options.words::<DiacriticsStats>(char_indices) {
+        acc.add_word(&wld);
+        stats.merge(&wld.buf);
+    }
+
+    let mut langs_count = acc.into_langs_count();
+    let flags = FulltextFlags {
+        no_diacritics: stats.apply(&mut langs_count),
+    };
+    (langs_count, flags)
+}
+
+/// All words detection summed up without collecting words,
+/// then filtered by max ([`filter_max`](fn.filter_max.html)).
+#[inline]
+pub fn fulltext_counts_filter_max(
+    char_indices: impl Iterator<Item = (usize, char)>,
+) -> (impl Iterator<Item = ScriptLanguage> + Clone, u32) {
+    filter_max(fulltext_counts(char_indices))
+}
+
+/// All words detection summed up without collecting words,
+/// then filtered with margin percent ([`filter_with_margin`](fn.filter_with_margin.html)).
+///
+/// Less then (100 - `PERCENT`)% margin for an error.
+/// `PERCENT` = 95 is recommended.
+#[inline]
+pub fn fulltext_counts_filter_with_margin<const PERCENT: u32>(
+    char_indices: impl Iterator<Item = (usize, char)>,
+) -> (impl Iterator<Item = (ScriptLanguage, u32)> + Clone, u32) {
+    filter_with_margin::<PERCENT>(fulltext_counts(char_indices))
+}
+
+/// All words detection summed up without collecting words,
+/// then filtered with margin percent
+/// ([`filter_with_margin_sorted`](fn.filter_with_margin_sorted.html)), then sorted.
+///
+/// Less then (100 - `PERCENT`)% margin for an error.
+/// `PERCENT` = 95 is recommended.
+#[inline]
+pub fn fulltext_counts_filter_with_margin_sorted<const PERCENT: u32>(
+    char_indices: impl Iterator<Item = (usize, char)>,
+) -> (Vec<(ScriptLanguage, u32)>, u32) {
+    filter_with_margin_sorted::<PERCENT>(fulltext_counts(char_indices))
+}
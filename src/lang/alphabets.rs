@@ -1,7 +1,7 @@
-use super::{ScriptLanguage, UcdScript};
+use super::{LetterTier, ScriptLanguage, UcdScript, Verification};
 #[cfg(all(debug_assertions, feature = "test_chars"))]
 use crate::ch_norm::test_chars;
-use alphabet_detector_macros::alphabet_match;
+use alphabet_detector_macros::{alphabet_export, alphabet_match};
 
 /// uses unicode private area
 pub(crate) fn char_compose_custom(ch: char, mark: char) -> Option<char> {
@@ -62,11 +62,41 @@ pub(crate) fn char_compose_custom(ch: char, mark: char) -> Option<char> {
     }
 }
 
+/// reverse of `char_compose_custom`, lowercase, marks in canonical order
+pub(crate) fn char_decompose_custom(ch: char) -> Option<&'static [char]> {
+    Some(match ch {
+        '\u{f00b9}' => &['ẹ', '\u{300}'],
+        '\u{f006d}' => &['m', '\u{300}'],
+        '\u{f00cd}' => &['ọ', '\u{300}'],
+        '\u{f01b9}' => &['ẹ', '\u{301}'],
+        '\u{f01cd}' => &['ọ', '\u{301}'],
+        '\u{f015b}' => &['ɛ', '\u{301}'],
+        '\u{f0154}' => &['ɔ', '\u{301}'],
+        '\u{f025b}' => &['ɛ', '\u{302}'],
+        '\u{f0254}' => &['ɔ', '\u{302}'],
+        '\u{f0367}' => &['g', '\u{303}'],
+        '\u{f046e}' => &['n', '\u{304}'],
+        '\u{f0890}' => &['ɛ', '\u{308}'],
+        '\u{f0854}' => &['ɔ', '\u{308}'],
+        '\u{f0c5b}' => &['ɛ', '\u{30c}'],
+        '\u{f0c54}' => &['ɔ', '\u{30c}'],
+        '\u{f3190}' => &['ɛ', '\u{331}', '\u{308}'],
+        '\u{f3161}' => &['a', '\u{331}'],
+        '\u{f3165}' => &['e', '\u{331}'],
+        '\u{f3169}' => &['i', '\u{331}'],
+        '\u{f316f}' => &['o', '\u{331}'],
+        '\u{f3154}' => &['ɔ', '\u{331}'],
+        _ => return None,
+    })
+}
+
 pub(crate) const WORD_COMMON_FIRST_CHAR_NOT_SKIPPABLE: &[char] = &['¡', '¿'];
 
 // How to add a new alphabet:
 // Add every letter for each alphabet within the script group.
-// Do not add letters used only for foreign words (e.g. proper names of foreign origin), but save them in a comment.
+// Letters used only in loanwords or foreign names go after the `loanword` marker,
+//  letters of dialects, transliteration or old orthography after the `extended` marker
+//  (`core` returns to the core letters), see `LetterTier`.
 // Even though it's possible to add not all of the letters,
 //  (for example when all alphabets include them, like in `UcdScript::Han`),
 //  it's recommended to add common letters (if alphabet is not very big),
@@ -75,33 +105,43 @@ pub(crate) const WORD_COMMON_FIRST_CHAR_NOT_SKIPPABLE: &[char] = &['¡', '¿'];
 //  languages written with different scripts in a one word (like Japanese),
 //  instead create a new `ScriptLanguage`.
 // There is no reason to add any letters if a script contains only one `ScriptLanguage`.
-// Verification info:
-//  No info - generated by chatgpt;
-//  + - verified on the internet;
-//  ++ - also verified by a text frequency analysis (cli_alphabet_intersection);
-//  +++ - verified by a native-level speaker.
+// Verification info (`#[verification(Level)]` of each alphabet, see `Verification`):
+//  Unverified - generated by chatgpt;
+//  Internet - verified on the internet;
+//  FrequencyAnalysis - also verified by a text frequency analysis (cli_alphabet_intersection);
+//  NativeSpeaker - verified by a native-level speaker;
+//  `?` after the level - doubtful.
+// Sources can be added by `#[source("url")]`.
 /// Returns all `ScriptLanguage`s by `UcdScript` and `char`
+///
+/// Empty for scripts excluded by cargo features (see `script_*` features).
+#[alphabet_export]
 #[inline]
 pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLanguage] {
     use UcdScript::*;
     match script {
+        #[cfg(feature = "script_adlam")]
         Adlam => &[ScriptLanguage::FulaPularAdlam],
+        #[cfg(feature = "script_ahom")]
         Ahom => &[ScriptLanguage::Ahom],
+        #[cfg(feature = "script_anatolian_hieroglyphs")]
         AnatolianHieroglyphs => &[ScriptLanguage::LuwianHieroglyphic],
+        #[cfg(feature = "script_arabic")]
         Arabic => alphabet_match!([
+            #[verification(Internet?)]
             (
-                ScriptLanguage::AcehneseJawi, //+?
+                ScriptLanguage::AcehneseJawi,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض',
                     'ط', 'ظ', 'ع', 'غ', 'ڠ', 'ف', 'ڤ', 'ق', 'ك', 'ل', 'م', 'ن', 'ڽ', 'و', 'ه', 'ء',
                     'ي', 'ى', 'ک', 'ئ', 'أ', 'إ', 'ؤ', /*unverified*/
                     'ڬ', 'ۏ', 'ݢ', '۔', '\u{64b}', '\u{651}', //
-                    // loanwords
-                    'ة',
+                    loanword, 'ة',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Arabic, //+
+                ScriptLanguage::Arabic,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط',
                     'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ء', 'ى', 'ئ', 'أ',
@@ -109,17 +149,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{650}', '\u{651}', '\u{652}', '\u{670}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicEgyptian,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط',
                     'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ء', 'ى', 'ئ', 'أ',
                     'ة', 'إ', 'ؤ', 'آ', '\u{64b}', '\u{64c}', '\u{64d}', '\u{64e}', '\u{64f}',
-                    '\u{650}', '\u{651}', '\u{652}',
-                    '\u{670}',
-                    // foreign words translit 'ڤ', 'چ'
+                    '\u{650}', '\u{651}', '\u{652}', '\u{670}', //
+                    loanword, 'ڤ', 'چ', // foreign words translit
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicMesopotamian,
                 [
@@ -129,17 +170,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{650}', '\u{651}', '\u{652}', '\u{670}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicMoroccan,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط',
                     'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ء', 'گ', 'ݣ', 'ى',
                     'ئ', 'أ', 'ة', 'إ', 'ڭ', 'ؤ', 'آ', '\u{64b}', '\u{64c}', '\u{64d}', '\u{64e}',
-                    '\u{64f}', '\u{650}', '\u{651}', '\u{652}',
-                    '\u{670}' //
-                               // transcription of loanwords 'پ', 'ڤ'
+                    '\u{64f}', '\u{650}', '\u{651}', '\u{652}', '\u{670}', //
+                    loanword, 'پ', 'ڤ', // transcription of loanwords
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicNajdi,
                 [
@@ -149,6 +191,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{650}', '\u{651}', '\u{652}', '\u{670}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicNorthLevantine,
                 [
@@ -157,6 +200,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ة', 'إ', 'ؤ', 'آ', '\u{64b}', '\u{64d}', '\u{64e}', '\u{651}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicSouthernYemeni,
                 [
@@ -165,6 +209,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ة', 'إ', 'ؤ', 'آ',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicSouthLevantine,
                 [
@@ -173,6 +218,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ة', 'إ', 'ؤ', 'آ', '\u{64b}', '\u{651}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ArabicSudanese,
                 [
@@ -182,8 +228,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ؤ', 'آ', '\u{64b}',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::ArabicTunisian, //+?
+                ScriptLanguage::ArabicTunisian,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط',
                     'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ء', 'گ', 'ى', 'ئ',
@@ -191,8 +238,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{650}', '\u{651}', '\u{652}', '\u{670}',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::AzerbaijaniSouth, //+?
+                ScriptLanguage::AzerbaijaniSouth,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'ژ', 'س', 'ش',
                     'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'گ', 'ل', 'م', 'ن', 'و', 'ه', 'ی',
@@ -201,17 +249,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{65b}',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::BanjarJawi, //+?
+                ScriptLanguage::BanjarJawi,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض',
                     'ط', 'ظ', 'ع', 'غ', 'ڠ', 'ف', 'ڤ', 'ق', 'ك', 'ل', 'م', 'ن', 'ڽ', 'و', 'ه', 'ء',
                     'ي', 'ى', 'ک', 'ئ', 'پ', 'أ', 'إ', /*unverified*/
                     'ؤ', 'ڬ', 'ݢ', '۔', 'ۏ', //
-                    // loanwords
-                    'ة',
+                    loanword, 'ة',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::DogriPersoArabic,
                 [
@@ -220,8 +269,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'و', 'ہ', 'ء', 'ی', 'ے', 'ڤ',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::KanuriCentralAjami, //+?
+                ScriptLanguage::KanuriCentralAjami,
                 [
                     'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط',
                     'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'و', 'ه', 'ي', 'ء', 'ى', 'ی', 'أ',
@@ -230,8 +280,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{650}', '\u{651}', '\u{652}', '\u{656}', '\u{657}', '\u{670}', '\u{6e7}',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kashmiri, //++
+                ScriptLanguage::Kashmiri,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ٹ', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ڈ', 'ذ', 'ر', 'ڑ', 'ز',
                     'ژ', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن',
@@ -242,6 +293,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{6ed}', 'ؤ',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::KurdishCentral,
                 [
@@ -251,6 +303,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ڵ', 'ڕ', '\u{64e}', '\u{64f}', '\u{650}', '\u{651}', '\u{652}',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::KurdishSouthern,
                 [
@@ -258,8 +311,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ف', 'ڤ', 'ق', 'ک', 'گ', 'ل', 'م', 'ن', 'ه', 'و', 'ي', 'ێ', 'ە',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::MathArabic, //+
+                ScriptLanguage::MathArabic,
                 [
                     // Digits (Mashriq)
                     '٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩',
@@ -270,8 +324,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                 ],
                 true, // Strict (no other chars)
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::PashtoSouthern, //+?
+                ScriptLanguage::PashtoSouthern,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ټ', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ډ', 'ذ', 'ر', 'ړ', 'ز',
                     'ژ', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'ګ', 'ل', 'م', 'ن',
@@ -281,8 +336,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{670}', '\u{64b}',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::PersianDari, //+? copy of PersianFarsi
+                ScriptLanguage::PersianDari, // copy of PersianFarsi
                 [
                     'ا', 'ب', 'پ', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'ژ', 'س', 'ش',
                     'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن', 'و', 'ه', 'ی',
@@ -290,16 +346,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{651}', '\u{654}',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::PersianFarsi, //++
+                ScriptLanguage::PersianFarsi,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'ژ', 'س', 'ش',
                     'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن', 'و', 'ه', 'ی',
                     'ء', 'ئ', 'ك', 'ي', 'آ', 'ى', 'أ', '\u{64b}', '\u{64e}', '\u{64f}', '\u{650}',
-                    '\u{651}', '\u{654}',
-                    // foreign 'ؤ'
+                    '\u{651}', '\u{654}', //
+                    loanword, 'ؤ',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::PunjabiEasternShahmukhi,
                 [
@@ -308,17 +366,19 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'و', 'ہ', 'ء', 'ی', 'ے',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Saraiki, //+
+                ScriptLanguage::Saraiki,
                 [
                     'ا', 'ب', 'ٻ', 'پ', 'ت', 'ٹ', 'ث', 'ج', 'ڄ', 'چ', 'ح', 'خ', 'د', 'ڈ', 'ݙ', 'ذ',
                     'ر', 'ڑ', 'ز', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ڳ',
-                    'ل', 'م', 'ن', 'ں', 'ݨ', 'و', 'ہ', 'ھ', 'ی', 'ے',
-                    // loanwords 'ژ'
+                    'ل', 'م', 'ن', 'ں', 'ݨ', 'و', 'ہ', 'ھ', 'ی', 'ے', //
+                    loanword, 'ژ',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::Sindhi, //+?
+                ScriptLanguage::Sindhi,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ٿ', 'ث', 'ج', 'ڄ', 'چ', 'ح', 'خ', 'د', 'ڊ', 'ذ', 'ر', 'ڙ',
                     'ز', 'ژ', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ڪ', 'گ', 'ل', 'م',
@@ -326,39 +386,46 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ڐ', 'ڱ', '۔', /*unverified*/
                     'آ', '۾', '۽', 'ٽ', 'ڏ', 'ڌ', 'ٺ', 'ڀ', 'ڇ', 'ڃ', 'ڍ', 'ڦ', '\u{64b}',
                     '\u{64c}', '\u{64e}', '\u{64f}', '\u{650}', '\u{651}', '\u{652}', '\u{670}',
-                    // loanwords
-                    'ؤ',
+                    loanword, 'ؤ',
                 ]
             ),
+            #[verification(Internet?)]
             (
-                ScriptLanguage::Urdu, //+?
+                ScriptLanguage::Urdu,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ٹ', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ڈ', 'ذ', 'ر', 'ڑ', 'ز',
                     'ژ', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن',
                     'ں', 'و', 'ہ', 'ء', 'ی', 'ے', 'ئ', 'ه', 'ھ', 'ي', 'ݲ',
                     '\u{658}', /*unverified*/
                     '۔', 'آ', '\u{64b}', '\u{64e}', '\u{64f}', '\u{650}', '\u{651}', '\u{652}',
-                    '\u{670}',
-                    // foreign 'ؤ'
+                    '\u{670}', //
+                    loanword, 'ؤ',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Uyghur,
                 [
                     'ا', 'ب', 'پ', 'ت', 'ج', 'چ', 'خ', 'د', 'ر', 'ز', 'ژ', 'س', 'ش', 'غ', 'ف', 'ق',
                     'ك', 'ڭ', 'ل', 'م', 'ن', 'و', 'ۇ', 'ۆ', 'ۋ', 'ې', 'ى', 'ي', 'ئ', 'ە', 'گ', 'ھ',
-                    'ه', // loanwords 'ص'
-                    /*unverified*/
-                    'ۈ',
+                    'ه', /*unverified*/
+                    'ۈ', //
+                    loanword, 'ص',
                 ]
             ),
-            // ScriptLanguage::UnknownArabic
+            ScriptLanguage::UnknownArabic,
         ]),
+        #[cfg(feature = "script_armenian")]
         Armenian => &[ScriptLanguage::Armenian],
+        #[cfg(feature = "script_avestan")]
         Avestan => &[ScriptLanguage::Avestan],
+        #[cfg(feature = "script_balinese")]
         Balinese => &[ScriptLanguage::BalineseBalinese],
+        #[cfg(feature = "script_bamum")]
         Bamum => &[ScriptLanguage::Bamum],
+        #[cfg(feature = "script_bassa_vah")]
         BassaVah => &[ScriptLanguage::Bassa],
+        #[cfg(feature = "script_batak")]
         Batak => &[
             ScriptLanguage::Angkola,
             ScriptLanguage::Karo,
@@ -367,43 +434,60 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
             ScriptLanguage::Simalungun,
             ScriptLanguage::TobaBatak,
         ],
+        #[cfg(feature = "script_bengali")]
         Bengali => alphabet_match!([
             (ScriptLanguage::Assamese, []),
             (ScriptLanguage::Bengali, []),
             (ScriptLanguage::BishnupriyaManipuri, []),
+            #[verification(Internet)]
             (
-                ScriptLanguage::MathBengali, //+
+                ScriptLanguage::MathBengali,
                 ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'],
                 true, // Strict (no other chars)
             ),
             (ScriptLanguage::MeiteiBengali, []),
         ]),
+        #[cfg(feature = "script_beria_erfe")]
         BeriaErfe => &[ScriptLanguage::ZaghawaBeriaErfe],
+        #[cfg(feature = "script_bhaiksuki")]
         Bhaiksuki => &[ScriptLanguage::SanskritBhaiksuki],
+        #[cfg(feature = "script_bopomofo")]
         Bopomofo => &[ScriptLanguage::ChineseMandarinBopomofo],
+        #[cfg(feature = "script_brahmi")]
         Brahmi => &[ScriptLanguage::SanskritBrahmi, ScriptLanguage::Prakrit],
+        #[cfg(feature = "script_braille")]
         Braille => alphabet_match!([
+            #[verification(Internet)]
             (
-                ScriptLanguage::MathBraille, //+
+                ScriptLanguage::MathBraille,
                 ['⠚', '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊']
             ),
             ScriptLanguage::UnknownBraille,
         ]),
+        #[cfg(feature = "script_buginese")]
         Buginese => &[
             ScriptLanguage::BugineseBuginese,
             ScriptLanguage::MakassareseBuginese,
         ],
+        #[cfg(feature = "script_buhid")]
         Buhid => &[ScriptLanguage::Buhid],
+        #[cfg(feature = "script_canadian_aboriginal")]
         CanadianAboriginal => &[
             ScriptLanguage::Cree,
             ScriptLanguage::Inuktitut,
             ScriptLanguage::Ojibwe,
         ],
+        #[cfg(feature = "script_carian")]
         Carian => &[ScriptLanguage::Carian],
+        #[cfg(feature = "script_caucasian_albanian")]
         CaucasianAlbanian => &[ScriptLanguage::CaucasianAlbanian],
+        #[cfg(feature = "script_chakma")]
         Chakma => &[ScriptLanguage::Chakma],
+        #[cfg(feature = "script_cham")]
         Cham => &[ScriptLanguage::ChamEastern, ScriptLanguage::ChamWestern],
+        #[cfg(feature = "script_cherokee")]
         Cherokee => &[ScriptLanguage::Cherokee],
+        #[cfg(feature = "script_chorasmian")]
         Chorasmian => &[ScriptLanguage::Chorasmian],
         // During parsing these considered as connectors
         // example1: `can't` for english is one word,
@@ -414,147 +498,278 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
         Common => match ch {
             '\'' => &[
                 // Cyrillic
+                #[cfg(feature = "script_cyrillic")]
                 ScriptLanguage::Belarusian,
+                #[cfg(feature = "script_cyrillic")]
                 ScriptLanguage::Nenets,
+                #[cfg(feature = "script_cyrillic")]
                 ScriptLanguage::Ossetian,
+                #[cfg(feature = "script_cyrillic")]
                 ScriptLanguage::Ukrainian,
                 // Latin
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Acehnese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Afrikaans,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::AkanTwi,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::AlbanianTosk,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Asturian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::AymaraCentral,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::AzerbaijaniNorth,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Balinese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Bambara,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Banjar,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Bemba,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Buginese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Catalan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Cebuano,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Chokwe,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::CreoleHaitian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Danish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Dholuo,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Dutch,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Dyula,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::English,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Faroese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Fijian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Filipino,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Finnish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Fon,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::French,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Friulian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::FulaPulaar,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::FulfuldeNigerian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::GaelicScottish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Galician,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Ganda,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::GuaraniParaguayan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Hausa,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Hawaiian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Icelandic,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Igbo,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Ilocano,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Indonesian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Irish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Italian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Javanese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Jingpho,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Kabuverdianu,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Kamba,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::KanuriCentral,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::KikongoKituba,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Kikuyu,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Kimbundu,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Kinyarwanda,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Ligurian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Limburgish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Lingala,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Lombard,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::LubaKasai,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Luxembourgish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::MalagasyPlateau,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Malay,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Maltese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Maori,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Minangkabau,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Mizo,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Mossi,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::NorwegianBokmal,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::NorwegianNynorsk,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Nyanja,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Occitan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::OromoSouthern,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::OromoWestCentral,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Pangasinan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Papiamento,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::QuechuaAyacucho,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Rundi,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Samoan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sango,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sardinian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sepedi,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Serer,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sesotho,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Shona,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sicilian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Somali,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Sundanese,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Swahili,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Swati,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::TamasheqLatin,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::TokPisin,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Tsonga,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Tswana,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Tumbuka,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Turkish,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Umbundu,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::UzbekNorthern,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Venetian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Welsh,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Wolof,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Xhosa,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Zulu,
             ],
-            '¡' => &[ScriptLanguage::AymaraCentral, ScriptLanguage::Spanish],
+            '¡' => &[
+                #[cfg(feature = "script_latin")]
+                ScriptLanguage::AymaraCentral,
+                #[cfg(feature = "script_latin")]
+                ScriptLanguage::Spanish,
+            ],
             '¿' => &[
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::AymaraCentral,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::QuechuaAyacucho,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Spanish,
             ],
             'ʻ' => &[
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Hawaiian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Samoan,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::UzbekNorthern,
             ],
             '·' => &[
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Catalan,
+                #[cfg(feature = "script_shavian")]
                 ScriptLanguage::EnglishShavian,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::French,
+                #[cfg(any(
+                    feature = "script_han",
+                    feature = "script_hiragana",
+                    feature = "script_katakana"
+                ))]
                 ScriptLanguage::Japanese,
+                #[cfg(any(feature = "script_han", feature = "script_hangul"))]
                 ScriptLanguage::Korean,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Latin,
+                #[cfg(feature = "script_latin")]
                 ScriptLanguage::Occitan,
             ],
             // 'ˈ' | 'ː' => &[ScriptLanguage::IPA],
             // '-' => bypassed for all langs in `word_iter`
             _ => &[], // must be always empty
         },
+        #[cfg(feature = "script_coptic")]
         Coptic => &[ScriptLanguage::Coptic],
+        #[cfg(feature = "script_cuneiform")]
         Cuneiform => &[
             ScriptLanguage::Akkadian,
             ScriptLanguage::Hittite,
             ScriptLanguage::LuwianCuneiform,
             ScriptLanguage::Sumerian,
         ],
+        #[cfg(feature = "script_cypriot")]
         Cypriot => &[ScriptLanguage::AncientGreek],
+        #[cfg(feature = "script_cypro_minoan")]
         CyproMinoan => &[ScriptLanguage::Minoan],
+        #[cfg(feature = "script_cyrillic")]
         Cyrillic => alphabet_match!([
+            #[verification(Internet)]
             (
-                ScriptLanguage::Abkhaz, //+
+                ScriptLanguage::Abkhaz,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Ӷ', 'ӷ', 'Ҕ', 'ҕ', 'Д', 'д', 'Е', 'е',
                     'Ж', 'ж', 'З', 'з', 'Ӡ', 'ӡ', 'И', 'и', 'К', 'к', 'Қ', 'қ', 'Ҟ', 'ҟ', 'Л', 'л',
@@ -564,8 +779,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ь', 'ь', 'Ә', 'ә',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Bashkir, //+
+                ScriptLanguage::Bashkir,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Ғ', 'ғ', 'Д', 'д', 'Ҙ', 'ҙ', 'Е', 'е',
                     'Ё', 'ё', 'Ж', 'ж', 'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Ҡ', 'ҡ', 'Л', 'л',
@@ -575,6 +791,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Belarusian,
                 [
@@ -584,6 +801,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ц', 'ц', 'Ч', 'ч', 'Ш', 'ш', 'Ы', 'ы', 'Ь', 'ь', 'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Bulgarian,
                 [
@@ -593,8 +811,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ш', 'ш', 'Щ', 'щ', 'Ъ', 'ъ', 'Ь', 'ь', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Buryat, //+
+                ScriptLanguage::Buryat,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё', 'Ж', 'ж',
                     'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н', 'О', 'о',
@@ -603,8 +822,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ь', 'ь', 'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Chechen, //+
+                ScriptLanguage::Chechen,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё', 'Ж', 'ж',
                     'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н', 'О', 'о',
@@ -613,6 +833,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Я', 'я', 'Ӏ', 'ӏ',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ChurchSlavonicOld,
                 [
@@ -624,8 +845,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ѭ', 'ѭ', 'Ѧ', 'ѧ', 'Ѩ', 'ѩ', 'Ѯ', 'ѯ', 'Ѱ', 'ѱ', 'Ѳ', 'ѳ', 'Ѵ', 'ѵ', 'Ҁ', 'ҁ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Chuvash, //+
+                ScriptLanguage::Chuvash,
                 [
                     'А', 'а', 'Ӑ', 'ӑ', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё',
                     'Ӗ', 'ӗ', 'Ж', 'ж', 'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м',
@@ -634,6 +856,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ы', 'ы', 'Ь', 'ь', 'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Kazakh,
                 [
@@ -645,6 +868,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Kyrgyz,
                 [
@@ -655,8 +879,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ь', 'ь', 'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Macedonian, //+
+                ScriptLanguage::Macedonian,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Ѓ', 'ѓ', 'Е', 'е', 'Ж', 'ж',
                     'З', 'з', 'Ѕ', 'ѕ', 'И', 'и', 'Ј', 'ј', 'К', 'к', 'Л', 'л', 'Љ', 'љ', 'М', 'м',
@@ -664,8 +889,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'У', 'у', 'Ф', 'ф', 'Х', 'х', 'Ц', 'ц', 'Ч', 'ч', 'Џ', 'џ', 'Ш', 'ш',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::MariEastern, //+
+                ScriptLanguage::MariEastern,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё', 'Ж', 'ж',
                     'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н', 'Ҥ', 'ҥ',
@@ -674,6 +900,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ь', 'ь', 'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::MongolianKhalkha,
                 [
@@ -684,8 +911,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Nenets, //+
+                ScriptLanguage::Nenets,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё', 'Ж', 'ж',
                     'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н', 'Ӈ', 'ӈ',
@@ -694,8 +922,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Ossetian, //+
+                ScriptLanguage::Ossetian,
                 [
                     'А', 'а', 'Ӕ', 'ӕ', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё',
                     'Ж', 'ж', 'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н',
@@ -704,8 +933,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(NativeSpeaker)]
             (
-                ScriptLanguage::Russian, //+++
+                ScriptLanguage::Russian,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Д', 'д', 'Е', 'е', 'Ё', 'ё', 'Ж', 'ж',
                     'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к', 'Л', 'л', 'М', 'м', 'Н', 'н', 'О', 'о',
@@ -714,6 +944,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Я', 'я',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Serbian,
                 [
@@ -723,8 +954,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ф', 'ф', 'Х', 'х', 'Ц', 'ц', 'Ч', 'ч', 'Џ', 'џ', 'Ш', 'ш',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Tajik, //+
+                ScriptLanguage::Tajik,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Ғ', 'ғ', 'Д', 'д', 'Е', 'е', 'Ё', 'ё',
                     'Ж', 'ж', 'З', 'з', 'И', 'и', 'Ӣ', 'ӣ', 'Й', 'й', 'К', 'к', 'Қ', 'қ', 'Л', 'л',
@@ -733,8 +965,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Э', 'э', 'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Tatar, //+
+                ScriptLanguage::Tatar,
                 [
                     'А', 'а', 'Ә', 'ә', 'Б', 'б', 'В', 'в', 'Ў', 'ў', 'Г', 'г', 'Ғ', 'ғ', 'Д', 'д',
                     'Е', 'е', 'Ё', 'ё', 'Ж', 'ж', 'Җ', 'җ', 'З', 'з', 'И', 'и', 'Й', 'й', 'К', 'к',
@@ -744,8 +977,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ю', 'ю', 'Я', 'я',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Ukrainian, //+
+                ScriptLanguage::Ukrainian,
                 [
                     'А', 'а', 'Б', 'б', 'В', 'в', 'Г', 'г', 'Ґ', 'ґ', 'Д', 'д', 'Е', 'е', 'Є', 'є',
                     'Ж', 'ж', 'З', 'з', 'И', 'и', 'І', 'і', 'Ї', 'ї', 'Й', 'й', 'К', 'к', 'Л', 'л',
@@ -756,8 +990,11 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
             ),
             ScriptLanguage::UnknownCyrillic
         ]),
+        #[cfg(feature = "script_deseret")]
         Deseret => &[ScriptLanguage::EnglishDeseret],
+        #[cfg(feature = "script_devanagari")]
         Devanagari => alphabet_match!([
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Awadhi,
                 [
@@ -766,6 +1003,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Bhojpuri,
                 [
@@ -774,6 +1012,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Chhattisgarhi,
                 [
@@ -782,6 +1021,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Dogri,
                 [
@@ -790,6 +1030,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Hindi,
                 [
@@ -798,6 +1039,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::KashmiriDevanagari,
                 [
@@ -806,6 +1048,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'प', 'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Magahi,
                 [
@@ -814,6 +1057,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Maithili,
                 [
@@ -822,6 +1066,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Marathi,
                 [
@@ -830,11 +1075,13 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह', 'ळ',
                 ],
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::MathDevanagari, //+
+                ScriptLanguage::MathDevanagari,
                 ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
                 true, // Strict (no other chars)
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Nepali,
                 [
@@ -843,6 +1090,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Sanskrit,
                 [
@@ -852,6 +1100,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::SanskritVedic,
                 [
@@ -861,6 +1110,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'स', 'ह', 'ळ',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::SaurashtraDevanagari,
                 [
@@ -869,6 +1119,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::SindhiDevanagari,
                 [
@@ -877,6 +1128,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Wancho,
                 [
@@ -885,37 +1137,56 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल', 'व', 'श', 'ष', 'स', 'ह',
                 ],
             ),
-            // ScriptLanguage::UnknownDevanagari
+            ScriptLanguage::UnknownDevanagari,
         ]),
+        #[cfg(feature = "script_dives_akuru")]
         DivesAkuru => &[ScriptLanguage::DhivehiDivesAkuru],
+        #[cfg(feature = "script_dogra")]
         Dogra => &[ScriptLanguage::DogriDogra],
+        #[cfg(feature = "script_duployan")]
         Duployan => &[
             ScriptLanguage::EnglishDuployan,
             ScriptLanguage::FrenchDuployan,
         ],
+        #[cfg(feature = "script_egyptian_hieroglyphs")]
         EgyptianHieroglyphs => &[ScriptLanguage::EgyptianHieroglyphs],
+        #[cfg(feature = "script_elbasan")]
         Elbasan => &[ScriptLanguage::AlbanianElbasan],
+        #[cfg(feature = "script_elymaic")]
         Elymaic => &[ScriptLanguage::AramaicElymaic],
+        #[cfg(feature = "script_ethiopic")]
         Ethiopic => &[
             ScriptLanguage::Amharic,
             ScriptLanguage::Geez,
             // ScriptLanguage::Oromo,
             ScriptLanguage::Tigrinya,
         ],
+        #[cfg(feature = "script_garay")]
         Garay => &[ScriptLanguage::WolofGaray],
+        #[cfg(feature = "script_georgian")]
         Georgian => &[ScriptLanguage::Georgian],
+        #[cfg(feature = "script_glagolitic")]
         Glagolitic => &[ScriptLanguage::ChurchSlavonicOldGlagolitic],
+        #[cfg(feature = "script_gothic")]
         Gothic => &[ScriptLanguage::Gothic],
+        #[cfg(feature = "script_grantha")]
         Grantha => &[
             ScriptLanguage::SanskritGrantha,
             ScriptLanguage::TamilGrantha,
         ],
+        #[cfg(feature = "script_greek")]
         Greek => &[ScriptLanguage::Greek],
+        #[cfg(feature = "script_gujarati")]
         Gujarati => &[ScriptLanguage::Gujarati],
+        #[cfg(feature = "script_gunjala_gondi")]
         GunjalaGondi => &[ScriptLanguage::GondiGunjala],
+        #[cfg(feature = "script_gurmukhi")]
         Gurmukhi => &[ScriptLanguage::PunjabiEastern],
+        #[cfg(feature = "script_gurung_khema")]
         GurungKhema => &[ScriptLanguage::GurungKhema],
+        #[cfg(feature = "script_han")]
         Han => alphabet_match!([
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ChineseMandarinSimplified,
                 [
@@ -1004,6 +1275,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '袭', '钱', '场', '码', '窑', '乔', '随', '历', '扩', '绪', '飞', '愿', '虫',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ChineseMandarinTraditional,
                 [
@@ -1095,6 +1367,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '麼', '瞇', '隻', '顚',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::ChineseCantoneseTraditional,
                 [
@@ -1189,6 +1462,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '麼', '瞇', '隻', '顚',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 // Hanja
                 ScriptLanguage::Korean,
@@ -1294,6 +1568,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖', '拾', '佰', '仟', '阡',
                 ]
             ), */
+            #[verification(Unverified)]
             (
                 // Kanji, also uses all Traditional Chinese characters
                 ScriptLanguage::Japanese,
@@ -1438,62 +1713,90 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                 ]
             ),
         ]),
+        #[cfg(feature = "script_hangul")]
         Hangul => &[ScriptLanguage::Korean],
+        #[cfg(feature = "script_hanifi_rohingya")]
         HanifiRohingya => &[ScriptLanguage::Rohingya],
+        #[cfg(feature = "script_hanunoo")]
         Hanunoo => &[ScriptLanguage::Hanunoo],
+        #[cfg(feature = "script_hatran")]
         Hatran => &[ScriptLanguage::AramaicHatran],
+        #[cfg(feature = "script_hebrew")]
         Hebrew => alphabet_match!([
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Hebrew,
                 [
-                    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'כ', 'ל', 'מ', 'נ', 'ס', 'ע',
-                    'פ', 'צ', 'ק', 'ר', 'ש', 'ת'
+                    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'כ', 'ך', 'ל', 'מ', 'ם', 'נ',
+                    'ן', 'ס', 'ע', 'פ', 'ף', 'צ', 'ץ', 'ק', 'ר', 'ש', 'ת'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::YiddishEastern,
                 [
-                    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'ײ', 'כ', 'ל', 'מ', 'נ', 'ס',
-                    'ע', 'פ', /* 'פּ' */ '\u{5bc}', /* 'פֿ' */ '\u{5bf}', 'צ', 'ק',
-                    'ר', 'ש', 'ת', 'װ'
+                    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'ײ', 'כ', 'ך', 'ל', 'מ', 'ם',
+                    'נ', 'ן', 'ס', 'ע', 'פ', 'ף', /* 'פּ' */ '\u{5bc}',
+                    /* 'פֿ' */ '\u{5bf}', 'צ', 'ץ', 'ק', 'ר', 'ש', 'ת', 'װ'
                 ]
             ),
+            ScriptLanguage::UnknownHebrew,
         ]),
+        #[cfg(feature = "script_hiragana")]
         Hiragana => &[ScriptLanguage::Japanese],
+        #[cfg(feature = "script_imperial_aramaic")]
         ImperialAramaic => &[ScriptLanguage::AramaicImperial],
         Inherited => &[], // must be always empty
+        #[cfg(feature = "script_inscriptional_pahlavi")]
         InscriptionalPahlavi => &[ScriptLanguage::MiddlePersianPahlaviInscriptional],
+        #[cfg(feature = "script_inscriptional_parthian")]
         InscriptionalParthian => &[ScriptLanguage::Parthian],
+        #[cfg(feature = "script_javanese")]
         Javanese => &[ScriptLanguage::JavaneseJavanese],
+        #[cfg(feature = "script_kaithi")]
         Kaithi => &[
             ScriptLanguage::BhojpuriKaithi,
             ScriptLanguage::HindiKaithi,
             ScriptLanguage::MagahiKaithi,
             ScriptLanguage::MaithiliKaithi,
         ],
+        #[cfg(feature = "script_kannada")]
         Kannada => &[ScriptLanguage::Kannada, ScriptLanguage::Tulu],
+        #[cfg(feature = "script_katakana")]
         Katakana => &[ScriptLanguage::Japanese],
+        #[cfg(feature = "script_kawi")]
         Kawi => &[ScriptLanguage::OldJavanese, ScriptLanguage::SanskritKawi],
+        #[cfg(feature = "script_kayah_li")]
         KayahLi => &[ScriptLanguage::KayahEastern, ScriptLanguage::KayahWestern],
+        #[cfg(feature = "script_kharoshthi")]
         Kharoshthi => &[ScriptLanguage::Gandhari],
+        #[cfg(feature = "script_khitan_small_script")]
         KhitanSmallScript => &[ScriptLanguage::Khitan],
+        #[cfg(feature = "script_khmer")]
         Khmer => &[ScriptLanguage::Khmer, ScriptLanguage::KhmerNorthern],
+        #[cfg(feature = "script_khojki")]
         Khojki => &[ScriptLanguage::KutchiKhojki, ScriptLanguage::SindhiKhojki],
+        #[cfg(feature = "script_khudawadi")]
         Khudawadi => &[ScriptLanguage::SindhiKhudawadi],
+        #[cfg(feature = "script_kirat_rai")]
         KiratRai => &[ScriptLanguage::Bantawa],
+        #[cfg(feature = "script_lao")]
         Lao => &[ScriptLanguage::Lao],
+        #[cfg(feature = "script_latin")]
         Latin => alphabet_match!([
+            #[verification(Internet)]
             (
-                ScriptLanguage::Acehnese, //+
+                ScriptLanguage::Acehnese,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r',
                     'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', 'É', 'é', 'È', 'è', 'Ë', 'ë',
-                    'Ô', 'ô', 'Ö', 'ö' // loanwords F, Q, V, X, Z
+                    'Ô', 'ô', 'Ö', 'ö', loanword, 'F', 'f', 'Q', 'q', 'V', 'v', 'X', 'x', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Afrikaans, //++
+                ScriptLanguage::Afrikaans,
                 [
                     'Á', 'á', 'A', 'a', 'Ä', 'ä', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'É', 'é',
                     'È', 'è', 'Ê', 'ê', 'Ë', 'ë', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'Í', 'í',
@@ -1503,28 +1806,29 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ý', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::AkanFante, //+
+                ScriptLanguage::AkanFante,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'K', 'k', 'M', 'm', 'N', 'n', 'O', 'o', 'Ɔ', 'ɔ', 'P', 'p', 'R', 'r',
                     'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::AkanTwi, //++
+                ScriptLanguage::AkanTwi,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'Ɔ', 'ɔ', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', 'Ã', 'ã', 'Á', 'á',
-                    'Ẽ', 'ẽ', 'Ĩ', 'ĩ', 'Õ', 'õ', 'Ũ',
-                    'ũ',
-                    // any more diacritics?
-                    // loanwords C, J, Q, V, X, Z
+                    'Ẽ', 'ẽ', 'Ĩ', 'ĩ', 'Õ', 'õ', 'Ũ', 'ũ', // any more diacritics?
+                    loanword, 'C', 'c', 'J', 'j', 'Q', 'q', 'V', 'v', 'X', 'x', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::AlbanianTosk, //+
+                ScriptLanguage::AlbanianTosk,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', /* 'Dh', 'dh', */ 'E',
                     'e', 'Ë', 'ë', 'F', 'f', 'G', 'g', /* 'Gj', 'gj', */ 'H', 'h', 'I', 'i',
@@ -1535,8 +1839,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     /* 'Xh', 'xh', */ 'Y', 'y', 'Z', 'z', /* 'Zh', 'zh', */
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Asturian, //+
+                ScriptLanguage::Asturian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -1545,8 +1850,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ü', 'ü',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::AymaraCentral, //++
+                ScriptLanguage::AymaraCentral,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -1555,8 +1861,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Í', 'í', 'Ó', 'ó', 'Ú', 'ú',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::AzerbaijaniNorth, //++
+                ScriptLanguage::AzerbaijaniNorth,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'Ə', 'ə', 'F', 'f',
                     'G', 'g', 'Ğ', 'ğ', 'H', 'h', 'X', 'x', 'I', 'ı', 'İ', 'i', 'J', 'j', 'K', 'k',
@@ -1564,8 +1871,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'S', 's', 'Ş', 'ş', 'T', 't', 'U', 'u', 'Ü', 'ü', 'V', 'v', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Balinese, //++
+                ScriptLanguage::Balinese,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1573,8 +1881,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Bambara, //++
+                ScriptLanguage::Bambara,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ɲ', 'ɲ',
@@ -1586,8 +1895,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     // No ɳ
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Banjar, //+
+                ScriptLanguage::Banjar,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1595,8 +1905,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z', 'É', 'é'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Basque, //++
+                ScriptLanguage::Basque,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -1604,16 +1915,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'X', 'x', 'Y', 'y', 'Z', 'z', 'Ç', 'ç', 'Á', 'á', 'É', 'é'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Bemba, //+
+                ScriptLanguage::Bemba,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Bosnian, //++
+                ScriptLanguage::Bosnian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Č', 'č', 'Ć', 'ć', 'D', 'd', 'Đ', 'đ', 'E', 'e',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l',
@@ -1622,16 +1935,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ž', 'ž',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Buginese, //+
+                ScriptLanguage::Buginese,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'Q', 'q', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Catalan, //++
+                ScriptLanguage::Catalan,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1640,26 +1955,28 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ŀ', 'ŀ', 'Ó', 'ó', 'Ò', 'ò', 'Ú', 'ú', 'Ü', 'ü',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Cebuano, //++
+                ScriptLanguage::Cebuano,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'I', 'i', 'K', 'k',
                     'L', 'l', 'M', 'm', 'N', 'n', /* 'Ng', 'ng', */ 'O', 'o', 'P', 'p', 'R',
-                    'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y',
-                    'y',
-                    // loanwords c, f, j, q, v, x, z
+                    'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', loanword, 'C', 'c', 'F',
+                    'f', 'J', 'j', 'Q', 'q', 'V', 'v', 'X', 'x', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Chokwe, //+
+                ScriptLanguage::Chokwe,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::CreoleHaitian, //+
+                ScriptLanguage::CreoleHaitian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1667,8 +1984,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'À', 'à', 'È', 'è', 'É', 'é', 'Ò', 'ò'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Croatian, //++
+                ScriptLanguage::Croatian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Č', 'č', 'Ć', 'ć', 'D', 'd', 'Đ', 'đ', 'E', 'e',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm',
@@ -1676,8 +1994,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'V', 'v', 'Z', 'z', 'Ž', 'ž'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Czech, //+
+                ScriptLanguage::Czech,
                 [
                     'A', 'a', 'Á', 'á', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'Ď', 'ď', 'E', 'e',
                     'È', 'è', 'É', 'é', 'Ě', 'ě', 'F', 'f', 'G', 'g', 'H', 'h',
@@ -1688,8 +2007,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ø',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Danish, //+
+                ScriptLanguage::Danish,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1697,16 +2017,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z', 'Å', 'å', 'Æ', 'æ', 'É', 'é', 'Ø', 'ø',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Dholuo, //++
+                ScriptLanguage::Dholuo,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::DinkaSouthwestern, //++
+                ScriptLanguage::DinkaSouthwestern,
                 #[rustfmt::skip]
                 [
                     'Ä', 'ä', 'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ë', 'ë', 'Ɛ', 'ɛ',
@@ -1718,8 +2040,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f0890}', // Ɛ̈ɛ̈
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Dutch, //+
+                ScriptLanguage::Dutch,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1728,8 +2051,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ĳ', 'ĳ', 'Ó', 'ó', 'Ö', 'ö', 'Ü', 'ü',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Dyula, //+
+                ScriptLanguage::Dyula,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ɲ', 'ɲ',
@@ -1737,8 +2061,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(NativeSpeaker)]
             (
-                ScriptLanguage::English, //+++
+                ScriptLanguage::English,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -1746,8 +2071,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Esperanto, //+
+                ScriptLanguage::Esperanto,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ĉ', 'ĉ', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g',
                     'Ĝ', 'ĝ', 'H', 'h', 'Ĥ', 'ĥ', 'I', 'i', 'J', 'j', 'Ĵ', 'ĵ', 'K', 'k', 'L', 'l',
@@ -1755,18 +2081,20 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'U', 'u', 'Ŭ', 'ŭ', 'V', 'v', 'X', 'x', 'Y', 'y', 'Z', 'z', // XY?
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Estonian, //++
+                ScriptLanguage::Estonian,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r',
                     'S', 's', 'Š', 'š', 'Z', 'z', 'Ž', 'ž', 'T', 't', 'U', 'u', 'V', 'v', 'Õ', 'õ',
-                    'Ä', 'ä', 'Ö', 'ö', 'Ü', 'ü',
-                    // foreign words C, Q, W, X, Y
+                    'Ä', 'ä', 'Ö', 'ö', 'Ü', 'ü', loanword, 'C', 'c', 'Q', 'q', 'W', 'w', 'X', 'x',
+                    'Y', 'y',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Ewe, //+
+                ScriptLanguage::Ewe,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'Ð', 'Ɖ', 'ɖ', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'Ƒ',
                     'ƒ', 'G', 'g', 'Ɣ', 'ɣ', 'H', 'h', 'I', 'i', 'K', 'k', 'L', 'l', 'M', 'm', 'N',
@@ -1775,8 +2103,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'à', 'È', 'è', 'Ẽ', 'ẽ', 'É', 'é', 'Ĩ', 'ĩ', 'Í', 'í', 'Ò', 'ò',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Faroese, //+
+                ScriptLanguage::Faroese,
                 [
                     'A', 'a', 'Á', 'á', 'B', 'b', 'D', 'd', 'Ð', 'ð', 'E', 'e', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'Í', 'í', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
@@ -1784,18 +2113,19 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'V', 'v', 'Y', 'y', 'Ý', 'ý', 'Æ', 'æ', 'Ø', 'ø'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Fijian, //+
+                ScriptLanguage::Fijian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'Q', 'q',
-                    'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y',
-                    'y',
-                    // loanwords 'H', 'h',
+                    'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', loanword,
+                    'H', 'h',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Filipino, //+
+                ScriptLanguage::Filipino,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -1803,8 +2133,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Finnish, //++
+                ScriptLanguage::Finnish,
                 [
                     'A', 'a', 'Ä', 'ä', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o',
@@ -1812,8 +2143,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Fon, //+
+                ScriptLanguage::Fon,
                 [
                     'A', 'a', 'À', 'à', 'Á', 'á', 'Ǎ', 'ǎ', 'B', 'b', 'C', 'c', 'D', 'd', 'Ð', 'Ɖ',
                     'ɖ', 'Ě', 'ě', 'É', 'é', 'È', 'è', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g', 'H',
@@ -1823,8 +2155,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'w', 'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::French, //+
+                ScriptLanguage::French,
                 [
                     'Æ', 'æ', 'À', 'à', 'Â', 'â', 'A', 'a', 'B', 'b', 'Ç', 'ç', 'C', 'c', 'D', 'd',
                     'É', 'é', 'E', 'e', 'Ê', 'ê', 'È', 'è', 'Ë', 'ë', 'F', 'f', 'G', 'g', 'H', 'h',
@@ -1834,19 +2167,20 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ÿ', 'ÿ', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Friulian, //++
+                ScriptLanguage::Friulian,
                 [
                     'A', 'a', 'Â', 'â', 'À', 'à', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'Ê', 'ê',
                     'È', 'è', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'Ì', 'ì', 'Î', 'î',
                     'J', 'j', 'L', 'l', 'M', 'm', 'N', 'n', 'Ò', 'ò', 'Ô', 'ô', 'O', 'o', 'P', 'p',
-                    'R', 'r', 'S', 's', 'T', 't', 'Ù', 'ù', 'Û', 'û', 'U', 'u', 'V', 'v', 'Z',
-                    'z',
-                    // loanwords 'K', 'k', 'Y', 'y', 'W', 'w', 'X', 'x'
+                    'R', 'r', 'S', 's', 'T', 't', 'Ù', 'ù', 'Û', 'û', 'U', 'u', 'V', 'v', 'Z', 'z',
+                    loanword, 'K', 'k', 'Y', 'y', 'W', 'w', 'X', 'x',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::FulaPulaar, //++
+                ScriptLanguage::FulaPulaar,
                 [
                     'A', 'a', 'B', 'b', 'Ɓ', 'ɓ', 'C', 'c', 'D', 'd', 'Ɗ', 'ɗ', 'E', 'e', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
@@ -1854,8 +2188,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'X', 'x', 'Y', 'y', 'Ƴ', 'ƴ', 'ʣ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::FulaPular, //+
+                ScriptLanguage::FulaPular,
                 [
                     'A', 'a', 'B', 'b', 'Ɓ', 'ɓ', 'C', 'c', 'D', 'd', 'Ɗ', 'ɗ', 'E', 'e', 'F', 'f',
                     'G', 'g', 'Ɠ', 'ɠ', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm',
@@ -1863,8 +2198,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'U', 'u', 'W', 'w', 'Y', 'y', 'Ƴ', 'ƴ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::FulfuldeNigerian, //+
+                ScriptLanguage::FulfuldeNigerian,
                 [
                     'A', 'a', 'B', 'b', 'Ɓ', 'ɓ', 'C', 'c', 'D', 'd', 'Ɗ', 'ɗ', 'E', 'e', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
@@ -1872,28 +2208,30 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'X', 'x', 'Y', 'y', 'Ƴ', 'ƴ',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::GaelicScottish, //++
+                ScriptLanguage::GaelicScottish,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r', 'S', 's',
-                    'T', 't', 'U', 'u', 'À', 'à', 'È', 'è', 'Ì', 'ì', 'Ò', 'ò', 'Ù',
-                    'ù',
-                    // foreign words J, K, Q, V, W, X, Y, Z
+                    'T', 't', 'U', 'u', 'À', 'à', 'È', 'è', 'Ì', 'ì', 'Ò', 'ò', 'Ù', 'ù', loanword,
+                    'J', 'j', 'K', 'k', 'Q', 'q', 'V', 'v', 'W', 'w', 'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Galician, //++
+                ScriptLanguage::Galician,
                 [
                     'Á', 'á', 'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'É', 'é', 'E', 'e', 'F', 'f',
                     'G', 'g', 'H', 'h', 'Í', 'í', 'I', 'i', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ',
                     'Ó', 'ó', 'O', 'o', 'P', 'p', 'Q', 'q', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u',
-                    'Ú', 'ú', 'V', 'v', 'X', 'x', 'Z', 'z',
-                    // foreign words J, K, W, Y
+                    'Ú', 'ú', 'V', 'v', 'X', 'x', 'Z', 'z', loanword, 'J', 'j', 'K', 'k', 'W', 'w',
+                    'Y', 'y',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Ganda, //+
+                ScriptLanguage::Ganda,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ŋ', 'ŋ', 'O', 'o',
@@ -1901,8 +2239,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::German, //+
+                ScriptLanguage::German,
                 [
                     'Ä', 'ä', 'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o',
@@ -1910,8 +2249,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'U', 'u', 'V', 'v', 'W', 'w', 'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::GuaraniParaguayan, //++
+                ScriptLanguage::GuaraniParaguayan,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'Ã', 'ã', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ẽ', 'ẽ', 'G', 'g',
@@ -1925,8 +2265,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f0367}', // G̃g̃
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Hausa, //++
+                ScriptLanguage::Hausa,
                 [
                     'A', 'a', 'B', 'b', 'Ɓ', 'ɓ', 'C', 'c', 'D', 'd', 'Ɗ', 'ɗ', 'E', 'e', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'Ƙ', 'ƙ', 'L', 'l', 'M', 'm',
@@ -1936,16 +2277,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ã', 'ã', 'Ā', 'ā', 'Õ', 'õ', 'Ũ', 'ũ', 'Ĩ', 'ĩ', 'Ẽ', 'ẽ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Hawaiian, //+
+                ScriptLanguage::Hawaiian,
                 [
                     'A', 'a', 'E', 'e', 'I', 'i', 'O', 'o', 'U', 'u', 'H', 'h', 'K', 'k', 'L', 'l',
                     'M', 'm', 'N', 'n', 'P', 'p', 'W', 'w', 'Ā', 'ā', 'Ē', 'ē', 'Ī', 'ī', 'Ō', 'ō',
                     'Ū', 'ū'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Hungarian, //++
+                ScriptLanguage::Hungarian,
                 [
                     'A', 'a', 'Á', 'á', 'B', 'b', 'C', 'c', /* 'Cs', 'cs', */ 'D', 'd',
                     /* 'Dz', 'dz', 'Dzs', 'dzs', */ 'E', 'e', 'É', 'é', 'F', 'f', 'G', 'g',
@@ -1957,19 +2300,20 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'z', /* 'Zs', 'zs' */
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Icelandic, //++
+                ScriptLanguage::Icelandic,
                 [
                     'A', 'a', 'Á', 'á', 'Æ', 'æ', 'B', 'b', 'D', 'd', 'Ð', 'ð', 'E', 'e', 'É', 'é',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'Í', 'í', 'J', 'j', 'K', 'k', 'L', 'l',
                     'M', 'm', 'N', 'n', 'O', 'o', 'Ó', 'ó', 'Ö', 'ö', 'P', 'p', 'R', 'r', 'S', 's',
-                    'T', 't', 'U', 'u', 'Ú', 'ú', 'V', 'v', 'X', 'x', 'Y', 'y', 'Ý', 'ý', 'Þ',
-                    'þ',
-                    // obsolete 'Z', 'z'
+                    'T', 't', 'U', 'u', 'Ú', 'ú', 'V', 'v', 'X', 'x', 'Y', 'y', 'Ý', 'ý', 'Þ', 'þ',
+                    extended, 'Z', 'z', // obsolete
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Igbo, //+
+                ScriptLanguage::Igbo,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'Ị', 'ị', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ṅ', 'ṅ',
@@ -1978,21 +2322,22 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Á', 'á', 'É', 'é', 'Í', 'í', 'Ó', 'ó', 'Ú', 'ú'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Ilocano, //++
+                ScriptLanguage::Ilocano,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'I', 'i', 'K', 'k',
                     'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r', 'S', 's', 'T', 't',
                     'U', 'u', 'W', 'w', 'Y', 'y',
                     /*'Á', 'á', 'É', 'é', 'Í', 'í', 'Ó', 'ó', 'Ú', 'ú',*/
                     // Spanish-based
-                    'C', 'c', 'F', 'f', 'J', 'j', 'Ñ', 'ñ', 'V', 'v', 'Z',
-                    'z',
-                    // loanwords Q, X,
+                    'C', 'c', 'F', 'f', 'J', 'j', 'Ñ', 'ñ', 'V', 'v', 'Z', 'z', loanword, 'Q', 'q',
+                    'X', 'x',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Indonesian, //+
+                ScriptLanguage::Indonesian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2000,27 +2345,29 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Irish, //++
+                ScriptLanguage::Irish,
                 [
                     'A', 'a', 'Á', 'á', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'É', 'é', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'Í', 'í', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o',
                     'Ó', 'ó', 'P', 'p', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'Ú', 'ú',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Italian, //++
+                ScriptLanguage::Italian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'Q', 'q',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'Z', 'z', 'À', 'à', 'È', 'è',
-                    'É', 'é', 'Ì', 'ì', 'Í', 'í', 'Ò', 'ò', 'Ù', 'ù', 'Ú',
-                    'ú',
-                    // loanwords 'W', 'w', 'Y', 'y'
+                    'É', 'é', 'Ì', 'ì', 'Í', 'í', 'Ò', 'ò', 'Ù', 'ù', 'Ú', 'ú', loanword, 'W', 'w',
+                    'Y', 'y',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Javanese, //+
+                ScriptLanguage::Javanese,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2029,16 +2376,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'É', 'é', 'È', 'è'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Jingpho, //++
+                ScriptLanguage::Jingpho,
                 [
                     'A', 'a', 'E', 'e', 'I', 'i', 'O', 'o', 'U', 'u', 'B', 'b', 'C', 'c', 'D', 'd',
                     'G', 'g', 'H', 'h', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kabiye, //++
+                ScriptLanguage::Kabiye,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'Ð', 'Ɖ', 'ɖ', 'E', 'e', 'Ɛ', 'ɛ', 'F',
@@ -2050,6 +2399,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f046e}', // N̄n̄
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Kabuverdianu,
                 [
@@ -2059,27 +2409,29 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Á', 'á', 'É', 'é', 'Í', 'í', 'Ó', 'ó', 'Ú', 'ú', 'Õ', 'õ', 'Ç', 'ç', 'Ê', 'ê'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kabyle, //++
+                ScriptLanguage::Kabyle,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'Ḍ', 'ḍ', 'E', 'e', 'F', 'f',
                     'G', 'g', 'Ǧ', 'ǧ', 'Ɣ', 'ɣ', 'H', 'h', 'Ḥ', 'ḥ', 'I', 'i', 'J', 'j', 'K', 'k',
                     'L', 'l', 'M', 'm', 'N', 'n', 'Q', 'q', 'R', 'r', 'Ṛ', 'ṛ', 'S', 's', 'Ṣ', 'ṣ',
                     'T', 't', 'Ṭ', 'ṭ', 'U', 'u', 'W', 'w', 'X', 'x', 'Y', 'y', 'Z', 'z', 'Ẓ', 'ẓ',
-                    'Ɛ', 'ɛ',
-                    // loanwords O, P
+                    'Ɛ', 'ɛ', loanword, 'O', 'o', 'P', 'p',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kamba, //++
+                ScriptLanguage::Kamba,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'Ĩ', 'ĩ', 'I', 'i',
                     'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'S', 's', 'T', 't', 'U', 'u',
                     'Ũ', 'ũ', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::KanuriCentral, //++
+                ScriptLanguage::KanuriCentral,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ǝ', 'ǝ', 'ə', 'F', 'f', 'G',
                     'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O',
@@ -2087,6 +2439,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::KikongoKituba,
                 [
@@ -2095,8 +2448,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kikuyu, //++
+                ScriptLanguage::Kikuyu,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'I', 'i',
                     'Ĩ', 'ĩ', 'J', 'j', 'K', 'k', 'M', 'm', 'N', 'n', 'O', 'o', 'R', 'r', 'T', 't',
@@ -2106,8 +2460,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     // must be absent P, Q, V, X, Z
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Kimbundu, //++
+                ScriptLanguage::Kimbundu,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'S', 's',
@@ -2117,8 +2472,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ê', 'ê', 'Ô', 'ô'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Kinyarwanda, //+
+                ScriptLanguage::Kinyarwanda,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r',
@@ -2126,8 +2482,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     /*unverified*/ 'L', 'l', 'Í', 'í', 'Ú', 'ú',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::KurdishNorthern, //+
+                ScriptLanguage::KurdishNorthern,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'Ê', 'ê', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'Î', 'î', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm',
@@ -2135,8 +2492,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'U', 'u', 'Û', 'û', 'V', 'v', 'W', 'w', 'X', 'x', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Latgalian, //++
+                ScriptLanguage::Latgalian,
                 [
                     'A', 'a', 'Ā', 'ā', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'E', 'e', 'Ē', 'ē',
                     'F', 'f', 'G', 'g', 'Ģ', 'ģ', 'H', 'h', 'I', 'i', 'Y', 'y', 'Ī', 'ī', 'J', 'j',
@@ -2145,8 +2503,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'V', 'v', 'Z', 'z', 'Ž', 'ž',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Latin, //+
+                ScriptLanguage::Latin,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'Q', 'q',
@@ -2154,8 +2513,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ā', 'ā', 'Ē', 'ē', 'Ī', 'ī', 'Ō', 'ō', 'Ū', 'ū', 'Æ', 'æ', 'Œ', 'œ'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Latvian, //++
+                ScriptLanguage::Latvian,
                 [
                     'A', 'a', 'Ā', 'ā', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'E', 'e', 'Ē', 'ē',
                     'F', 'f', 'G', 'g', 'Ģ', 'ģ', 'H', 'h', 'I', 'i', 'Ī', 'ī', 'J', 'j', 'K', 'k',
@@ -2164,17 +2524,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Ligurian, //++
+                ScriptLanguage::Ligurian,
                 [
                     'A', 'a', 'Æ', 'æ', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'F', 'f',
                     'G', 'g', 'H', 'h', 'I', 'i', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'Q', 'q', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'X', 'x', 'Z', 'z',
                     'À', 'à', 'Â', 'â', 'É', 'é', 'È', 'è', 'Ê', 'ê', 'Ì', 'ì', 'Î', 'î', 'Ò', 'ò',
-                    'Ô', 'ô', 'Ö', 'ö', 'Ù', 'ù', 'Û', 'û',
-                    // loanwords 'J', 'j', 'Ó', 'ó',
+                    'Ô', 'ô', 'Ö', 'ö', 'Ù', 'ù', 'Û', 'û', loanword, 'J', 'j', 'Ó', 'ó',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Limburgish,
                 [
@@ -2186,8 +2547,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ö', 'ö', 'Ü', 'ü'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Lingala, //++
+                ScriptLanguage::Lingala,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g',
@@ -2202,11 +2564,12 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f025b}', // Ɛ̂ɛ̂
                     '\u{f0c54}', // Ɔ̌ɔ̌
                     '\u{f0c5b}', // Ɛ̌ɛ̌
-                    // loanwords J, X
+                    loanword, 'J', 'j', 'X', 'x',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Lithuanian, //++
+                ScriptLanguage::Lithuanian,
                 [
                     'A', 'a', 'Ą', 'ą', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'E', 'e', 'Ę', 'ę',
                     'Ė', 'ė', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'Į', 'į', 'Y', 'y', 'J', 'j',
@@ -2214,8 +2577,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Š', 'š', 'T', 't', 'U', 'u', 'Ų', 'ų', 'Ū', 'ū', 'V', 'v', 'Z', 'z', 'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Lombard, //++
+                ScriptLanguage::Lombard,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'Q', 'q',
@@ -2224,8 +2588,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ö', 'ö', 'Ù', 'ù', 'Ü', 'ü', 'Œ', 'œ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::LubaKasai, //+
+                ScriptLanguage::LubaKasai,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2233,6 +2598,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     /*unverified*/ 'Î', 'î', 'Ê', 'ê',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Luxembourgish,
                 [
@@ -2244,6 +2610,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'È', 'è'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::MalagasyPlateau,
                 [
@@ -2253,8 +2620,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ô', 'ô'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Malay, //+
+                ScriptLanguage::Malay,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2262,8 +2630,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Maltese, //+
+                ScriptLanguage::Maltese,
                 [
                     'A', 'a', 'B', 'b', 'Ċ', 'ċ', 'D', 'd', 'E', 'e', 'F', 'f', 'Ġ', 'ġ', 'G', 'g',
                     'H', 'h', 'Ħ', 'ħ', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
@@ -2271,26 +2640,28 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'X', 'x', 'Ż', 'ż', 'Z', 'z', /*unverified*/ 'À', 'à', 'C', 'c',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Mandailing, //+
+                ScriptLanguage::Mandailing,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r',
                     'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Maori, //++
+                ScriptLanguage::Maori,
                 [
                     'A', 'a', 'E', 'e', 'H', 'h', 'I', 'i', 'K', 'k', 'M', 'm', 'N', 'n', 'O', 'o',
                     'P', 'p', 'R', 'r', 'T', 't', 'U', 'u', 'W', 'w', 'G', 'g', 'Ā', 'ā', 'Ē', 'ē',
-                    'Ī', 'ī', 'Ō', 'ō', 'Ū', 'ū', //
-                    // Southern dialect
-                    'L', 'l', 'Ḵ', 'ḵ',
+                    'Ī', 'ī', 'Ō', 'ō', 'Ū', 'ū', extended, 'L', 'l', 'Ḵ',
+                    'ḵ', // Southern dialect
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Minangkabau, //+
+                ScriptLanguage::Minangkabau,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ē', 'ē', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -2298,8 +2669,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     /*unverified*/ 'F', 'f', 'V', 'v',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Mizo, //++
+                ScriptLanguage::Mizo,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2313,8 +2685,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ì', 'ì',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Mossi, //++
+                ScriptLanguage::Mossi,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'Ɛ', 'ɛ', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'Ɩ', 'ɩ', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2324,8 +2697,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     // any more diacritics?
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::NigerianPidgin, //+
+                ScriptLanguage::NigerianPidgin,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ẹ', 'ẹ', 'F', 'f', 'G', 'g',
@@ -2340,8 +2714,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f01cd}', // Ọ́ọ́
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::NorwegianBokmal, //++
+                ScriptLanguage::NorwegianBokmal,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2349,8 +2724,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z', 'Æ', 'æ', 'Ø', 'ø', 'Å', 'å', 'É', 'é', 'Ó', 'ó', 'Ô', 'ô',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::NorwegianNynorsk, //++
+                ScriptLanguage::NorwegianNynorsk,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2359,8 +2735,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ô', 'ô', 'Ê', 'ê',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Nuer, //++
+                ScriptLanguage::Nuer,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'Ä', 'ä', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'Ë', 'ë', 'Ɛ', 'ɛ',
@@ -2377,8 +2754,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     '\u{f3190}', // Ɛ̱̈ɛ̱̈
                 ]
             ),
+            #[verification(FrequencyAnalysis?)]
             (
-                ScriptLanguage::Nyanja, //++?
+                ScriptLanguage::Nyanja,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2386,8 +2764,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Z', 'z'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Occitan, //+
+                ScriptLanguage::Occitan,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2396,16 +2775,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ó', 'ó', 'Ú', 'ú', 'Ï', 'ï', 'Ü', 'ü', 'Ç', 'ç',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::OromoSouthern, //+
+                ScriptLanguage::OromoSouthern,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::OromoWestCentral, //+
+                ScriptLanguage::OromoWestCentral,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2413,8 +2794,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Pangasinan, //++
+                ScriptLanguage::Pangasinan,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2422,8 +2804,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Papiamento, //++
+                ScriptLanguage::Papiamento,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ñ', 'ñ', 'O', 'o',
@@ -2432,6 +2815,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ò', 'ò', 'Ü', 'ü', 'Ú', 'ú', 'Ù', 'ù'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Polish,
                 [
@@ -2441,8 +2825,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ś', 'ś', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y', 'Z', 'z', 'Ź', 'ź', 'Ż', 'ż',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Portuguese, //++
+                ScriptLanguage::Portuguese,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2453,14 +2838,16 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     // formerly: 'Ï', 'ï', 'Ü', 'ü', 'È', 'è', 'Ì', 'ì', 'Ù', 'ù',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::QuechuaAyacucho, //++
+                ScriptLanguage::QuechuaAyacucho,
                 [
                     'A', 'a', 'C', 'c', 'H', 'h', 'I', 'i', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
                     'Ñ', 'ñ', 'P', 'p', 'Q', 'q', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w',
                     'Y', 'y', 'B', 'b', 'D', 'd', 'E', 'e', 'J', 'j', 'O', 'o', 'V', 'v',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Romanian,
                 [
@@ -2471,16 +2858,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ţ', 'ţ',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Rundi, //+
+                ScriptLanguage::Rundi,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::SamiNorthern, //+
+                ScriptLanguage::SamiNorthern,
                 [
                     'A', 'a', 'Á', 'á', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'Đ', 'đ', 'E', 'e',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm',
@@ -2488,17 +2877,19 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ŧ', 'ŧ', 'U', 'u', 'V', 'v', 'Z', 'z', 'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Samoan, //++
+                ScriptLanguage::Samoan,
                 [
                     'A', 'a', 'E', 'e', 'F', 'f', 'G', 'g', 'I', 'i', 'L', 'l', 'M', 'm', 'N', 'n',
                     'O', 'o', 'P', 'p', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'Ā', 'ā', 'Ē', 'ē',
-                    'Ī', 'ī', 'Ō', 'ō', 'Ū', 'ū', // loanwords H
-                    'K', 'k', 'R', 'r', // rare, but used
+                    'Ī', 'ī', 'Ō', 'ō', 'Ū', 'ū', 'K', 'k', 'R', 'r', // rare, but used
+                    loanword, 'H', 'h',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Sango, //++
+                ScriptLanguage::Sango,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i',
                     'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r', 'S', 's',
@@ -2507,6 +2898,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'É', 'é',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Sardinian,
                 [
@@ -2517,17 +2909,30 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ò', 'ò', 'Ú', 'ú', 'Ù', 'ù'
                 ]
             ),
+            #[verification(FrequencyAnalysis?)]
             (
-                ScriptLanguage::Sepedi, //++?
+                ScriptLanguage::Sepedi,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'Ê', 'ê', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'Ô', 'ô',
                     'P', 'p', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'X', 'x', 'Y', 'y',
-                    'Z', 'z', 'Š', 'š' // loanwords C
+                    'Z', 'z', 'Š', 'š', loanword, 'C', 'c',
+                ]
+            ),
+            #[verification(Internet)]
+            (
+                ScriptLanguage::SerbianLatin,
+                [
+                    'A', 'a', 'B', 'b', 'C', 'c', 'Č', 'č', 'Ć', 'ć', 'D', 'd', 'Đ', 'đ', 'E', 'e',
+                    'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l',
+                    /* 'Lj', 'lj', */ 'M', 'm', 'N', 'n', /* 'Nj', 'nj', */ 'O', 'o',
+                    'P', 'p', 'R', 'r', 'S', 's', 'Š', 'š', 'T', 't', 'U', 'u', 'V', 'v', 'Z', 'z',
+                    'Ž', 'ž',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Serer, //+
+                ScriptLanguage::Serer,
                 [
                     'A', 'a', 'B', 'b', 'Ɓ', 'ɓ', 'C', 'c', 'Ƈ', 'ƈ', 'D', 'd', 'Ɗ', 'ɗ', 'E', 'e',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'ʃ', 'K', 'k', 'L', 'l', 'M',
@@ -2536,8 +2941,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ƴ',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Sesotho, //++
+                ScriptLanguage::Sesotho,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'Q', 'q',
@@ -2546,6 +2952,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'C', 'c', // in 'ch' (Lesotho)
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Shona,
                 [
@@ -2555,8 +2962,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Á', 'á', 'É', 'é', 'Í', 'í', 'Ó', 'ó', 'Ú', 'ú'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Sicilian, //++
+                ScriptLanguage::Sicilian,
                 [
                     'A', 'a', 'À', 'à', 'Â', 'â', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'Ḍ', 'ḍ',
                     'Đ', 'đ', 'E', 'e', 'È', 'è', 'Ê', 'ê', 'Ë', 'ë', 'F', 'f', 'G', 'g', 'Ġ', 'ġ',
@@ -2564,13 +2972,13 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ṅ', 'ṅ', 'O', 'o', 'Ò', 'ò', 'Ô', 'ô',
                     'P', 'p', 'Q', 'q', 'R', 'r', 'Ṛ', 'ṛ', 'S', 's', 'Š', 'š', 'Ṣ', 'ṣ', 'T', 't',
                     'Ṭ', 'ṭ', 'U', 'u', 'Ù', 'ù', 'Ú', 'ú', 'Û', 'û', 'V', 'v', 'X', 'x', 'Y', 'y',
-                    'Z', 'z', 'Ż', 'ż',
-                    /* 'É', 'é' ?*/
-                    // loanwords W
+                    'Z', 'z', 'Ż', 'ż', /* 'É', 'é' ?*/
+                    loanword, 'W', 'w',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Silesian, //++
+                ScriptLanguage::Silesian,
                 [
                     'A', 'a', 'Ã', 'ã', 'B', 'b', 'C', 'c', 'Ć', 'ć', 'Č', 'č', 'D', 'd', 'E', 'e',
                     'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'Ł', 'ł',
@@ -2579,18 +2987,19 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ů', 'ů', 'W', 'w', 'Y', 'y', 'Z', 'z', 'Ź', 'ź', 'Ż', 'ż', 'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Slovak, //++
+                ScriptLanguage::Slovak,
                 [
                     'A', 'a', 'Á', 'á', 'Ä', 'ä', 'B', 'b', 'C', 'c', 'Č', 'č', 'D', 'd', 'Ď', 'ď',
                     'E', 'e', 'É', 'é', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i', 'Í', 'í', 'J', 'j',
                     'K', 'k', 'L', 'l', 'Ĺ', 'ĺ', 'Ľ', 'ľ', 'M', 'm', 'N', 'n', 'Ň', 'ň', 'O', 'o',
                     'Ó', 'ó', 'Ô', 'ô', 'P', 'p', 'R', 'r', 'Ŕ', 'ŕ', 'S', 's', 'Š', 'š', 'T', 't',
                     'Ť', 'ť', 'U', 'u', 'Ú', 'ú', 'V', 'v', 'X', 'x', 'Y', 'y', 'Ý', 'ý', 'Z', 'z',
-                    'Ž', 'ž',
-                    // loanwords Q, W
+                    'Ž', 'ž', loanword, 'Q', 'q', 'W', 'w',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Slovenian,
                 [
@@ -2600,15 +3009,17 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Somali, //++
+                ScriptLanguage::Somali,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'Q', 'q',
-                    'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'X', 'x', 'Y',
-                    'y' // loanwords P, V, Z
+                    'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'X', 'x', 'Y', 'y', loanword,
+                    'P', 'p', 'V', 'v', 'Z', 'z',
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Spanish,
                 [
@@ -2619,6 +3030,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ü', 'ü'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Sundanese,
                 [
@@ -2628,6 +3040,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z', /*unverified*/ 'É', 'é'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Swahili,
                 [
@@ -2636,8 +3049,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Swati, //++
+                ScriptLanguage::Swati,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2645,6 +3059,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'r' // R - unofficially used
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Swedish,
                 [
@@ -2654,8 +3069,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z', 'Å', 'å', 'Ä', 'ä', 'Ö', 'ö',
                 ]
             ),
+            #[verification(FrequencyAnalysis?)]
             (
-                ScriptLanguage::TamasheqLatin, //++?
+                ScriptLanguage::TamasheqLatin,
                 [
                     'A', 'a', 'Ă', 'ă', 'Ǎ', 'ǎ', 'Ə', 'ə', 'ǝ', 'B', 'b', 'C', 'c', 'D',
                     'd', /* 'Ḍ', 'ḍ', */
@@ -2668,8 +3084,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     /* 'P', 'p' ?*/
                 ]
             ),
+            #[verification(FrequencyAnalysis?)]
             (
-                ScriptLanguage::TatarCrimean, //++?
+                ScriptLanguage::TatarCrimean,
                 [
                     'A', 'a', 'Â', 'â', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'F', 'f',
                     'G', 'g', 'Ğ', 'ğ', 'H', 'h', 'İ', 'i', 'I', 'ı', 'J', 'j', 'K', 'k', 'L', 'l',
@@ -2677,16 +3094,18 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'S', 's', 'Ş', 'ş', 'T', 't', 'U', 'u', 'Ü', 'ü', 'V', 'v', 'Y', 'y', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::TokPisin, //++
+                ScriptLanguage::TokPisin,
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h', 'I', 'i',
                     'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p', 'R', 'r',
                     'S', 's', 'T', 't', 'U', 'u', 'V', 'v', 'W', 'w', 'Y', 'y'
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Tsonga, //+
+                ScriptLanguage::Tsonga,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2694,6 +3113,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Tswana,
                 [
@@ -2702,6 +3122,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'S', 's', 'T', 't', 'U', 'u', 'W', 'w', 'Y', 'y'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Tumbuka,
                 [
@@ -2712,8 +3133,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ŵ', 'ŵ'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Turkish, //++
+                ScriptLanguage::Turkish,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g',
                     'Ğ', 'ğ', 'H', 'h', 'I', 'ı', 'İ', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm',
@@ -2722,8 +3144,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Â', 'â', 'Î', 'î', 'Û', 'û'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Turkmen, //++
+                ScriptLanguage::Turkmen,
                 [
                     'A', 'a', 'B', 'b', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'Ň', 'ň', 'O', 'o',
@@ -2731,8 +3154,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'Y', 'y', 'Ý', 'ý', 'Z', 'z', 'Ä', 'ä', 'Ž', 'ž',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Umbundu, //++
+                ScriptLanguage::Umbundu,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2740,8 +3164,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ẽ', 'ẽ', 'Ĩ', 'ĩ', 'Õ', 'õ', 'Ũ', 'ũ', 'Ñ', 'ñ', // any more diacritics?
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::UzbekNorthern, //++
+                ScriptLanguage::UzbekNorthern,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2749,8 +3174,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Venetian, //++
+                ScriptLanguage::Venetian,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'Ç', 'ç', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g',
                     'H', 'h', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'Ł', 'ł', 'M', 'm', 'N', 'n',
@@ -2759,6 +3185,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Ó', 'ó', 'Ù', 'ù'
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Vietnamese,
                 [
@@ -2776,17 +3203,19 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'ỹ', 'Ỵ', 'ỵ'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Waray, //++
+                ScriptLanguage::Waray,
                 [
                     'A', 'a', 'B', 'b', 'K', 'k', 'D', 'd', 'G', 'g', 'H', 'h', 'I', 'i', 'L', 'l',
                     'M', 'm', 'N', 'n', 'P', 'p', 'R', 'r', 'S', 's', 'T', 't', 'U', 'u', 'W', 'w',
-                    'Y', 'y', 'E', 'e', 'O', 'o',
-                    // loanwords J, V, C, F and others
+                    'Y', 'y', 'E', 'e', 'O', 'o', loanword, 'J', 'j', 'V', 'v', 'C', 'c', 'F',
+                    'f', // and others
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Welsh, //++
+                ScriptLanguage::Welsh,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', /* 'Ch', 'ch', */ 'D', 'd',
                     /* 'Dd', 'dd', */ 'E', 'e', 'F', 'f', /* 'Ff', 'ff', */ 'G', 'g',
@@ -2796,23 +3225,23 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'W', 'w', 'Y', 'y', 'Â', 'â', 'Ê', 'ê', 'Î', 'î', 'Ô', 'ô', 'Û', 'û', 'Ŵ', 'ŵ',
                     'Ŷ', 'ŷ', 'À', 'à', 'È', 'è', 'Ì', 'ì', 'Ò', 'ò', 'Ù', 'ù', 'Ỳ', 'ỳ', 'Á', 'á',
                     'É', 'é', 'Í', 'í', 'Ó', 'ó', 'Ú', 'ú', 'Ẃ', 'ẃ', 'Ý', 'ý', 'Ë', 'ë', 'Ï', 'ï',
-                    'Ü', 'ü', 'Ÿ',
-                    'ÿ',
-                    // loanwords 'K', 'k', 'Q', 'q', 'V', 'v', 'X', 'x', 'Z', 'z',
+                    'Ü', 'ü', 'Ÿ', 'ÿ', loanword, 'K', 'k', 'Q', 'q', 'V', 'v', 'X', 'x', 'Z', 'z',
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Wolof, //++
+                ScriptLanguage::Wolof,
                 [
                     'A', 'a', 'À', 'à', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'É', 'é', 'Ë', 'ë',
                     'F', 'f', 'G', 'g', 'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n',
                     'Ñ', 'ñ', 'Ŋ', 'ŋ', 'O', 'o', 'Ó', 'ó', 'P', 'p', 'Q', 'q', 'R', 'r', 'S', 's',
-                    'T', 't', 'U', 'u', 'W', 'w', 'X', 'x', 'Y', 'y',
-                    // loanwords H, V, Z
+                    'T', 't', 'U', 'u', 'W', 'w', 'X', 'x', 'Y', 'y', loanword, 'H', 'h', 'V', 'v',
+                    'Z', 'z',
                 ]
             ),
+            #[verification(Internet)]
             (
-                ScriptLanguage::Xhosa, //+
+                ScriptLanguage::Xhosa,
                 [
                     'A', 'a', 'B', 'b', 'C', 'c', 'D', 'd', 'E', 'e', 'F', 'f', 'G', 'g', 'H', 'h',
                     'I', 'i', 'J', 'j', 'K', 'k', 'L', 'l', 'M', 'm', 'N', 'n', 'O', 'o', 'P', 'p',
@@ -2820,8 +3249,9 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     'Y', 'y', 'Z', 'z'
                 ]
             ),
+            #[verification(FrequencyAnalysis)]
             (
-                ScriptLanguage::Yoruba, //++
+                ScriptLanguage::Yoruba,
                 #[rustfmt::skip]
                 [
                     'A', 'a', 'B', 'b', 'D', 'd', 'E', 'e', 'Ẹ', 'ẹ', 'F', 'f', 'G', 'g', 'H', 'h',
@@ -2842,6 +3272,7 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                     // '\u{f04cd}', // Ọ̄ọ̄
                 ]
             ),
+            #[verification(Unverified)]
             (
                 ScriptLanguage::Zulu,
                 [
@@ -2853,152 +3284,241 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
             ),
             ScriptLanguage::UnknownLatin
         ]),
+        #[cfg(feature = "script_lepcha")]
         Lepcha => &[ScriptLanguage::Lepcha],
+        #[cfg(feature = "script_limbu")]
         Limbu => &[ScriptLanguage::Limbu],
+        #[cfg(feature = "script_linear_a")]
         LinearA => &[ScriptLanguage::MinoanLinearA],
+        #[cfg(feature = "script_linear_b")]
         LinearB => &[ScriptLanguage::MycenaeanGreek],
+        #[cfg(feature = "script_lisu")]
         Lisu => &[ScriptLanguage::Lisu],
+        #[cfg(feature = "script_lycian")]
         Lycian => &[ScriptLanguage::Lycian],
+        #[cfg(feature = "script_lydian")]
         Lydian => &[ScriptLanguage::Lydian],
+        #[cfg(feature = "script_mahajani")]
         Mahajani => &[
             ScriptLanguage::HindiMahajani,
             ScriptLanguage::MarwariMahajani,
             ScriptLanguage::PunjabiEasternMahajani,
         ],
+        #[cfg(feature = "script_makasar")]
         Makasar => &[ScriptLanguage::MakassareseMakasar],
+        #[cfg(feature = "script_malayalam")]
         Malayalam => &[ScriptLanguage::Malayalam],
+        #[cfg(feature = "script_mandaic")]
         Mandaic => &[ScriptLanguage::AramaicMandaic],
+        #[cfg(feature = "script_manichaean")]
         Manichaean => &[
             ScriptLanguage::MiddlePersianManichaean,
             ScriptLanguage::SogdianManichaean,
         ],
+        #[cfg(feature = "script_marchen")]
         Marchen => &[ScriptLanguage::Zhangzhung],
+        #[cfg(feature = "script_masaram_gondi")]
         MasaramGondi => &[ScriptLanguage::GondiMasaram],
+        #[cfg(feature = "script_medefaidrin")]
         Medefaidrin => &[ScriptLanguage::Medefaidrin],
+        #[cfg(feature = "script_meetei_mayek")]
         MeeteiMayek => &[ScriptLanguage::MeiteiMeeteiMayek],
+        #[cfg(feature = "script_mende_kikakui")]
         MendeKikakui => &[ScriptLanguage::Mende],
+        #[cfg(feature = "script_meroitic_cursive")]
         MeroiticCursive => &[ScriptLanguage::MeroiticCursive],
+        #[cfg(feature = "script_meroitic_hieroglyphs")]
         MeroiticHieroglyphs => &[ScriptLanguage::MeroiticHieroglyphs],
+        #[cfg(feature = "script_miao")]
         Miao => &[ScriptLanguage::HmongMiao],
+        #[cfg(feature = "script_modi")]
         Modi => &[ScriptLanguage::MarathiModi],
+        #[cfg(feature = "script_mongolian")]
         Mongolian => &[
             ScriptLanguage::MongolianBuryat,
             ScriptLanguage::MongolianKhalkhaMongolian,
             ScriptLanguage::OiratMongolian,
         ],
+        #[cfg(feature = "script_mro")]
         Mro => &[ScriptLanguage::Mro],
+        #[cfg(feature = "script_multani")]
         Multani => &[ScriptLanguage::SaraikiMultani],
+        #[cfg(feature = "script_nag_mundari")]
         NagMundari => &[ScriptLanguage::Mundari],
+        #[cfg(feature = "script_myanmar")]
         Myanmar => &[
             ScriptLanguage::Burmese,
             ScriptLanguage::KarenSgaw,
             ScriptLanguage::Mon,
             ScriptLanguage::Shan,
         ],
+        #[cfg(feature = "script_nabataean")]
         Nabataean => &[ScriptLanguage::AramaicNabataean],
+        #[cfg(feature = "script_nandinagari")]
         Nandinagari => &[ScriptLanguage::SanskritNandinagari],
+        #[cfg(feature = "script_newa")]
         Newa => &[ScriptLanguage::Newar],
+        #[cfg(feature = "script_new_tai_lue")]
         NewTaiLue => &[ScriptLanguage::TaiLueNew],
+        #[cfg(feature = "script_nko")]
         Nko => &[ScriptLanguage::BambaraNko, ScriptLanguage::Manding],
+        #[cfg(feature = "script_nushu")]
         Nushu => &[ScriptLanguage::ChineseTuhua],
+        #[cfg(feature = "script_nyiakeng_puachue_hmong")]
         NyiakengPuachueHmong => &[ScriptLanguage::HmongNyiakengPuachue],
+        #[cfg(feature = "script_ogham")]
         Ogham => &[ScriptLanguage::OldIrishOgham],
+        #[cfg(feature = "script_ol_chiki")]
         OlChiki => &[ScriptLanguage::Santali],
+        #[cfg(feature = "script_old_hungarian")]
         OldHungarian => &[ScriptLanguage::HungarianOld],
+        #[cfg(feature = "script_old_italic")]
         OldItalic => &[
             ScriptLanguage::Etruscan,
             ScriptLanguage::Oscan,
             ScriptLanguage::Umbrian,
         ],
+        #[cfg(feature = "script_old_permic")]
         OldPermic => &[
             ScriptLanguage::KomiPermyakOldPermic,
             ScriptLanguage::KomiZyrianOldPermic,
         ],
+        #[cfg(feature = "script_old_north_arabian")]
         OldNorthArabian => &[ScriptLanguage::AncientNorthArabian],
+        #[cfg(feature = "script_old_persian")]
         OldPersian => &[ScriptLanguage::OldPersian],
+        #[cfg(feature = "script_old_sogdian")]
         OldSogdian => &[ScriptLanguage::SogdianOld],
+        #[cfg(feature = "script_old_south_arabian")]
         OldSouthArabian => &[ScriptLanguage::AncientSouthArabian],
+        #[cfg(feature = "script_old_turkic")]
         OldTurkic => &[ScriptLanguage::OldTurkic],
+        #[cfg(feature = "script_old_uyghur")]
         OldUyghur => &[ScriptLanguage::OldUyghur],
+        #[cfg(feature = "script_ol_onal")]
         OlOnal => &[ScriptLanguage::Bhumij],
+        #[cfg(feature = "script_oriya")]
         Oriya => &[ScriptLanguage::Odia, ScriptLanguage::Sambalpuri],
+        #[cfg(feature = "script_osage")]
         Osage => &[ScriptLanguage::Osage],
+        #[cfg(feature = "script_osmanya")]
         Osmanya => &[ScriptLanguage::SomaliOsmanya],
+        #[cfg(feature = "script_pahawh_hmong")]
         PahawhHmong => &[ScriptLanguage::HmongPahawh],
+        #[cfg(feature = "script_palmyrene")]
         Palmyrene => &[ScriptLanguage::AramaicPalmyrene],
+        #[cfg(feature = "script_pau_cin_hau")]
         PauCinHau => &[ScriptLanguage::Tedim],
+        #[cfg(feature = "script_phags_pa")]
         PhagsPa => &[
             ScriptLanguage::MongolianKhalkhaPhagsPa,
             ScriptLanguage::TibetanPhagsPa,
         ],
+        #[cfg(feature = "script_phoenician")]
         Phoenician => &[ScriptLanguage::Phoenician],
+        #[cfg(feature = "script_psalter_pahlavi")]
         PsalterPahlavi => &[ScriptLanguage::MiddlePersianPahlaviPsalter],
+        #[cfg(feature = "script_rejang")]
         Rejang => &[ScriptLanguage::RejangRejang],
+        #[cfg(feature = "script_runic")]
         Runic => &[ScriptLanguage::OldEnglishRunic, ScriptLanguage::OldNorse],
+        #[cfg(feature = "script_samaritan")]
         Samaritan => &[
             ScriptLanguage::AramaicSamaritan,
             ScriptLanguage::HebrewSamaritan,
         ],
+        #[cfg(feature = "script_saurashtra")]
         Saurashtra => &[ScriptLanguage::SaurashtraSaurashtra],
+        #[cfg(feature = "script_sharada")]
         Sharada => &[
             ScriptLanguage::SanskritSharada,
             ScriptLanguage::KashmiriSharada,
         ],
+        #[cfg(feature = "script_shavian")]
         Shavian => &[ScriptLanguage::EnglishShavian],
+        #[cfg(feature = "script_sidetic")]
         Sidetic => &[ScriptLanguage::Sidetic],
+        #[cfg(feature = "script_siddham")]
         Siddham => &[ScriptLanguage::SanskritSiddham],
+        #[cfg(feature = "script_sign_writing")]
         SignWriting => &[ScriptLanguage::SignLanguages],
+        #[cfg(feature = "script_sinhala")]
         Sinhala => &[ScriptLanguage::Sinhala],
+        #[cfg(feature = "script_sogdian")]
         Sogdian => &[ScriptLanguage::Sogdian],
+        #[cfg(feature = "script_sora_sompeng")]
         SoraSompeng => &[ScriptLanguage::Sora],
+        #[cfg(feature = "script_soyombo")]
         Soyombo => &[
             ScriptLanguage::MongolianKhalkhaSoyombo,
             ScriptLanguage::SanskritSoyombo,
             ScriptLanguage::TibetanSoyombo,
         ],
+        #[cfg(feature = "script_sundanese")]
         Sundanese => &[ScriptLanguage::SundaneseSundanese],
+        #[cfg(feature = "script_sunuwar")]
         Sunuwar => &[ScriptLanguage::Sunuwar],
+        #[cfg(feature = "script_syloti_nagri")]
         SylotiNagri => &[ScriptLanguage::Sylheti],
+        #[cfg(feature = "script_syriac")]
         Syriac => &[ScriptLanguage::AramaicSyriac],
+        #[cfg(feature = "script_tagalog")]
         Tagalog => &[ScriptLanguage::FilipinoTagalog],
+        #[cfg(feature = "script_tagbanwa")]
         Tagbanwa => &[ScriptLanguage::Tagbanwa],
+        #[cfg(feature = "script_tai_le")]
         TaiLe => &[ScriptLanguage::TaiNuea],
+        #[cfg(feature = "script_tai_tham")]
         TaiTham => &[
             ScriptLanguage::LaoTaiTham,
             ScriptLanguage::NorthernThaiTaiTham,
             ScriptLanguage::TaiLue,
         ],
+        #[cfg(feature = "script_tai_viet")]
         TaiViet => &[ScriptLanguage::TaiDam, ScriptLanguage::TaiDon],
+        #[cfg(feature = "script_takri")]
         Takri => &[
             ScriptLanguage::DogriTakri,
             ScriptLanguage::DogriKangriTakri,
             ScriptLanguage::KashmiriTakri,
         ],
+        #[cfg(feature = "script_tamil")]
         Tamil => &[ScriptLanguage::Tamil],
+        #[cfg(feature = "script_tangsa")]
         Tangsa => &[ScriptLanguage::Tangsa],
+        #[cfg(feature = "script_tangut")]
         Tangut => &[ScriptLanguage::Tangut],
+        #[cfg(feature = "script_tai_yo")]
         TaiYo => &[ScriptLanguage::TaiYoLaiTay],
+        #[cfg(feature = "script_telugu")]
         Telugu => &[ScriptLanguage::Telugu],
+        #[cfg(feature = "script_tolong_siki")]
         TolongSiki => &[ScriptLanguage::KurukhTolongSiki],
+        #[cfg(feature = "script_thaana")]
         Thaana => &[ScriptLanguage::Dhivehi],
+        #[cfg(feature = "script_thai")]
         Thai => &[
             ScriptLanguage::Isan,
             ScriptLanguage::NorthernThai,
             ScriptLanguage::Thai,
         ],
+        #[cfg(feature = "script_tibetan")]
         Tibetan => &[ScriptLanguage::Dzongkha, ScriptLanguage::Tibetan],
+        #[cfg(feature = "script_tifinagh")]
         Tifinagh => &[ScriptLanguage::TamazightCentralAtlas],
         /* alphabet_match!([
+            #[verification(Internet)]
             (
-                ScriptLanguage::TamasheqTifinagh, //+
+                ScriptLanguage::TamasheqTifinagh,
                 [
                     'ⴰ', 'ⴱ', 'ⴴ', 'ⴶ', 'ⴷ', 'ⴸ', 'ⴹ', 'ⴼ', 'ⴾ', 'ⵂ', 'ⵃ', 'ⵄ', 'ⵆ', 'ⵈ', 'ⵉ', 'ⵌ',
                     'ⵋ', 'ⵍ', 'ⵎ', 'ⵏ', 'ⵑ', 'ⵓ', 'ⵔ', 'ⵗ', 'ⵙ', 'ⵚ', 'ⵛ', 'ⵜ', 'ⵟ', 'ⵡ', 'ⵢ', 'ⵣ',
                     'ⵥ', 'ⵦ',
                 ]
             ),
+            #[verification(FrequencyAnalysis?)]
             (
-                ScriptLanguage::TamazightCentralAtlas, //++?
+                ScriptLanguage::TamazightCentralAtlas,
                 [
                     // Neo-Tifinagh
                     'ⴰ', 'ⴱ', 'ⴳ', 'ⵯ', 'ⴷ', 'ⴹ', 'ⴻ', 'ⴼ', 'ⴽ', 'ⵀ', 'ⵃ', 'ⵄ', 'ⵅ', 'ⵇ', 'ⵉ', 'ⵊ',
@@ -3010,25 +3530,40 @@ pub fn script_char_to_slangs(script: UcdScript, ch: char) -> &'static [ScriptLan
                 ]
             ),
         ]), */
+        #[cfg(feature = "script_tirhuta")]
         Tirhuta => &[ScriptLanguage::MaithiliTirhuta],
+        #[cfg(feature = "script_todhri")]
         Todhri => &[ScriptLanguage::AlbanianTodhri],
+        #[cfg(feature = "script_toto")]
         Toto => &[ScriptLanguage::Toto],
+        #[cfg(feature = "script_tulu_tigalari")]
         TuluTigalari => &[
             ScriptLanguage::KannadaTuluTigalari,
             ScriptLanguage::SanskritTuluTigalari,
             ScriptLanguage::TuluTigalari,
         ],
+        #[cfg(feature = "script_ugaritic")]
         Ugaritic => &[ScriptLanguage::Ugaritic],
+        #[cfg(feature = "script_vai")]
         Vai => &[ScriptLanguage::Vai],
+        #[cfg(feature = "script_vithkuqi")]
         Vithkuqi => &[ScriptLanguage::AlbanianToskVithkuqi],
+        #[cfg(feature = "script_wancho")]
         Wancho => &[ScriptLanguage::WanchoWancho],
+        #[cfg(feature = "script_warang_citi")]
         WarangCiti => &[ScriptLanguage::HoWarangCiti],
+        #[cfg(feature = "script_yezidi")]
         Yezidi => &[ScriptLanguage::KurdishNorthernYezidi],
+        #[cfg(feature = "script_yi")]
         Yi => &[ScriptLanguage::Nuosu],
+        #[cfg(feature = "script_zanabazar_square")]
         ZanabazarSquare => &[
             ScriptLanguage::MongolianKhalkhaZanabazarSquare,
             ScriptLanguage::SanskritZanabazarSquare,
             ScriptLanguage::TibetanZanabazarSquare,
         ],
+        // scripts excluded by cargo features
+        #[allow(unreachable_patterns)]
+        _ => &[],
     }
 }
@@ -0,0 +1,221 @@
+use super::{Language, Script, ScriptLanguage};
+use strum::IntoEnumIterator;
+
+/// CLDR likely subtags for regions, where the script differs from the default one:
+/// (language subtag, region subtag, script)
+const REGION_SCRIPTS: &[(&str, &str, Script)] = &[
+    ("mn", "CN", Script::Mongolian),
+    ("pa", "PK", Script::Arabic),
+    ("sd", "IN", Script::Devanagari),
+    ("uz", "AF", Script::Arabic),
+    ("yue", "CN", Script::HanSimplified),
+    ("zh", "HK", Script::HanTraditional),
+    ("zh", "MO", Script::HanTraditional),
+    ("zh", "TW", Script::HanTraditional),
+];
+
+/// Languages by a BCP 47 language subtag, else by ISO 639-3 code (also old one).
+///
+/// "und" matches `Language::Unknown` and groups (4-letter codes).
+fn languages_by_subtag(subtag: &str) -> Vec<Language> {
+    let langs: Vec<Language> = Language::iter()
+        .filter(|l| l.into_bcp47().eq_ignore_ascii_case(subtag))
+        .collect();
+    if !langs.is_empty() || !(3..=4).contains(&subtag.len()) {
+        return langs;
+    }
+
+    let mut buf = [0; 4];
+    let buf = &mut buf[..subtag.len()];
+    buf.copy_from_slice(subtag.as_bytes());
+    buf.make_ascii_lowercase();
+    Language::from_bytes(buf).into_iter().collect()
+}
+
+/// Script by ISO 15924 code, case-insensitive.
+#[inline]
+fn script_by_subtag(subtag: &str) -> Option<Script> {
+    let mut buf = [0; 4];
+    buf.copy_from_slice(subtag.as_bytes());
+    buf.make_ascii_lowercase();
+    buf[0].make_ascii_uppercase();
+    Script::from_bytes(&buf)
+}
+
+impl Language {
+    /// BCP 47 language subtag: ISO 639-1 code if there is one, else ISO 639-3 code.
+    ///
+    /// Languages sharing ISO 639-1 code with their macrolanguage default
+    /// (like Cantonese for "zh") get ISO 639-3 code.
+    /// Groups (4-letter codes) are "und", `Language::Math` is "zxx" (no linguistic content).
+    #[inline]
+    pub const fn into_bcp47(self) -> &'static str {
+        match self {
+            Language::Acehnese => "ace",
+            Language::AzerbaijaniSouth => "azb",
+            Language::ChineseCantonese => "yue",
+            Language::FulaPular => "fuf",
+            Language::FulfuldeNigerian => "fuv",
+            Language::HebrewSamaritan => "smp",
+            Language::KomiPermyak => "koi",
+            Language::KurdishCentral => "ckb",
+            Language::KurdishSouthern => "sdh",
+            Language::Math => "zxx",
+            Language::Latgalian => "ltg",
+            Language::OromoSouthern => "gax",
+            Language::PersianDari => "prs",
+            Language::SanskritVedic => "vsn",
+            lang => match lang.into_2letter() {
+                Some(s) => s,
+                None if lang.into_str().len() == 3 => lang.into_str(),
+                None => "und",
+            },
+        }
+    }
+
+    /// Default script according to CLDR likely subtags.
+    ///
+    /// For languages with only one `ScriptLanguage` its script is used.
+    pub fn default_script(self) -> Option<Script> {
+        let script = match self {
+            Language::Unknown | Language::Math => return None,
+
+            Language::Acehnese
+            | Language::AlbanianTosk
+            | Language::Albanian
+            | Language::Balinese
+            | Language::Bambara
+            | Language::Banjar
+            | Language::Buginese
+            | Language::English
+            | Language::Filipino
+            | Language::French
+            | Language::FulaPular
+            | Language::Hmong
+            | Language::Hungarian
+            | Language::Javanese
+            | Language::KanuriCentral
+            | Language::KurdishNorthern
+            | Language::Makassarese
+            | Language::Mandailing
+            | Language::Somali
+            | Language::Sundanese
+            | Language::Wolof => Script::Latin,
+
+            Language::Kashmiri | Language::Saraiki | Language::Sindhi => Script::Arabic,
+            Language::Bhojpuri
+            | Language::Dogri
+            | Language::Hindi
+            | Language::Magahi
+            | Language::Maithili
+            | Language::Marathi
+            | Language::Sanskrit => Script::Devanagari,
+            Language::ChurchSlavonic | Language::MongolianKhalkha | Language::Serbian => {
+                Script::Cyrillic
+            }
+
+            Language::ChineseMandarin => Script::HanSimplified,
+            Language::Dhivehi => Script::Thaana,
+            Language::Gondi => Script::Telugu,
+            Language::Kannada | Language::Tulu => Script::Kannada,
+            Language::Lao => Script::Lao,
+            Language::Meitei => Script::Bengali,
+            Language::Meroitic => Script::MeroiticCursive,
+            Language::MiddlePersianPahlavi => Script::InscriptionalPahlavi,
+            Language::NorthernThai => Script::TaiTham,
+            Language::PunjabiEastern => Script::Gurmukhi,
+            Language::Saurashtra => Script::Saurashtra,
+            Language::Sogdian => Script::Sogdian,
+            Language::TaiLue => Script::NewTaiLue,
+            Language::Tamil => Script::Tamil,
+            Language::Tibetan => Script::Tibetan,
+            Language::Wancho => Script::Wancho,
+
+            lang => {
+                let slangs: &'static [ScriptLanguage] = lang.into();
+                match slangs {
+                    [slang] => Script::from(*slang),
+                    _ => return None,
+                }
+            }
+        };
+        Some(script)
+    }
+}
+
+impl ScriptLanguage {
+    /// BCP 47 tag, like "en", "zh-Hant" or "pa-Arab".
+    ///
+    /// Script subtag is left out, if it's the default script of the language
+    /// ([`Language::default_script`](enum.Language.html#method.default_script)),
+    /// it's never left out for "und".
+    pub fn to_bcp47(self) -> String {
+        let (lang, script) = self.into_parts();
+        let lang_tag = lang.into_bcp47();
+        if lang_tag != "und" && lang.default_script() == Some(script) {
+            lang_tag.to_owned()
+        } else {
+            format!("{lang_tag}-{}", script.into_str())
+        }
+    }
+
+    /// Parses a BCP 47 tag or a locale string, like "zh-TW", "pt_BR" or "sr-Latn".
+    ///
+    /// Case-insensitive, both "-" and "_" separators are accepted.
+    /// Missing script is filled in from CLDR likely subtags (by region, then language default).
+    /// Every matching `ScriptLanguage` is returned, when the tag is ambiguous,
+    /// all of the language's `ScriptLanguage`s, when the likely script is not found.
+    pub fn from_bcp47(tag: &str) -> Vec<Self> {
+        let mut subtags = tag.split(['-', '_']);
+        let Some(lang_tag) = subtags.next() else {
+            return Vec::new();
+        };
+        let langs = languages_by_subtag(lang_tag);
+
+        let mut script = None;
+        let mut region = None;
+        for subtag in subtags {
+            let is_alpha = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+            match subtag.len() {
+                4 if is_alpha && script.is_none() && region.is_none() => {
+                    let Some(s) = script_by_subtag(subtag) else {
+                        return Vec::new();
+                    };
+                    script = Some(s);
+                }
+                2 if is_alpha && region.is_none() => region = Some(subtag),
+                3 if subtag.bytes().all(|b| b.is_ascii_digit()) && region.is_none() => {
+                    region = Some(subtag)
+                }
+                _ => break,
+            }
+        }
+
+        let mut res = Vec::new();
+        for lang in langs {
+            // groups need a script subtag
+            if script.is_none() && lang_tag.len() == 3 && lang.into_str().len() == 4 {
+                continue;
+            }
+            let slangs: &'static [ScriptLanguage] = lang.into();
+            if let Some(script) = script {
+                res.extend(ScriptLanguage::from_parts((lang, script)));
+                continue;
+            }
+
+            let likely_script = region
+                .and_then(|region| {
+                    REGION_SCRIPTS.iter().find(|(l, r, _)| {
+                        l.eq_ignore_ascii_case(lang.into_bcp47()) && r.eq_ignore_ascii_case(region)
+                    })
+                })
+                .map(|&(_, _, s)| s)
+                .or_else(|| lang.default_script());
+            match likely_script.and_then(|s| ScriptLanguage::from_parts((lang, s))) {
+                Some(slang) => res.push(slang),
+                None => res.extend_from_slice(slangs),
+            }
+        }
+        res
+    }
+}
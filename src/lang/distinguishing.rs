@@ -0,0 +1,35 @@
+use super::{ScriptLanguage, ScriptLanguageSet};
+use ::std::collections::BTreeSet;
+
+/// Chars of the alphabets ([`ScriptLanguage::alphabet`](enum.ScriptLanguage.html#method.alphabet)),
+/// which split `slangs`: contained by some, but not all of them.
+///
+/// Each char is returned with the `ScriptLanguage`s it keeps, sorted by char.
+/// Uppercase chars are skipped, if their lowercase chars split `slangs` the same way.
+/// `ScriptLanguage`s without a listed alphabet add no chars, but are kept by the chars
+/// they are detected by ([`ScriptLanguage::alphabet_contains`](enum.ScriptLanguage.html#method.alphabet_contains)).
+pub fn distinguishing_chars(slangs: &[ScriptLanguage]) -> Vec<(char, ScriptLanguageSet)> {
+    let all = ScriptLanguageSet::from_slice(slangs);
+    let chars: BTreeSet<char> = all
+        .iter()
+        .flat_map(|slang| slang.alphabet().into_iter().flatten().copied())
+        .collect();
+    let keeps = |ch: char| -> ScriptLanguageSet {
+        all.iter()
+            .filter(|slang| slang.alphabet_contains(ch))
+            .collect()
+    };
+
+    chars
+        .iter()
+        .map(|&ch| (ch, keeps(ch)))
+        .filter(|(_, kept)| *kept != all)
+        .filter(|&(ch, kept)| {
+            let mut lower = ch.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(lc), None) if lc != ch && chars.contains(&lc) => keeps(lc) != kept,
+                _ => true,
+            }
+        })
+        .collect()
+}
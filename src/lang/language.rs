@@ -1,9 +1,11 @@
 use ::std::fmt::Debug;
 use alphabet_detector_macros::Language;
+use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
 
 // For dialect create a new Language. Avoid macrolanguages.
 // short = ISO 639-3 code. shortest = ISO 639-1 code.
+// name = English name (if it differs from the variant name). native = native name (autonym).
 /// Int representation is unstable and can be changed anytime.
 /// Code representation (const
 /// [`into_code`](enum.Language.html#method.into_code)/[`from_code`](enum.Language.html#method.from_code))
@@ -18,27 +20,32 @@ use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
     pyo3::prelude::pyclass(eq, eq_int, frozen, hash, ord)
 )]
 pub enum Language {
-    #[language(short = "abk", shortest = "ab")]
+    #[language(short = "abk", shortest = "ab", native = "аԥсуа бызшәа")]
     Abkhaz,
     #[language(short = "ace", shortest = "ac")]
     Acehnese,
-    #[language(short = "afr", shortest = "af")]
+    #[language(short = "afr", shortest = "af", native = "Afrikaans")]
     Afrikaans,
     #[language(short = "aho")]
     Ahom,
-    #[language(short = "fat", shortest = "ak")]
+    #[language(short = "fat", shortest = "ak", name = "Fante")]
     AkanFante,
     /// default Akan
-    #[language(short = "twi", old_short = "aka", shortest = "tw")]
+    #[language(short = "twi", old_short = "aka", shortest = "tw", name = "Twi")]
     AkanTwi,
     #[language(short = "akk")]
     Akkadian,
     /// Macro, unique scripts
-    #[language(short = "sqi", old_short = "alb", shortest = "sq")]
+    #[language(short = "sqi", old_short = "alb", shortest = "sq", native = "shqip")]
     Albanian,
-    #[language(short = "als", shortest = "sq")]
+    #[language(
+        short = "als",
+        shortest = "sq",
+        name = "Tosk Albanian",
+        native = "shqip"
+    )]
     AlbanianTosk,
-    #[language(short = "amh", shortest = "am")]
+    #[language(short = "amh", shortest = "am", native = "አማርኛ")]
     Amharic,
     #[language(short = "grc")]
     AncientGreek,
@@ -50,63 +57,73 @@ pub enum Language {
     AncientSouthArabian,
     #[language(short = "akb")]
     Angkola,
-    #[language(short = "arb", old_short = "ara", shortest = "ar")]
+    #[language(short = "arb", old_short = "ara", shortest = "ar", native = "العربية")]
     Arabic,
-    #[language(short = "arz")]
+    #[language(short = "arz", name = "Egyptian Arabic")]
     ArabicEgyptian,
-    #[language(short = "acm")]
+    #[language(short = "acm", name = "Mesopotamian Arabic")]
     ArabicMesopotamian,
-    #[language(short = "ary")]
+    #[language(short = "ary", name = "Moroccan Arabic")]
     ArabicMoroccan,
-    #[language(short = "ars")]
+    #[language(short = "ars", name = "Najdi Arabic")]
     ArabicNajdi,
-    #[language(short = "apc")]
+    #[language(short = "apc", name = "North Levantine Arabic")]
     ArabicNorthLevantine,
-    #[language(short = "acq")]
+    #[language(short = "acq", name = "Southern Yemeni Arabic")]
     ArabicSouthernYemeni,
-    #[language(short = "ajp")]
+    #[language(short = "ajp", name = "South Levantine Arabic")]
     ArabicSouthLevantine,
-    #[language(short = "apd")]
+    #[language(short = "apd", name = "Sudanese Arabic")]
     ArabicSudanese,
-    #[language(short = "aeb")]
+    #[language(short = "aeb", name = "Tunisian Arabic")]
     ArabicTunisian,
-    #[language(short = "elym")]
+    #[language(short = "elym", name = "Elymaic Aramaic")]
     AramaicElymaic,
-    #[language(short = "hatr")]
+    #[language(short = "hatr", name = "Hatran Aramaic")]
     AramaicHatran,
-    #[language(short = "arc")]
+    #[language(short = "arc", name = "Imperial Aramaic")]
     AramaicImperial,
-    #[language(short = "myz")]
+    #[language(short = "myz", name = "Mandaic")]
     AramaicMandaic,
-    #[language(short = "nabt")]
+    #[language(short = "nabt", name = "Nabataean Aramaic")]
     AramaicNabataean,
-    #[language(short = "palm")]
+    #[language(short = "palm", name = "Palmyrene Aramaic")]
     AramaicPalmyrene,
-    #[language(short = "sam")]
+    #[language(short = "sam", name = "Samaritan Aramaic")]
     AramaicSamaritan,
-    #[language(short = "syc")]
+    #[language(short = "syc", name = "Syriac")]
     AramaicSyriac,
-    #[language(short = "hye", old_short = "arm", shortest = "hy")]
+    #[language(short = "hye", old_short = "arm", shortest = "hy", native = "հայերեն")]
     Armenian,
-    #[language(short = "asm", shortest = "as")]
+    #[language(short = "asm", shortest = "as", native = "অসমীয়া")]
     Assamese,
-    #[language(short = "ast")]
+    #[language(short = "ast", native = "asturianu")]
     Asturian,
     #[language(short = "ave", shortest = "ae")]
     Avestan,
     #[language(short = "awa")]
     Awadhi,
-    #[language(short = "ayr", old_short = "aym", shortest = "ay")]
+    #[language(
+        short = "ayr",
+        old_short = "aym",
+        shortest = "ay",
+        name = "Central Aymara"
+    )]
     AymaraCentral,
     /// Latin
-    #[language(short = "azj", shortest = "az")]
+    #[language(
+        short = "azj",
+        shortest = "az",
+        name = "North Azerbaijani",
+        native = "azərbaycan dili"
+    )]
     AzerbaijaniNorth,
     /// Arabic
-    #[language(short = "azb", shortest = "az")]
+    #[language(short = "azb", shortest = "az", name = "South Azerbaijani")]
     AzerbaijaniSouth,
     #[language(short = "ban")]
     Balinese,
-    #[language(short = "bam", shortest = "bm")]
+    #[language(short = "bam", shortest = "bm", native = "bamanankan")]
     Bambara,
     #[language(short = "bax")]
     Bamum,
@@ -114,39 +131,39 @@ pub enum Language {
     Banjar,
     #[language(short = "bap")]
     Bantawa,
-    #[language(short = "bak", shortest = "ba")]
+    #[language(short = "bak", shortest = "ba", native = "башҡорт теле")]
     Bashkir,
-    #[language(short = "eus", old_short = "baq", shortest = "eu")]
+    #[language(short = "eus", old_short = "baq", shortest = "eu", native = "euskara")]
     Basque,
     #[language(short = "bsq")]
     Bassa,
-    #[language(short = "bel", shortest = "be")]
+    #[language(short = "bel", shortest = "be", native = "беларуская")]
     Belarusian,
     #[language(short = "bem")]
     Bemba,
-    #[language(short = "ben", shortest = "bn")]
+    #[language(short = "ben", shortest = "bn", native = "বাংলা")]
     Bengali,
-    #[language(short = "bho")]
+    #[language(short = "bho", native = "भोजपुरी")]
     Bhojpuri,
     #[language(short = "bhum")]
     Bhumij,
     #[language(short = "bpy")]
     BishnupriyaManipuri,
-    #[language(short = "bos", shortest = "bs")]
+    #[language(short = "bos", shortest = "bs", native = "bosanski")]
     Bosnian,
     #[language(short = "bug")]
     Buginese,
     #[language(short = "bku")]
     Buhid,
-    #[language(short = "bul", shortest = "bg")]
+    #[language(short = "bul", shortest = "bg", native = "български")]
     Bulgarian,
-    #[language(short = "mya", old_short = "bur", shortest = "my")]
+    #[language(short = "mya", old_short = "bur", shortest = "my", native = "မြန်မာ")]
     Burmese,
     #[language(short = "bxr", old_short = "bua")]
     Buryat,
     #[language(short = "xcr")]
     Carian,
-    #[language(short = "cat", shortest = "ca")]
+    #[language(short = "cat", shortest = "ca", native = "català")]
     Catalan,
     #[language(short = "xag")]
     CaucasianAlbanian,
@@ -154,22 +171,22 @@ pub enum Language {
     Cebuano,
     #[language(short = "ccp")]
     Chakma,
-    #[language(short = "cjm")]
+    #[language(short = "cjm", name = "Eastern Cham")]
     ChamEastern,
-    #[language(short = "cja")]
+    #[language(short = "cja", name = "Western Cham")]
     ChamWestern,
-    #[language(short = "che", shortest = "ce")]
+    #[language(short = "che", shortest = "ce", native = "нохчийн")]
     Chechen,
-    #[language(short = "chr")]
+    #[language(short = "chr", native = "ᏣᎳᎩ")]
     Cherokee,
     #[language(short = "hne")]
     Chhattisgarhi,
-    #[language(short = "yue", shortest = "zh")]
+    #[language(short = "yue", shortest = "zh", name = "Cantonese", native = "粵語")]
     ChineseCantonese,
     /// default Chinese
-    #[language(short = "cmn", old_short = ["zho", "chi"], shortest = "zh")]
+    #[language(short = "cmn", old_short = ["zho", "chi"], shortest = "zh", name = "Mandarin Chinese", native = "中文")]
     ChineseMandarin,
-    #[language(short = "tuhu")]
+    #[language(short = "tuhu", name = "Tuhua")]
     ChineseTuhua,
     #[language(short = "cjk")]
     Chokwe,
@@ -177,107 +194,128 @@ pub enum Language {
     Chorasmian,
     #[language(short = "chu", shortest = "cu")]
     ChurchSlavonic,
-    #[language(short = "chv", shortest = "cv")]
+    #[language(short = "chv", shortest = "cv", native = "чӑвашла")]
     Chuvash,
     #[language(short = "cop")]
     Coptic,
     /// Macro, unique script
     #[language(short = "cre", shortest = "cr")]
     Cree,
-    #[language(short = "hat", shortest = "ht")]
+    #[language(
+        short = "hat",
+        shortest = "ht",
+        name = "Haitian Creole",
+        native = "Kreyòl ayisyen"
+    )]
     CreoleHaitian,
-    #[language(short = "hrv", shortest = "hr")]
+    #[language(short = "hrv", shortest = "hr", native = "hrvatski")]
     Croatian,
-    #[language(short = "ces", old_short = "cze", shortest = "cs")]
+    #[language(short = "ces", old_short = "cze", shortest = "cs", native = "čeština")]
     Czech,
-    #[language(short = "dan", shortest = "da")]
+    #[language(short = "dan", shortest = "da", native = "dansk")]
     Danish,
-    #[language(short = "div", shortest = "dv")]
+    #[language(short = "div", shortest = "dv", native = "ދިވެހި")]
     Dhivehi,
     #[language(short = "luo")]
     Dholuo,
-    #[language(short = "dik")]
+    #[language(short = "dik", name = "Southwestern Dinka")]
     DinkaSouthwestern,
     #[language(short = "dgo", old_short = "doi")]
     Dogri,
-    #[language(short = "xnr")]
+    #[language(short = "xnr", name = "Kangri")]
     DogriKangri,
-    #[language(short = "nld", old_short = "dut", shortest = "nl")]
+    #[language(
+        short = "nld",
+        old_short = "dut",
+        shortest = "nl",
+        native = "Nederlands"
+    )]
     Dutch,
     #[language(short = "dyu")]
     Dyula,
-    #[language(short = "dzo", shortest = "dz")]
+    #[language(short = "dzo", shortest = "dz", native = "རྫོང་ཁ")]
     Dzongkha,
-    #[language(short = "egyp")]
+    #[language(short = "egyp", name = "Ancient Egyptian")]
     EgyptianHieroglyphs,
-    #[language(short = "eng", shortest = "en")]
+    #[language(short = "eng", shortest = "en", native = "English")]
     English,
-    #[language(short = "epo", shortest = "eo")]
+    #[language(short = "epo", shortest = "eo", native = "Esperanto")]
     Esperanto,
-    #[language(short = "ekk", old_short = "est", shortest = "et")]
+    #[language(short = "ekk", old_short = "est", shortest = "et", native = "eesti")]
     Estonian,
     #[language(short = "ett")]
     Etruscan,
-    #[language(short = "ewe", shortest = "ee")]
+    #[language(short = "ewe", shortest = "ee", native = "Eʋegbe")]
     Ewe,
-    #[language(short = "fao", shortest = "fo")]
+    #[language(short = "fao", shortest = "fo", native = "føroyskt")]
     Faroese,
-    #[language(short = "fij", shortest = "fj")]
+    #[language(short = "fij", shortest = "fj", native = "Vosa Vakaviti")]
     Fijian,
-    #[language(short = "fil", old_short = "tgl", shortest = "tl")]
+    #[language(short = "fil", old_short = "tgl", shortest = "tl", native = "Filipino")]
     Filipino,
-    #[language(short = "fin", shortest = "fi")]
+    #[language(short = "fin", shortest = "fi", native = "suomi")]
     Finnish,
     #[language(short = "fon")]
     Fon,
-    #[language(short = "fra", old_short = "fre", shortest = "fr")]
+    #[language(short = "fra", old_short = "fre", shortest = "fr", native = "français")]
     French,
-    #[language(short = "fur")]
+    #[language(short = "fur", native = "furlan")]
     Friulian,
-    #[language(short = "fuc", shortest = "ff")]
+    #[language(short = "fuc", shortest = "ff", name = "Pulaar")]
     FulaPulaar,
-    #[language(short = "fuf", shortest = "ff")]
+    #[language(short = "fuf", shortest = "ff", name = "Pular")]
     FulaPular,
-    #[language(short = "fuv", shortest = "ff")]
+    #[language(short = "fuv", shortest = "ff", name = "Nigerian Fulfulde")]
     FulfuldeNigerian,
-    #[language(short = "gla", shortest = "gd")]
+    #[language(
+        short = "gla",
+        shortest = "gd",
+        name = "Scottish Gaelic",
+        native = "Gàidhlig"
+    )]
     GaelicScottish,
-    #[language(short = "glg", shortest = "gl")]
+    #[language(short = "glg", shortest = "gl", native = "galego")]
     Galician,
-    #[language(short = "lug", shortest = "lg")]
+    #[language(short = "lug", shortest = "lg", native = "Luganda")]
     Ganda,
     #[language(short = "pgd")]
     Gandhari,
-    #[language(short = "gez")]
+    #[language(short = "gez", name = "Ge'ez")]
     Geez,
-    #[language(short = "kat", old_short = "geo", shortest = "ka")]
+    #[language(short = "kat", old_short = "geo", shortest = "ka", native = "ქართული")]
     Georgian,
-    #[language(short = "deu", old_short = "ger", shortest = "de")]
+    #[language(short = "deu", old_short = "ger", shortest = "de", native = "Deutsch")]
     German,
     /// Macro, unique scripts
     #[language(short = "gon")]
     Gondi,
     #[language(short = "got")]
     Gothic,
-    #[language(short = "ell", old_short = "gre", shortest = "el")]
+    #[language(short = "ell", old_short = "gre", shortest = "el", native = "Ελληνικά")]
     Greek,
-    #[language(short = "gug", old_short = "grn", shortest = "gn")]
+    #[language(
+        short = "gug",
+        old_short = "grn",
+        shortest = "gn",
+        name = "Paraguayan Guarani",
+        native = "avañeʼẽ"
+    )]
     GuaraniParaguayan,
-    #[language(short = "guj", shortest = "gu")]
+    #[language(short = "guj", shortest = "gu", native = "ગુજરાતી")]
     Gujarati,
     #[language(short = "gvr")]
     Gurung,
-    #[language(short = "hnn")]
+    #[language(short = "hnn", name = "Hanunó'o")]
     Hanunoo,
-    #[language(short = "hau", shortest = "ha")]
+    #[language(short = "hau", shortest = "ha", native = "Hausa")]
     Hausa,
-    #[language(short = "haw")]
+    #[language(short = "haw", native = "ʻŌlelo Hawaiʻi")]
     Hawaiian,
-    #[language(short = "heb", shortest = "he")]
+    #[language(short = "heb", shortest = "he", native = "עברית")]
     Hebrew,
-    #[language(short = "smp", shortest = "he")]
+    #[language(short = "smp", shortest = "he", name = "Samaritan Hebrew")]
     HebrewSamaritan,
-    #[language(short = "hin", shortest = "hi")]
+    #[language(short = "hin", shortest = "hi", native = "हिन्दी")]
     Hindi,
     #[language(short = "hit")]
     Hittite,
@@ -286,28 +324,28 @@ pub enum Language {
     Hmong,
     #[language(short = "hoc")]
     Ho,
-    #[language(short = "hun", shortest = "hu")]
+    #[language(short = "hun", shortest = "hu", native = "magyar")]
     Hungarian,
-    #[language(short = "isl", old_short = "ice", shortest = "is")]
+    #[language(short = "isl", old_short = "ice", shortest = "is", native = "íslenska")]
     Icelandic,
-    #[language(short = "ibo", shortest = "ig")]
+    #[language(short = "ibo", shortest = "ig", native = "Igbo")]
     Igbo,
     #[language(short = "ilo")]
     Ilocano,
-    #[language(short = "ind", shortest = "id")]
+    #[language(short = "ind", shortest = "id", native = "Bahasa Indonesia")]
     Indonesian,
     /// Macro, unique script
-    #[language(short = "iku", shortest = "iu")]
+    #[language(short = "iku", shortest = "iu", native = "ᐃᓄᒃᑎᑐᑦ")]
     Inuktitut,
-    #[language(short = "gle", shortest = "ga")]
+    #[language(short = "gle", shortest = "ga", native = "Gaeilge")]
     Irish,
     #[language(short = "tts")]
     Isan,
-    #[language(short = "ita", shortest = "it")]
+    #[language(short = "ita", shortest = "it", native = "italiano")]
     Italian,
-    #[language(short = "jpn", shortest = "ja")]
+    #[language(short = "jpn", shortest = "ja", native = "日本語")]
     Japanese,
-    #[language(short = "jav", shortest = "jv")]
+    #[language(short = "jav", shortest = "jv", native = "basa Jawa")]
     Javanese,
     #[language(short = "kac")]
     Jingpho,
@@ -315,65 +353,70 @@ pub enum Language {
     Kabiye,
     #[language(short = "kea")]
     Kabuverdianu,
-    #[language(short = "kab")]
+    #[language(short = "kab", native = "Taqbaylit")]
     Kabyle,
     #[language(short = "kam")]
     Kamba,
-    #[language(short = "kan", shortest = "kn")]
+    #[language(short = "kan", shortest = "kn", native = "ಕನ್ನಡ")]
     Kannada,
-    #[language(short = "knc", shortest = "kr")]
+    #[language(short = "knc", shortest = "kr", name = "Central Kanuri")]
     KanuriCentral,
-    #[language(short = "ksw", old_short = "kar")]
+    #[language(short = "ksw", old_short = "kar", name = "S'gaw Karen")]
     KarenSgaw,
     #[language(short = "btx")]
     Karo,
     #[language(short = "kas", shortest = "ks")]
     Kashmiri,
-    #[language(short = "eky")]
+    #[language(short = "eky", name = "Eastern Kayah")]
     KayahEastern,
-    #[language(short = "kyu")]
+    #[language(short = "kyu", name = "Western Kayah")]
     KayahWestern,
-    #[language(short = "kaz", shortest = "kk")]
+    #[language(short = "kaz", shortest = "kk", native = "қазақ тілі")]
     Kazakh,
     #[language(short = "zkt")]
     Khitan,
-    #[language(short = "khm", shortest = "km")]
+    #[language(short = "khm", shortest = "km", native = "ខ្មែរ")]
     Khmer,
-    #[language(short = "kxm")]
+    #[language(short = "kxm", name = "Northern Khmer")]
     KhmerNorthern,
-    #[language(short = "ktu")]
+    #[language(short = "ktu", name = "Kituba")]
     KikongoKituba,
-    #[language(short = "kik", shortest = "ki")]
+    #[language(short = "kik", shortest = "ki", native = "Gĩkũyũ")]
     Kikuyu,
     #[language(short = "kmb")]
     Kimbundu,
-    #[language(short = "kin", shortest = "rw")]
+    #[language(short = "kin", shortest = "rw", native = "Ikinyarwanda")]
     Kinyarwanda,
-    #[language(short = "koi", shortest = "kv")]
+    #[language(short = "koi", shortest = "kv", name = "Komi-Permyak")]
     KomiPermyak,
-    #[language(short = "kpv", shortest = "kv")]
+    #[language(short = "kpv", shortest = "kv", name = "Komi-Zyrian")]
     KomiZyrian,
-    #[language(short = "kor", shortest = "ko")]
+    #[language(short = "kor", shortest = "ko", native = "한국어")]
     Korean,
-    #[language(short = "ckb", shortest = "ku")]
+    #[language(short = "ckb", shortest = "ku", name = "Central Kurdish")]
     KurdishCentral,
-    #[language(short = "kmr", shortest = "ku")]
+    #[language(
+        short = "kmr",
+        shortest = "ku",
+        name = "Northern Kurdish",
+        native = "Kurmancî"
+    )]
     KurdishNorthern,
-    #[language(short = "sdh", shortest = "ku")]
+    #[language(short = "sdh", shortest = "ku", name = "Southern Kurdish")]
     KurdishSouthern,
     #[language(short = "kru")]
     Kurukh,
     #[language(short = "kfr")]
     Kutchi,
-    #[language(short = "kir", shortest = "ky")]
+    #[language(short = "kir", shortest = "ky", native = "кыргызча")]
     Kyrgyz,
-    #[language(short = "lao", shortest = "lo")]
+    #[language(short = "lao", shortest = "lo", native = "ລາວ")]
     Lao,
     #[language(short = "ltg", shortest = "lv")]
     Latgalian,
-    #[language(short = "lat", shortest = "la")]
+    #[language(short = "lat", shortest = "la", native = "Latina")]
     Latin,
-    #[language(short = "lvs", shortest = "lv")]
+    #[language(short = "lvs", shortest = "lv", native = "latviešu")]
     Latvian,
     #[language(short = "lep")]
     Lepcha,
@@ -381,59 +424,74 @@ pub enum Language {
     Ligurian,
     #[language(short = "lif")]
     Limbu,
-    #[language(short = "lim", shortest = "li")]
+    #[language(short = "lim", shortest = "li", native = "Limburgs")]
     Limburgish,
-    #[language(short = "lin", shortest = "ln")]
+    #[language(short = "lin", shortest = "ln", native = "lingála")]
     Lingala,
     #[language(short = "lis")]
     Lisu,
-    #[language(short = "lit", shortest = "lt")]
+    #[language(short = "lit", shortest = "lt", native = "lietuvių")]
     Lithuanian,
     #[language(short = "lmo")]
     Lombard,
-    #[language(short = "lua")]
+    #[language(short = "lua", name = "Luba-Kasai")]
     LubaKasai,
-    #[language(short = "xlu")]
+    #[language(short = "xlu", name = "Cuneiform Luwian")]
     LuwianCuneiform,
-    #[language(short = "hlu")]
+    #[language(short = "hlu", name = "Hieroglyphic Luwian")]
     LuwianHieroglyphic,
-    #[language(short = "ltz", shortest = "lb")]
+    #[language(short = "ltz", shortest = "lb", native = "Lëtzebuergesch")]
     Luxembourgish,
     #[language(short = "xlc")]
     Lycian,
     #[language(short = "xld")]
     Lydian,
-    #[language(short = "mkd", old_short = "mac", shortest = "mk")]
+    #[language(
+        short = "mkd",
+        old_short = "mac",
+        shortest = "mk",
+        native = "македонски"
+    )]
     Macedonian,
     #[language(short = "mag")]
     Magahi,
-    #[language(short = "mai")]
+    #[language(short = "mai", native = "मैथिली")]
     Maithili,
     #[language(short = "mak")]
     Makassarese,
-    #[language(short = "plt", shortest = "mg")]
+    #[language(
+        short = "plt",
+        shortest = "mg",
+        name = "Plateau Malagasy",
+        native = "Malagasy"
+    )]
     MalagasyPlateau,
-    #[language(short = "zsm", old_short = ["msa", "may"], shortest = "ms")]
+    #[language(short = "zsm", old_short = ["msa", "may"], shortest = "ms", native = "Bahasa Melayu")]
     Malay,
-    #[language(short = "mal", shortest = "ml")]
+    #[language(short = "mal", shortest = "ml", native = "മലയാളം")]
     Malayalam,
-    #[language(short = "mlt", shortest = "mt")]
+    #[language(short = "mlt", shortest = "mt", native = "Malti")]
     Maltese,
     #[language(short = "btm")]
     Mandailing,
     /// Macro, unique script
     #[language(short = "man")]
     Manding,
-    #[language(short = "mri", old_short = "mao", shortest = "mi")]
+    #[language(
+        short = "mri",
+        old_short = "mao",
+        shortest = "mi",
+        native = "te reo Māori"
+    )]
     Maori,
-    #[language(short = "mar", shortest = "mr")]
+    #[language(short = "mar", shortest = "mr", native = "मराठी")]
     Marathi,
-    #[language(short = "mhr", old_short = "chm")]
+    #[language(short = "mhr", old_short = "chm", name = "Eastern Mari")]
     MariEastern,
     /// Macro, unique script
     #[language(short = "mwr")]
     Marwari,
-    #[language(short = "math")]
+    #[language(short = "math", name = "Mathematical notation")]
     Math,
     #[language(short = "dmf")]
     Medefaidrin,
@@ -443,23 +501,29 @@ pub enum Language {
     Mende,
     #[language(short = "xmr")]
     Meroitic,
-    #[language(short = "xmn")]
+    #[language(short = "xmn", name = "Manichaean Middle Persian")]
     MiddlePersianManichaean,
-    #[language(short = "pal")]
+    #[language(short = "pal", name = "Middle Persian")]
     MiddlePersianPahlavi,
     #[language(short = "min")]
     Minangkabau,
     #[language(short = "omn")]
     Minoan,
-    #[language(short = "lab")]
+    #[language(short = "lab", name = "Minoan (Linear A)")]
     MinoanLinearA,
     #[language(short = "lus")]
     Mizo,
     #[language(short = "mnw")]
     Mon,
-    #[language(short = "bxu")]
+    #[language(short = "bxu", name = "Buryat Mongolian")]
     MongolianBuryat,
-    #[language(short = "khk", old_short = "mon", shortest = "mn")]
+    #[language(
+        short = "khk",
+        old_short = "mon",
+        shortest = "mn",
+        name = "Mongolian",
+        native = "монгол"
+    )]
     MongolianKhalkha,
     #[language(short = "mos")]
     Mossi,
@@ -471,7 +535,7 @@ pub enum Language {
     MycenaeanGreek,
     #[language(short = "yrk")]
     Nenets,
-    #[language(short = "npi", shortest = "ne")]
+    #[language(short = "npi", shortest = "ne", native = "नेपाली")]
     Nepali,
     #[language(short = "new")]
     Newar,
@@ -479,19 +543,24 @@ pub enum Language {
     NigerianPidgin,
     #[language(short = "nod")]
     NorthernThai,
-    #[language(short = "nob", shortest = "nb")]
+    #[language(
+        short = "nob",
+        shortest = "nb",
+        name = "Norwegian Bokmål",
+        native = "norsk bokmål"
+    )]
     NorwegianBokmal,
-    #[language(short = "nno", shortest = "nn")]
+    #[language(short = "nno", shortest = "nn", native = "norsk nynorsk")]
     NorwegianNynorsk,
     #[language(short = "nus")]
     Nuer,
     #[language(short = "iii", shortest = "ii")]
     Nuosu,
-    #[language(short = "nya", shortest = "ny")]
+    #[language(short = "nya", shortest = "ny", native = "Chichewa")]
     Nyanja,
-    #[language(short = "oci", shortest = "oc")]
+    #[language(short = "oci", shortest = "oc", native = "occitan")]
     Occitan,
-    #[language(short = "ory", old_short = "ori", shortest = "or")]
+    #[language(short = "ory", old_short = "ori", shortest = "or", native = "ଓଡ଼ିଆ")]
     Odia,
     #[language(short = "xal", old_short = "xwo")]
     Oirat,
@@ -512,126 +581,146 @@ pub enum Language {
     OldTurkic,
     #[language(short = "oui")]
     OldUyghur,
-    #[language(short = "gax", shortest = "om")]
+    #[language(short = "gax", shortest = "om", name = "Southern Oromo")]
     OromoSouthern,
-    #[language(short = "gaz", shortest = "om")]
+    #[language(short = "gaz", shortest = "om", name = "West Central Oromo")]
     OromoWestCentral,
     #[language(short = "osa")]
     Osage,
     #[language(short = "osc")]
     Oscan,
-    #[language(short = "oss", shortest = "os")]
+    #[language(short = "oss", shortest = "os", native = "ирон")]
     Ossetian,
     #[language(short = "btd")]
     Pakpak,
     #[language(short = "pag")]
     Pangasinan,
-    #[language(short = "pap")]
+    #[language(short = "pap", native = "Papiamentu")]
     Papiamento,
     #[language(short = "xpr")]
     Parthian,
-    #[language(short = "pbt", shortest = "ps")]
+    #[language(
+        short = "pbt",
+        shortest = "ps",
+        name = "Southern Pashto",
+        native = "پښتو"
+    )]
     PashtoSouthern,
     /// Afghani
-    #[language(short = "prs", shortest = "fa")]
+    #[language(short = "prs", shortest = "fa", name = "Dari", native = "دری")]
     PersianDari,
     /// Iranian
-    #[language(short = "pes", old_short = ["fas", "per"], shortest = "fa")]
+    #[language(short = "pes", old_short = ["fas", "per"], shortest = "fa", name = "Persian", native = "فارسی")]
     PersianFarsi,
     #[language(short = "phn")]
     Phoenician,
-    #[language(short = "pol", shortest = "pl")]
+    #[language(short = "pol", shortest = "pl", native = "polski")]
     Polish,
-    #[language(short = "por", shortest = "pt")]
+    #[language(short = "por", shortest = "pt", native = "português")]
     Portuguese,
     /// Group
     #[language(short = "pra")]
     Prakrit,
-    #[language(short = "pan", shortest = "pa")]
+    #[language(short = "pan", shortest = "pa", name = "Punjabi", native = "ਪੰਜਾਬੀ")]
     PunjabiEastern,
-    #[language(short = "quy", shortest = "qu")]
+    #[language(
+        short = "quy",
+        shortest = "qu",
+        name = "Ayacucho Quechua",
+        native = "Runasimi"
+    )]
     QuechuaAyacucho,
     #[language(short = "rej")]
     Rejang,
     #[language(short = "rhg")]
     Rohingya,
-    #[language(short = "ron", old_short = "rum", shortest = "ro")]
+    #[language(short = "ron", old_short = "rum", shortest = "ro", native = "română")]
     Romanian,
-    #[language(short = "run", shortest = "rn")]
+    #[language(short = "run", shortest = "rn", native = "Ikirundi")]
     Rundi,
-    #[language(short = "rus", shortest = "ru")]
+    #[language(short = "rus", shortest = "ru", native = "русский")]
     Russian,
     #[language(short = "spv")]
     Sambalpuri,
-    #[language(short = "sme", shortest = "se")]
+    #[language(
+        short = "sme",
+        shortest = "se",
+        name = "Northern Sami",
+        native = "davvisámegiella"
+    )]
     SamiNorthern,
-    #[language(short = "smo", shortest = "sm")]
+    #[language(short = "smo", shortest = "sm", native = "Gagana Samoa")]
     Samoan,
     #[language(short = "sag", shortest = "sg")]
     Sango,
-    #[language(short = "cls", old_short = "san", shortest = "sa")]
+    #[language(short = "cls", old_short = "san", shortest = "sa", native = "संस्कृतम्")]
     Sanskrit,
-    #[language(short = "vsn", shortest = "sa")]
+    #[language(short = "vsn", shortest = "sa", name = "Vedic Sanskrit")]
     SanskritVedic,
-    #[language(short = "sat")]
+    #[language(short = "sat", native = "ᱥᱟᱱᱛᱟᱲᱤ")]
     Santali,
     #[language(short = "skr")]
     Saraiki,
     /// Macro, hard to separate
-    #[language(short = "srd", shortest = "sc")]
+    #[language(short = "srd", shortest = "sc", native = "sardu")]
     Sardinian,
     #[language(short = "saz")]
     Saurashtra,
-    #[language(short = "nso")]
+    #[language(short = "nso", native = "Sesotho sa Leboa")]
     Sepedi,
-    #[language(short = "srp", shortest = "sr")]
+    #[language(short = "srp", shortest = "sr", native = "српски")]
     Serbian,
     #[language(short = "srr")]
     Serer,
-    #[language(short = "sot")]
+    #[language(short = "sot", native = "Sesotho")]
     Sesotho,
     #[language(short = "shn")]
     Shan,
-    #[language(short = "sna", shortest = "sn")]
+    #[language(short = "sna", shortest = "sn", native = "chiShona")]
     Shona,
-    #[language(short = "scn")]
+    #[language(short = "scn", native = "sicilianu")]
     Sicilian,
     #[language(short = "xsd")]
     Sidetic,
     /// Group
-    #[language(short = "sgn")]
+    #[language(short = "sgn", name = "Sign languages")]
     SignLanguages,
     #[language(short = "szl")]
     Silesian,
     #[language(short = "bts")]
     Simalungun,
-    #[language(short = "snd", shortest = "sd")]
+    #[language(short = "snd", shortest = "sd", native = "سنڌي")]
     Sindhi,
-    #[language(short = "sin", shortest = "si")]
+    #[language(short = "sin", shortest = "si", native = "සිංහල")]
     Sinhala,
-    #[language(short = "slk", old_short = "slo", shortest = "sk")]
+    #[language(
+        short = "slk",
+        old_short = "slo",
+        shortest = "sk",
+        native = "slovenčina"
+    )]
     Slovak,
-    #[language(short = "slv", shortest = "sl")]
+    #[language(short = "slv", shortest = "sl", native = "slovenščina")]
     Slovenian,
     #[language(short = "sog")]
     Sogdian,
-    #[language(short = "som", shortest = "so")]
+    #[language(short = "som", shortest = "so", native = "Soomaali")]
     Somali,
     #[language(short = "srb")]
     Sora,
-    #[language(short = "spa", shortest = "es")]
+    #[language(short = "spa", shortest = "es", native = "español")]
     Spanish,
     #[language(short = "sux")]
     Sumerian,
-    #[language(short = "sun", shortest = "su")]
+    #[language(short = "sun", shortest = "su", native = "basa Sunda")]
     Sundanese,
     #[language(short = "suz")]
     Sunuwar,
-    #[language(short = "swh", shortest = "sw")]
+    #[language(short = "swh", shortest = "sw", native = "Kiswahili")]
     Swahili,
-    #[language(short = "ssw", shortest = "ss")]
+    #[language(short = "ssw", shortest = "ss", native = "siSwati")]
     Swati,
-    #[language(short = "swe", shortest = "sv")]
+    #[language(short = "swe", shortest = "sv", native = "svenska")]
     Swedish,
     #[language(short = "syl")]
     Sylheti,
@@ -641,38 +730,38 @@ pub enum Language {
     TaiDam,
     #[language(short = "twh")]
     TaiDon,
-    #[language(short = "khb")]
+    #[language(short = "khb", name = "Tai Lü")]
     TaiLue,
     #[language(short = "tdd")]
     TaiNuea,
     #[language(short = "tyj")]
     TaiYo,
-    #[language(short = "tgk", shortest = "tg")]
+    #[language(short = "tgk", shortest = "tg", native = "тоҷикӣ")]
     Tajik,
     #[language(short = "taq", old_short = "tmh")]
     Tamasheq,
-    #[language(short = "tzm")]
+    #[language(short = "tzm", name = "Central Atlas Tamazight")]
     TamazightCentralAtlas,
-    #[language(short = "tam", shortest = "ta")]
+    #[language(short = "tam", shortest = "ta", native = "தமிழ்")]
     Tamil,
     /// Macro, unique script
     #[language(short = "nst")]
     Tangsa,
     #[language(short = "txg")]
     Tangut,
-    #[language(short = "tat", shortest = "tt")]
+    #[language(short = "tat", shortest = "tt", native = "татар теле")]
     Tatar,
-    #[language(short = "crh")]
+    #[language(short = "crh", name = "Crimean Tatar")]
     TatarCrimean,
     #[language(short = "ctd")]
     Tedim,
-    #[language(short = "tel", shortest = "te")]
+    #[language(short = "tel", shortest = "te", native = "తెలుగు")]
     Telugu,
-    #[language(short = "tha", shortest = "th")]
+    #[language(short = "tha", shortest = "th", native = "ไทย")]
     Thai,
-    #[language(short = "bod", old_short = "tib", shortest = "bo")]
+    #[language(short = "bod", old_short = "tib", shortest = "bo", native = "བོད་སྐད")]
     Tibetan,
-    #[language(short = "tir", shortest = "ti")]
+    #[language(short = "tir", shortest = "ti", native = "ትግርኛ")]
     Tigrinya,
     #[language(short = "bbc")]
     TobaBatak,
@@ -680,21 +769,21 @@ pub enum Language {
     TokPisin,
     #[language(short = "txo")]
     Toto,
-    #[language(short = "tso", shortest = "ts")]
+    #[language(short = "tso", shortest = "ts", native = "Xitsonga")]
     Tsonga,
-    #[language(short = "tsn", shortest = "tn")]
+    #[language(short = "tsn", shortest = "tn", native = "Setswana")]
     Tswana,
     #[language(short = "tcy")]
     Tulu,
     #[language(short = "tum")]
     Tumbuka,
-    #[language(short = "tur", shortest = "tr")]
+    #[language(short = "tur", shortest = "tr", native = "Türkçe")]
     Turkish,
-    #[language(short = "tuk", shortest = "tk")]
+    #[language(short = "tuk", shortest = "tk", native = "türkmençe")]
     Turkmen,
     #[language(short = "uga")]
     Ugaritic,
-    #[language(short = "ukr", shortest = "uk")]
+    #[language(short = "ukr", shortest = "uk", native = "українська")]
     Ukrainian,
     #[language(short = "xum")]
     Umbrian,
@@ -703,39 +792,41 @@ pub enum Language {
     /// Undetermined language based on alphabets
     #[language(short = "und")]
     Unknown,
-    #[language(short = "urd", shortest = "ur")]
+    #[language(short = "urd", shortest = "ur", native = "اردو")]
     Urdu,
-    #[language(short = "uig", shortest = "ug")]
+    #[language(short = "uig", shortest = "ug", native = "ئۇيغۇرچە")]
     Uyghur,
-    #[language(short = "uzn", shortest = "uz")]
+    #[language(short = "uzn", shortest = "uz", name = "Uzbek", native = "oʻzbekcha")]
     UzbekNorthern,
     #[language(short = "vai")]
     Vai,
-    #[language(short = "vec")]
+    #[language(short = "vec", native = "vèneto")]
     Venetian,
-    #[language(short = "vie", shortest = "vi")]
+    #[language(short = "vie", shortest = "vi", native = "Tiếng Việt")]
     Vietnamese,
     #[language(short = "nnp")]
     Wancho,
     #[language(short = "war")]
     Waray,
-    #[language(short = "cym", old_short = "wel", shortest = "cy")]
+    #[language(short = "cym", old_short = "wel", shortest = "cy", native = "Cymraeg")]
     Welsh,
-    #[language(short = "wol", shortest = "wo")]
+    #[language(short = "wol", shortest = "wo", native = "Wolof")]
     Wolof,
-    #[language(short = "xho", shortest = "xh")]
+    #[language(short = "xho", shortest = "xh", native = "isiXhosa")]
     Xhosa,
-    #[language(short = "ydd", shortest = "yi")]
+    #[language(short = "ydd", shortest = "yi", name = "Yiddish", native = "ייִדיש")]
     YiddishEastern,
-    #[language(short = "yor", shortest = "yo")]
+    #[language(short = "yor", shortest = "yo", native = "Èdè Yorùbá")]
     Yoruba,
     #[language(short = "zag")]
     Zaghawa,
     #[language(short = "xzh")]
     Zhangzhung,
-    #[language(short = "zul", shortest = "zu")]
+    #[language(short = "zul", shortest = "zu", native = "isiZulu")]
     Zulu,
 }
 
 impl_try_from!(Language, u32, u32 i32 usize isize u64 i64 u128 i128);
 impl_serde!(Language, "Language");
+
+pub type LanguageArr<T> = [T; Language::COUNT];
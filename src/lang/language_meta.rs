@@ -0,0 +1,628 @@
+use super::{Language, ScriptLanguage, ScriptLanguageArr};
+use debug_unsafe::slice::SliceGetter;
+use strum::IntoEnumIterator;
+
+/// Top-level language family (or a kind of language, like creoles).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LanguageFamily {
+    AfroAsiatic,
+    /// Algonquian and related languages
+    Algic,
+    AustroAsiatic,
+    Austronesian,
+    Aymaran,
+    Constructed,
+    /// Creoles and pidgins
+    Creole,
+    Dravidian,
+    EskimoAleut,
+    HmongMien,
+    IndoEuropean,
+    Iroquoian,
+    /// Language isolates
+    Isolate,
+    Japonic,
+    Kartvelian,
+    Koreanic,
+    KraDai,
+    Mongolic,
+    NigerCongo,
+    NiloSaharan,
+    NortheastCaucasian,
+    NorthwestCaucasian,
+    Quechuan,
+    SignLanguage,
+    SinoTibetan,
+    Siouan,
+    Tupian,
+    Turkic,
+    Tyrsenian,
+    Uralic,
+}
+
+/// `Ancient` and `Extinct` languages are not spoken natively,
+/// but some are still used liturgically or in scholarship.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LanguageStatus {
+    Living,
+    /// Died out after antiquity
+    Extinct,
+    /// Language of antiquity
+    Ancient,
+    Constructed,
+}
+
+/// Approximate speakers count in millions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LanguageSpeakers {
+    /// First and second language speakers
+    pub total: f32,
+    /// First language speakers, if known
+    pub l1: Option<f32>,
+}
+
+impl Language {
+    /// `None` for unclassified languages.
+    pub const fn family(self) -> Option<LanguageFamily> {
+        use Language::*;
+        let family = match self {
+            Abkhaz => LanguageFamily::NorthwestCaucasian,
+            Acehnese | Angkola | Balinese | Banjar | Buginese | Buhid | Cebuano | ChamEastern
+            | ChamWestern | Fijian | Filipino | Hanunoo | Hawaiian | Ilocano | Indonesian
+            | Javanese | Karo | Makassarese | MalagasyPlateau | Malay | Mandailing | Maori
+            | Minangkabau | OldJavanese | Pakpak | Pangasinan | Rejang | Samoan | Simalungun
+            | Sundanese | Tagbanwa | TobaBatak | Waray => LanguageFamily::Austronesian,
+            Afrikaans
+            | Albanian
+            | AlbanianTosk
+            | AncientGreek
+            | Armenian
+            | Assamese
+            | Asturian
+            | Avestan
+            | Awadhi
+            | Belarusian
+            | Bengali
+            | Bhojpuri
+            | BishnupriyaManipuri
+            | Bosnian
+            | Bulgarian
+            | Carian
+            | Catalan
+            | Chakma
+            | Chhattisgarhi
+            | Chorasmian
+            | ChurchSlavonic
+            | Croatian
+            | Czech
+            | Danish
+            | Dhivehi
+            | Dogri
+            | DogriKangri
+            | Dutch
+            | English
+            | Faroese
+            | French
+            | Friulian
+            | GaelicScottish
+            | Galician
+            | Gandhari
+            | German
+            | Gothic
+            | Greek
+            | Gujarati
+            | Hindi
+            | Hittite
+            | Icelandic
+            | Irish
+            | Italian
+            | Kashmiri
+            | KurdishCentral
+            | KurdishNorthern
+            | KurdishSouthern
+            | Kutchi
+            | Latgalian
+            | Latin
+            | Latvian
+            | Ligurian
+            | Limburgish
+            | Lithuanian
+            | Lombard
+            | LuwianCuneiform
+            | LuwianHieroglyphic
+            | Luxembourgish
+            | Lycian
+            | Lydian
+            | Macedonian
+            | Magahi
+            | Maithili
+            | Marathi
+            | Marwari
+            | MiddlePersianManichaean
+            | MiddlePersianPahlavi
+            | MycenaeanGreek
+            | Nepali
+            | NorwegianBokmal
+            | NorwegianNynorsk
+            | Occitan
+            | Odia
+            | OldEnglish
+            | OldIrish
+            | OldNorse
+            | OldPersian
+            | Oscan
+            | Ossetian
+            | Parthian
+            | PashtoSouthern
+            | PersianDari
+            | PersianFarsi
+            | Polish
+            | Portuguese
+            | Prakrit
+            | PunjabiEastern
+            | Rohingya
+            | Romanian
+            | Russian
+            | Sambalpuri
+            | Sanskrit
+            | SanskritVedic
+            | Saraiki
+            | Sardinian
+            | Saurashtra
+            | Serbian
+            | Sicilian
+            | Sidetic
+            | Silesian
+            | Sindhi
+            | Sinhala
+            | Slovak
+            | Slovenian
+            | Sogdian
+            | Spanish
+            | Swedish
+            | Sylheti
+            | Tajik
+            | Ukrainian
+            | Umbrian
+            | Urdu
+            | Venetian
+            | Welsh
+            | YiddishEastern => LanguageFamily::IndoEuropean,
+            Ahom | Isan | Lao | NorthernThai | Shan | TaiDam | TaiDon | TaiLue | TaiNuea
+            | TaiYo | Thai => LanguageFamily::KraDai,
+            AkanFante | AkanTwi | Bambara | Bamum | Bassa | Bemba | Chokwe | Dyula | Ewe | Fon
+            | FulaPulaar | FulaPular | FulfuldeNigerian | Ganda | Igbo | Kabiye | Kamba
+            | Kikuyu | Kimbundu | Kinyarwanda | Lingala | LubaKasai | Manding | Mende | Mossi
+            | Nyanja | Rundi | Sepedi | Serer | Sesotho | Shona | Swahili | Swati | Tsonga
+            | Tswana | Tumbuka | Umbundu | Vai | Wolof | Xhosa | Yoruba | Zulu => {
+                LanguageFamily::NigerCongo
+            }
+            Akkadian
+            | Amharic
+            | AncientNorthArabian
+            | AncientSouthArabian
+            | Arabic
+            | ArabicEgyptian
+            | ArabicMesopotamian
+            | ArabicMoroccan
+            | ArabicNajdi
+            | ArabicNorthLevantine
+            | ArabicSouthernYemeni
+            | ArabicSouthLevantine
+            | ArabicSudanese
+            | ArabicTunisian
+            | AramaicElymaic
+            | AramaicHatran
+            | AramaicImperial
+            | AramaicMandaic
+            | AramaicNabataean
+            | AramaicPalmyrene
+            | AramaicSamaritan
+            | AramaicSyriac
+            | Coptic
+            | EgyptianHieroglyphs
+            | Geez
+            | Hausa
+            | Hebrew
+            | HebrewSamaritan
+            | Kabyle
+            | Maltese
+            | OromoSouthern
+            | OromoWestCentral
+            | Phoenician
+            | Somali
+            | Tamasheq
+            | TamazightCentralAtlas
+            | Tigrinya
+            | Ugaritic => LanguageFamily::AfroAsiatic,
+            AymaraCentral => LanguageFamily::Aymaran,
+            AzerbaijaniNorth | AzerbaijaniSouth | Bashkir | Chuvash | Kazakh | Kyrgyz
+            | OldTurkic | OldUyghur | Tatar | TatarCrimean | Turkish | Turkmen | Uyghur
+            | UzbekNorthern => LanguageFamily::Turkic,
+            Bantawa | Burmese | ChineseCantonese | ChineseMandarin | ChineseTuhua | Dzongkha
+            | Gurung | Jingpho | KarenSgaw | KayahEastern | KayahWestern | Lepcha | Limbu
+            | Lisu | Meitei | Mizo | Mro | Newar | Nuosu | Sunuwar | Tangsa | Tangut | Tedim
+            | Tibetan | Toto | Wancho | Zhangzhung => LanguageFamily::SinoTibetan,
+            Basque | Sumerian => LanguageFamily::Isolate,
+            Bhumij | Ho | Khmer | KhmerNorthern | Mon | Mundari | Santali | Sora | Vietnamese => {
+                LanguageFamily::AustroAsiatic
+            }
+            Buryat | Khitan | MongolianBuryat | MongolianKhalkha | Oirat => {
+                LanguageFamily::Mongolic
+            }
+            CaucasianAlbanian | Chechen => LanguageFamily::NortheastCaucasian,
+            Cherokee => LanguageFamily::Iroquoian,
+            Cree | Ojibwe => LanguageFamily::Algic,
+            CreoleHaitian | Kabuverdianu | KikongoKituba | NigerianPidgin | Papiamento | Sango
+            | TokPisin => LanguageFamily::Creole,
+            Dholuo | DinkaSouthwestern | KanuriCentral | Meroitic | Nuer | Zaghawa => {
+                LanguageFamily::NiloSaharan
+            }
+            Esperanto | Medefaidrin => LanguageFamily::Constructed,
+            Estonian | Finnish | Hungarian | KomiPermyak | KomiZyrian | MariEastern | Nenets
+            | SamiNorthern => LanguageFamily::Uralic,
+            Etruscan => LanguageFamily::Tyrsenian,
+            Georgian => LanguageFamily::Kartvelian,
+            Gondi | Kannada | Kurukh | Malayalam | Tamil | Telugu | Tulu => {
+                LanguageFamily::Dravidian
+            }
+            GuaraniParaguayan => LanguageFamily::Tupian,
+            Hmong => LanguageFamily::HmongMien,
+            Inuktitut => LanguageFamily::EskimoAleut,
+            Japanese => LanguageFamily::Japonic,
+            Korean => LanguageFamily::Koreanic,
+            Osage => LanguageFamily::Siouan,
+            QuechuaAyacucho => LanguageFamily::Quechuan,
+            SignLanguages => LanguageFamily::SignLanguage,
+            Math | Minoan | MinoanLinearA | Unknown => return None,
+        };
+        Some(family)
+    }
+
+    pub const fn status(self) -> LanguageStatus {
+        use Language::*;
+        match self {
+            Akkadian | AncientGreek | AncientNorthArabian | AncientSouthArabian
+            | AramaicElymaic | AramaicHatran | AramaicImperial | AramaicNabataean
+            | AramaicPalmyrene | AramaicSyriac | Avestan | Carian | EgyptianHieroglyphs
+            | Etruscan | Gandhari | Gothic | Hittite | Latin | LuwianCuneiform
+            | LuwianHieroglyphic | Lycian | Lydian | Meroitic | Minoan | MinoanLinearA
+            | MycenaeanGreek | OldPersian | Oscan | Parthian | Phoenician | Prakrit | Sanskrit
+            | SanskritVedic | Sidetic | Sumerian | Ugaritic | Umbrian => LanguageStatus::Ancient,
+            Ahom
+            | AramaicSamaritan
+            | CaucasianAlbanian
+            | Chorasmian
+            | ChurchSlavonic
+            | Coptic
+            | Geez
+            | HebrewSamaritan
+            | Khitan
+            | MiddlePersianManichaean
+            | MiddlePersianPahlavi
+            | OldEnglish
+            | OldIrish
+            | OldJavanese
+            | OldNorse
+            | OldTurkic
+            | OldUyghur
+            | Sogdian
+            | Tangut
+            | Zhangzhung => LanguageStatus::Extinct,
+            Esperanto | Medefaidrin | Math => LanguageStatus::Constructed,
+            _ => LanguageStatus::Living,
+        }
+    }
+
+    #[inline]
+    pub const fn is_living(self) -> bool {
+        matches!(self.status(), LanguageStatus::Living)
+    }
+
+    /// ISO 639-3 code of the macrolanguage, which includes this language.
+    pub const fn macrolanguage(self) -> Option<&'static str> {
+        use Language::*;
+        let code = match self {
+            AkanFante | AkanTwi => "aka",
+            Arabic | ArabicEgyptian | ArabicMesopotamian | ArabicMoroccan | ArabicNajdi
+            | ArabicNorthLevantine | ArabicSouthernYemeni | ArabicSouthLevantine
+            | ArabicSudanese | ArabicTunisian => "ara",
+            AymaraCentral => "aym",
+            AzerbaijaniNorth | AzerbaijaniSouth => "aze",
+            Buryat | MongolianBuryat => "bua",
+            MariEastern => "chm",
+            DinkaSouthwestern => "din",
+            DogriKangri => "doi",
+            Estonian => "est",
+            PersianDari | PersianFarsi => "fas",
+            FulaPulaar | FulaPular | FulfuldeNigerian => "ful",
+            GuaraniParaguayan => "grn",
+            Bosnian | Croatian | Serbian => "hbs",
+            KanuriCentral => "kau",
+            KomiPermyak | KomiZyrian => "kom",
+            KikongoKituba => "kon",
+            KurdishCentral | KurdishNorthern | KurdishSouthern => "kur",
+            Saraiki => "lah",
+            Latgalian | Latvian => "lav",
+            MalagasyPlateau => "mlg",
+            MongolianKhalkha => "mon",
+            Banjar | Malay | Minangkabau => "msa",
+            Nepali => "nep",
+            NorwegianBokmal | NorwegianNynorsk => "nor",
+            Odia => "ori",
+            OromoSouthern | OromoWestCentral => "orm",
+            PashtoSouthern => "pus",
+            QuechuaAyacucho => "que",
+            Sanskrit | SanskritVedic => "san",
+            AlbanianTosk => "sqi",
+            Swahili => "swa",
+            UzbekNorthern => "uzb",
+            YiddishEastern => "yid",
+            ChineseCantonese | ChineseMandarin => "zho",
+            _ => return None,
+        };
+        Some(code)
+    }
+
+    /// Approximate speakers count, `None` if unknown.
+    pub const fn speakers(self) -> Option<LanguageSpeakers> {
+        use Language::*;
+        let (total, l1) = match self {
+            Abkhaz => (0.2, None),
+            Acehnese => (2.8, None),
+            Afrikaans => (17.5, None),
+            AkanFante => (2.8, None),
+            AlbanianTosk => (1.8, None),
+            Amharic => (60.0, None),
+            Angkola => (0.5, None),
+            Arabic => (335.0, None),
+            ArabicEgyptian => (119.0, None),
+            ArabicMesopotamian => (17.0, None),
+            ArabicMoroccan => (40.0, None),
+            ArabicNajdi => (19.0, None),
+            ArabicNorthLevantine => (60.0, None),
+            ArabicSouthernYemeni => (12.0, None),
+            ArabicSudanese => (52.0, None),
+            ArabicTunisian => (13.0, None),
+            Armenian => (5.4, None),
+            Assamese => (24.0, None),
+            Asturian => (0.62, None),
+            Awadhi => (39.0, None),
+            AymaraCentral => (1.7, None),
+            AzerbaijaniNorth => (10.0, None),
+            AzerbaijaniSouth => (14.0, None),
+            Balinese => (3.3, None),
+            Bambara => (14.2, None),
+            Banjar => (10.6, None),
+            Bashkir => (0.75, None),
+            Basque => (0.8, None),
+            Belarusian => (5.0, None),
+            Bemba => (4.1, None),
+            Bengali => (284.0, Some(234.0)),
+            Bhojpuri => (52.0, None),
+            BishnupriyaManipuri => (0.12, None),
+            Bosnian => (2.7, None),
+            Buginese => (4.0, None),
+            Bulgarian => (7.9, None),
+            Burmese => (43.0, None),
+            Buryat => (0.22, None),
+            Catalan => (9.2, None),
+            Cebuano => (20.0, None),
+            ChamEastern => (0.5, None),
+            Chechen => (1.8, None),
+            Chhattisgarhi => (16.0, None),
+            ChineseCantonese => (15.0, None),
+            ChineseMandarin => (1200.0, Some(940.0)),
+            Chokwe => (2.5, None),
+            Chuvash => (0.75, None),
+            Cree => (0.096, None),
+            CreoleHaitian => (13.0, None),
+            Croatian => (6.5, None),
+            Czech => (12.0, None),
+            Danish => (6.0, None),
+            Dhivehi => (0.34, None),
+            Dholuo => (4.2, None),
+            Dogri => (2.6, None),
+            Dutch => (30.0, None),
+            Dyula => (13.0, None),
+            Dzongkha => (0.64, None),
+            English => (1500.0, Some(390.0)),
+            Esperanto => (2.0, None),
+            Estonian => (1.2, None),
+            Ewe => (5.0, None),
+            Faroese => (0.07, None),
+            Fijian => (0.7, None),
+            Filipino => (83.0, None),
+            Finnish => (5.0, None),
+            Fon => (2.3, None),
+            French => (310.0, Some(74.0)),
+            Friulian => (0.6, None),
+            FulaPulaar => (6.3, None),
+            FulaPular => (4.8, None),
+            FulfuldeNigerian => (17.0, None),
+            GaelicScottish => (0.07, None),
+            Galician => (2.4, None),
+            Ganda => (11.0, None),
+            Georgian => (3.9, None),
+            German => (180.0, Some(76.0)),
+            Greek => (13.5, None),
+            GuaraniParaguayan => (6.5, None),
+            Gujarati => (62.0, Some(57.0)),
+            Hausa => (94.0, None),
+            Hawaiian => (0.025, None),
+            Hebrew => (8.3, None),
+            Hindi => (610.0, Some(345.0)),
+            Hungarian => (14.0, None),
+            Icelandic => (0.33, None),
+            Igbo => (47.0, None),
+            Ilocano => (11.0, None),
+            Indonesian => (270.0, Some(43.0)),
+            Inuktitut => (0.042, None),
+            Irish => (1.8, None),
+            Isan => (22.0, None),
+            Italian => (68.0, Some(64.0)),
+            Japanese => (123.0, Some(123.0)),
+            Javanese => (68.0, None),
+            Jingpho => (0.94, None),
+            Kabiye => (1.0, None),
+            Kabuverdianu => (0.87, None),
+            Kabyle => (3.0, None),
+            Kamba => (5.6, None),
+            Kannada => (79.0, None),
+            KanuriCentral => (9.1, None),
+            KarenSgaw => (2.2, None),
+            Karo => (0.5, None),
+            Kashmiri => (7.1, None),
+            Kazakh => (16.0, None),
+            Khmer => (21.0, None),
+            KhmerNorthern => (1.4, None),
+            KikongoKituba => (18.2, None),
+            Kikuyu => (6.6, None),
+            Kimbundu => (1.7, None),
+            Kinyarwanda => (15.0, None),
+            KomiPermyak => (0.063, None),
+            KomiZyrian => (0.1, None),
+            Korean => (82.0, Some(81.0)),
+            KurdishCentral => (6.1, None),
+            KurdishNorthern => (17.0, None),
+            KurdishSouthern => (6.0, None),
+            Kyrgyz => (5.2, None),
+            Lao => (4.5, None),
+            Latgalian => (0.2, None),
+            Latvian => (1.5, None),
+            Ligurian => (0.6, None),
+            Limburgish => (1.3, None),
+            Lingala => (41.0, None),
+            Lithuanian => (3.0, None),
+            Lombard => (3.8, None),
+            LubaKasai => (15.0, None),
+            Luxembourgish => (0.4, None),
+            Macedonian => (2.0, None),
+            Magahi => (13.0, None),
+            Maithili => (22.0, None),
+            Makassarese => (2.1, None),
+            Malay => (32.0, None),
+            Malayalam => (38.0, None),
+            Maltese => (0.57, None),
+            Mandailing => (1.1, None),
+            Manding => (9.1, None),
+            Maori => (0.05, None),
+            Marathi => (99.0, Some(83.0)),
+            MariEastern => (0.25, None),
+            Meitei => (3.0, None),
+            Minangkabau => (5.5, None),
+            Mizo => (1.0, None),
+            Mon => (1.1, None),
+            MongolianBuryat => (0.02, None),
+            MongolianKhalkha => (5.0, None),
+            Mossi => (12.0, None),
+            Nenets => (0.025, None),
+            Nepali => (32.0, None),
+            NigerianPidgin => (120.0, None),
+            NorthernThai => (6.0, None),
+            NorwegianBokmal => (4.3, None),
+            Nuer => (1.7, None),
+            Nyanja => (7.0, None),
+            Occitan => (0.8, None),
+            Odia => (37.6, None),
+            Oirat => (0.37, None),
+            Ojibwe => (0.05, None),
+            Ossetian => (0.5, None),
+            Pakpak => (1.2, None),
+            Pangasinan => (1.8, None),
+            Papiamento => (0.35, None),
+            PashtoSouthern => (18.5, None),
+            PersianDari => (33.0, None),
+            PersianFarsi => (83.0, Some(57.0)),
+            Polish => (43.0, Some(40.0)),
+            Portuguese => (267.0, Some(250.0)),
+            PunjabiEastern => (52.0, None),
+            QuechuaAyacucho => (0.92, None),
+            Romanian => (22.0, None),
+            Rundi => (13.0, None),
+            Russian => (253.0, Some(148.0)),
+            Sambalpuri => (2.63, None),
+            SamiNorthern => (0.025, None),
+            Samoan => (0.43, None),
+            Sango => (0.62, None),
+            Santali => (7.6, None),
+            Saraiki => (29.0, None),
+            Sardinian => (1.0, None),
+            Saurashtra => (0.2, None),
+            Sepedi => (15.3, None),
+            Serbian => (12.0, None),
+            Serer => (1.9, None),
+            Sesotho => (13.5, None),
+            Shan => (4.7, None),
+            Shona => (14.0, None),
+            Sicilian => (4.7, None),
+            Silesian => (0.46, None),
+            Simalungun => (1.2, None),
+            Sindhi => (37.0, None),
+            Sinhala => (19.0, None),
+            Slovak => (7.1, None),
+            Slovenian => (2.5, None),
+            Somali => (24.0, None),
+            Spanish => (558.0, Some(485.0)),
+            Sundanese => (32.0, None),
+            Swahili => (98.0, None),
+            Swati => (4.7, None),
+            Swedish => (13.0, None),
+            TaiDam => (0.76, None),
+            TaiDon => (0.5, None),
+            TaiLue => (0.55, None),
+            Tajik => (10.5, None),
+            Tamasheq => (0.9, None),
+            TamazightCentralAtlas => (2.7, None),
+            Tamil => (86.0, Some(79.0)),
+            Tatar => (4.8, None),
+            TatarCrimean => (0.06, None),
+            Telugu => (96.0, Some(83.0)),
+            Thai => (71.0, None),
+            Tibetan => (1.2, None),
+            Tigrinya => (9.9, None),
+            TobaBatak => (1.6, None),
+            TokPisin => (4.1, None),
+            Tsonga => (7.1, None),
+            Tswana => (8.0, None),
+            Tumbuka => (7.1, None),
+            Turkish => (90.0, Some(82.0)),
+            Turkmen => (7.8, None),
+            Ukrainian => (39.0, None),
+            Umbundu => (7.0, None),
+            Urdu => (255.0, Some(70.0)),
+            Uyghur => (13.0, None),
+            UzbekNorthern => (58.0, None),
+            Venetian => (3.9, None),
+            Vietnamese => (97.0, Some(86.0)),
+            Wancho => (0.06, None),
+            Waray => (3.6, None),
+            Welsh => (0.65, None),
+            Wolof => (18.0, None),
+            Xhosa => (19.0, None),
+            Yoruba => (50.0, None),
+            Zulu => (28.0, None),
+            _ => return None,
+        };
+        Some(LanguageSpeakers { total, l1 })
+    }
+}
+
+/// Counts of `ScriptLanguage`s, which `Language` doesn't satisfy `f`, are zeroed.
+pub fn filter_languages(
+    mut langs_cnt: ScriptLanguageArr<u32>,
+    f: impl Fn(Language) -> bool,
+) -> ScriptLanguageArr<u32> {
+    for slang in ScriptLanguage::iter() {
+        if !f(Language::from(slang)) {
+            *langs_cnt.get_safe_unchecked_mut(slang as usize) = 0;
+        }
+    }
+    langs_cnt
+}
+
+/// Only living languages are retained ([`LanguageStatus::Living`](enum.LanguageStatus.html#variant.Living)).
+#[inline]
+pub fn filter_living(langs_cnt: ScriptLanguageArr<u32>) -> ScriptLanguageArr<u32> {
+    filter_languages(langs_cnt, Language::is_living)
+}
@@ -0,0 +1,28 @@
+/// Tier of a letter in an alphabet.
+///
+/// Marked in `alphabet_match!` by `extended` or `loanword` before the letters
+/// (and `core` to return to the core letters).
+/// Extended and loanword letters are left out of the default detection, as if not listed.
+/// With [`FulltextOptions::tier_weights`](struct.FulltextOptions.html#method.tier_weights)
+/// detection counts are sums of the letters [`weight`](#method.weight)s, so they count
+/// toward a `ScriptLanguage` too, but support it less than the core ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LetterTier {
+    #[default]
+    Core,
+    /// Used in dialects, transliteration or old orthography
+    Extended,
+    /// Used only in loanwords or foreign names
+    Loanword,
+}
+
+impl LetterTier {
+    #[inline]
+    pub const fn weight(self) -> u32 {
+        match self {
+            Self::Core => 3,
+            Self::Extended => 2,
+            Self::Loanword => 1,
+        }
+    }
+}
@@ -91,16 +91,27 @@ macro_rules! impl_serde {
 }
 
 mod alphabets;
+mod bcp47;
+mod distinguishing;
 mod language;
+mod language_meta;
+mod letter_tier;
 mod script;
 mod script_language;
+mod script_language_set;
+mod verification;
 
 pub mod ucd;
 
 pub use alphabets::*;
+pub use distinguishing::*;
 pub use language::*;
+pub use language_meta::*;
+pub use letter_tier::*;
 pub use script::*;
 pub use script_language::*;
-pub use ucd::UcdScript;
+pub use script_language_set::*;
+pub use ucd::{ScriptDirection, ScriptType, UcdScript, UcdScriptArr};
+pub use verification::*;
 
 pub use strum::{EnumCount, IntoEnumIterator};
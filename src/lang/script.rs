@@ -1,8 +1,9 @@
 use super::UcdScript;
 use alphabet_detector_macros::Script;
+use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
 
-// ISO 15924 code.
+// ISO 15924 code. name = English name (if it differs from the variant name).
 /// Has aliases in comparison to [`UcdScript`](enum.UcdScript.html).
 /// Int representation is unstable and can be changed anytime.
 /// Code representation (const
@@ -50,7 +51,11 @@ pub enum Script {
     Buginese,
     #[scr(short = "Buhd", code = 372)]
     Buhid,
-    #[scr(short = "Cans", code = 440)]
+    #[scr(
+        short = "Cans",
+        code = 440,
+        name = "Unified Canadian Aboriginal Syllabics"
+    )]
     CanadianAboriginal,
     #[scr(short = "Cari", code = 201)]
     Carian,
@@ -68,7 +73,7 @@ pub enum Script {
     Common,
     #[scr(short = "Copt", code = 204)]
     Coptic,
-    #[scr(short = "Xsux", code = 20)]
+    #[scr(short = "Xsux", code = 20, name = "Sumero-Akkadian Cuneiform")]
     Cuneiform,
     #[scr(short = "Cprt", code = 403)]
     Cypriot,
@@ -120,11 +125,11 @@ pub enum Script {
     Hangul,
     #[scr(short = "Rohg", code = 167)]
     HanifiRohingya,
-    #[scr(short = "Hans", code = 501)]
+    #[scr(short = "Hans", code = 501, name = "Simplified Han")]
     HanSimplified,
-    #[scr(short = "Hant", code = 502)]
+    #[scr(short = "Hant", code = 502, name = "Traditional Han")]
     HanTraditional,
-    #[scr(short = "Hano", code = 371)]
+    #[scr(short = "Hano", code = 371, name = "Hanunó'o")]
     Hanunoo,
     #[scr(short = "Hatr", code = 127)]
     Hatran,
@@ -232,7 +237,7 @@ pub enum Script {
     Newa,
     #[scr(short = "Talu", code = 354)]
     NewTaiLue,
-    #[scr(short = "Nkoo", code = 165)]
+    #[scr(short = "Nkoo", code = 165, name = "N'Ko")]
     Nko,
     #[scr(short = "Nshu", code = 499)]
     Nushu,
@@ -262,7 +267,7 @@ pub enum Script {
     OldUyghur,
     #[scr(short = "Onao", code = 296)]
     OlOnal,
-    #[scr(short = "Orya", code = 327)]
+    #[scr(short = "Orya", code = 327, name = "Odia")]
     Oriya,
     #[scr(short = "Osge", code = 219)]
     Osage,
@@ -274,7 +279,7 @@ pub enum Script {
     Palmyrene,
     #[scr(short = "Pauc", code = 263)]
     PauCinHau,
-    #[scr(short = "Phag", code = 331)]
+    #[scr(short = "Phag", code = 331, name = "Phags-pa")]
     PhagsPa,
     #[scr(short = "Phnx", code = 115)]
     Phoenician,
@@ -296,7 +301,7 @@ pub enum Script {
     Siddham,
     #[scr(short = "Sidt", code = 180)]
     Sidetic,
-    #[scr(short = "Sgnw", code = 95)]
+    #[scr(short = "Sgnw", code = 95, name = "SignWriting")]
     SignWriting,
     #[scr(short = "Sinh", code = 348)]
     Sinhala,
@@ -375,6 +380,8 @@ pub enum Script {
 impl_try_from!(Script, u16, u16 i16 u32 i32 usize isize u64 i64 u128 i128);
 impl_serde!(Script, "Script");
 
+pub type ScriptArr<T> = [T; Script::COUNT];
+
 impl From<Script> for UcdScript {
     #[inline]
     fn from(s: Script) -> Self {
@@ -1,6 +1,8 @@
-use super::{script_char_to_slangs, Language, Script, UcdScript};
+use super::{
+    script_char_to_slangs, script_char_to_tiered_slangs, Language, LetterTier, Script, UcdScript,
+};
 use ::std::fmt::Debug;
-use alphabet_detector_macros::ScriptLanguage;
+use alphabet_detector_macros::{slang_features, slang_unknown, ScriptLanguage};
 use strum::{EnumCount, IntoEnumIterator};
 use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
 
@@ -14,6 +16,12 @@ use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
 /// or string representation (const
 /// [`into_str`](enum.ScriptLanguage.html#method.into_str)/[`from_str`](enum.ScriptLanguage.html#method.from_str))
 /// are more stable.
+///
+/// Variants of the scripts excluded by cargo features are left out.
+/// `Unknown<Script>` variants are generated only for the scripts, where letters
+/// no alphabet claims fall back to them.
+#[slang_unknown(Latin, Arabic, Devanagari, Cyrillic, Hebrew, Braille)]
+#[slang_features]
 #[derive(
     Clone,
     Copy,
@@ -126,6 +134,8 @@ pub enum ScriptLanguage {
     Ganda, // 11 old
     #[slang(script = Latin)]
     Mossi, // 12
+    #[slang(script = Latin, lang = Serbian)]
+    SerbianLatin, // 12 (digraphic)
     #[slang(script = Latin)]
     Banjar, // 10.6 old
     #[slang(script = Latin)]
@@ -304,8 +314,6 @@ pub enum ScriptLanguage {
     Hawaiian, // 0.025
     #[slang(script = Latin)]
     Latin, // 0
-    #[slang(script = Latin, lang = Unknown)]
-    UnknownLatin,
 
     // Han
     #[slang(script = HanSimplified, lang = ChineseMandarin)]
@@ -376,8 +384,6 @@ pub enum ScriptLanguage {
     DogriPersoArabic, // 2.6
     #[slang(script = Arabic, lang = Math)]
     MathArabic,
-    // #[slang(script = Arabic, lang = Unknown)]
-    // UnknownArabic,
 
     // Devanagari
     #[slang(script = Devanagari)]
@@ -412,8 +418,6 @@ pub enum ScriptLanguage {
     SanskritVedic,
     #[slang(script = Devanagari, lang = Math)]
     MathDevanagari,
-    // #[slang(script = Devanagari, lang = Unknown)]
-    // UnknownDevanagari,
 
     // Cyrillic
     #[slang(script = Cyrillic)]
@@ -456,8 +460,6 @@ pub enum ScriptLanguage {
     Nenets, // 0.025
     #[slang(script = Cyrillic, lang = ChurchSlavonic)]
     ChurchSlavonicOld,
-    #[slang(script = Cyrillic, lang = Unknown)]
-    UnknownCyrillic,
 
     // Bengali
     #[slang(script = Bengali)]
@@ -758,9 +760,6 @@ pub enum ScriptLanguage {
 
     #[slang(script = Braille, lang = Math)]
     MathBraille,
-    /// any language adapted to Braille
-    #[slang(script = Braille, lang = Unknown)]
-    UnknownBraille,
 
     // Single language scripts
     #[slang(script = Ahom)]
@@ -1032,15 +1031,42 @@ impl ScriptLanguage {
     #[inline]
     pub fn all_with_script(script: UcdScript) -> &'static [Self] {
         let ch = match script {
-            // UcdScript::Arabic => 'ل', // except ScriptLanguage::MathArabic
+            UcdScript::Arabic => 'ل', // except ScriptLanguage::MathArabic
             UcdScript::Cyrillic => 'А',
-            // UcdScript::Devanagari => 'ठ', // except ScriptLanguage::MathDevanagari
+            UcdScript::Devanagari => 'ठ', // except ScriptLanguage::MathDevanagari
+            UcdScript::Hebrew => 'א',
             UcdScript::Latin => 'A',
             _ => char::default(),
         };
         script_char_to_slangs(script, ch)
     }
 
+    /// Is `ch` a letter of this `ScriptLanguage`.
+    ///
+    /// Checks [`alphabet`](#method.alphabet), if it's not listed,
+    /// then checks the detection of `ch` ([`script_char_to_slangs`](fn.script_char_to_slangs.html)).
+    #[inline]
+    pub fn alphabet_contains(self, ch: char) -> bool {
+        match self.alphabet() {
+            Some(alphabet) => alphabet.contains(&ch),
+            None => script_char_to_slangs(UcdScript::find(ch), ch).contains(&self),
+        }
+    }
+
+    /// Tier of `ch` in this `ScriptLanguage`'s alphabet, `None` if it's not a letter of it
+    /// ([`alphabet_contains`](#method.alphabet_contains)).
+    #[inline]
+    pub fn letter_tier(self, ch: char) -> Option<LetterTier> {
+        if !self.alphabet_contains(ch) {
+            return None;
+        }
+        let tier = script_char_to_tiered_slangs(UcdScript::from(self), ch)
+            .iter()
+            .find(|(slang, _)| *slang == self)
+            .map_or(LetterTier::Core, |&(_, tier)| tier);
+        Some(tier)
+    }
+
     /// # Safety
     ///
     /// Must be a valid value of `ScriptLanguage`.
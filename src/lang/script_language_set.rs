@@ -0,0 +1,381 @@
+use super::{script_char_to_slangs, ScriptLanguage, ScriptLanguageArr, UcdScript};
+use ::core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+};
+use debug_unsafe::slice::SliceGetter;
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use strum::EnumCount;
+
+macro_rules! zip_words {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $op:expr) => {{
+        let (mut res, other) = ($a, $b);
+        let mut i = 0;
+        while i < WORDS {
+            let ($x, $y) = (res.0[i], other.0[i]);
+            res.0[i] = $op;
+            i += 1;
+        }
+        res
+    }};
+}
+
+const WORDS: usize = ScriptLanguage::COUNT.div_ceil(u64::BITS as usize);
+
+/// Fixed-size bitset of `ScriptLanguage`s.
+///
+/// Iterates in `ScriptLanguage` (int representation) order.
+/// Serialized as a list of codes: `["engLatn", "rusCyrl"]`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScriptLanguageSet([u64; WORDS]);
+
+impl ScriptLanguageSet {
+    pub const EMPTY: Self = Self([0; WORDS]);
+    pub const ALL: Self = Self::all();
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    const fn all() -> Self {
+        let mut words = [u64::MAX; WORDS];
+        let rem = ScriptLanguage::COUNT % u64::BITS as usize;
+        if rem != 0 {
+            words[WORDS - 1] = (1 << rem) - 1;
+        }
+        Self(words)
+    }
+
+    #[inline]
+    pub const fn from_slice(slangs: &[ScriptLanguage]) -> Self {
+        let mut res = Self::EMPTY;
+        let mut i = 0;
+        while i < slangs.len() {
+            res.insert(slangs[i]);
+            i += 1;
+        }
+        res
+    }
+
+    /// `ScriptLanguage`s with non zero count
+    #[inline]
+    pub fn from_counts(langs_cnt: &ScriptLanguageArr<u32>) -> Self {
+        let mut res = Self::EMPTY;
+        for (i, &cnt) in langs_cnt.iter().enumerate() {
+            if cnt > 0 {
+                *res.0.get_safe_unchecked_mut(i / 64) |= 1 << (i % 64);
+            }
+        }
+        res
+    }
+
+    /// Returns `true` if `slang` was not present
+    #[inline]
+    pub const fn insert(&mut self, slang: ScriptLanguage) -> bool {
+        let (word, bit) = Self::position(slang);
+        let absent = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        absent
+    }
+
+    /// Returns `true` if `slang` was present
+    #[inline]
+    pub const fn remove(&mut self, slang: ScriptLanguage) -> bool {
+        let (word, bit) = Self::position(slang);
+        let present = self.0[word] & bit != 0;
+        self.0[word] &= !bit;
+        present
+    }
+
+    #[inline]
+    pub const fn contains(&self, slang: ScriptLanguage) -> bool {
+        let (word, bit) = Self::position(slang);
+        self.0[word] & bit != 0
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        let mut res = 0;
+        let mut i = 0;
+        while i < WORDS {
+            res += self.0[i].count_ones() as usize;
+            i += 1;
+        }
+        res
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        zip_words!(self, other, |a, b| a | b)
+    }
+
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        zip_words!(self, other, |a, b| a & b)
+    }
+
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        zip_words!(self, other, |a, b| a & !b)
+    }
+
+    #[inline]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        zip_words!(self, other, |a, b| a ^ b)
+    }
+
+    #[inline]
+    pub const fn complement(self) -> Self {
+        Self::ALL.difference(self)
+    }
+
+    #[inline]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.0[i] & !other.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[inline]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    #[inline]
+    pub const fn first(&self) -> Option<ScriptLanguage> {
+        let mut i = 0;
+        while i < WORDS {
+            if self.0[i] != 0 {
+                let idx = i * 64 + self.0[i].trailing_zeros() as usize;
+                return Some(unsafe { ScriptLanguage::transmute_from_usize(idx) });
+            }
+            i += 1;
+        }
+        None
+    }
+
+    #[inline]
+    pub fn iter(&self) -> ScriptLanguageSetIter {
+        ScriptLanguageSetIter {
+            words: self.0,
+            word: 0,
+        }
+    }
+
+    #[inline(always)]
+    const fn position(slang: ScriptLanguage) -> (usize, u64) {
+        let i = slang as usize;
+        (i / 64, 1 << (i % 64))
+    }
+}
+
+impl fmt::Debug for ScriptLanguageSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over `ScriptLanguageSet` in `ScriptLanguage` (int representation) order
+#[derive(Clone, Debug)]
+pub struct ScriptLanguageSetIter {
+    words: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for ScriptLanguageSetIter {
+    type Item = ScriptLanguage;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word < WORDS {
+            let bits = self.words.get_safe_unchecked_mut(self.word);
+            if *bits != 0 {
+                let idx = self.word * 64 + bits.trailing_zeros() as usize;
+                *bits &= *bits - 1;
+                return Some(unsafe { ScriptLanguage::transmute_from_usize(idx) });
+            }
+            self.word += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words[self.word.min(WORDS)..]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ScriptLanguageSetIter {}
+impl FusedIterator for ScriptLanguageSetIter {}
+
+impl IntoIterator for ScriptLanguageSet {
+    type Item = ScriptLanguage;
+    type IntoIter = ScriptLanguageSetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ScriptLanguageSet {
+    type Item = ScriptLanguage;
+    type IntoIter = ScriptLanguageSetIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<ScriptLanguage> for ScriptLanguageSet {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = ScriptLanguage>>(iter: T) -> Self {
+        let mut res = Self::EMPTY;
+        res.extend(iter);
+        res
+    }
+}
+
+impl Extend<ScriptLanguage> for ScriptLanguageSet {
+    #[inline]
+    fn extend<T: IntoIterator<Item = ScriptLanguage>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|slang| {
+            self.insert(slang);
+        });
+    }
+}
+
+impl From<ScriptLanguage> for ScriptLanguageSet {
+    #[inline]
+    fn from(slang: ScriptLanguage) -> Self {
+        Self::from_slice(&[slang])
+    }
+}
+
+impl From<&[ScriptLanguage]> for ScriptLanguageSet {
+    #[inline]
+    fn from(slangs: &[ScriptLanguage]) -> Self {
+        Self::from_slice(slangs)
+    }
+}
+
+impl From<&ScriptLanguageArr<bool>> for ScriptLanguageSet {
+    #[inline]
+    fn from(arr: &ScriptLanguageArr<bool>) -> Self {
+        let mut res = Self::EMPTY;
+        for (i, &v) in arr.iter().enumerate() {
+            if v {
+                *res.0.get_safe_unchecked_mut(i / 64) |= 1 << (i % 64);
+            }
+        }
+        res
+    }
+}
+
+macro_rules! impl_set_op {
+    ($tr:ident, $method:ident, $tr_assign:ident, $method_assign:ident, $f:ident) => {
+        impl $tr for ScriptLanguageSet {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                self.$f(rhs)
+            }
+        }
+
+        impl $tr_assign for ScriptLanguageSet {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.$f(rhs);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, difference);
+impl_set_op!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    symmetric_difference
+);
+
+impl Not for ScriptLanguageSet {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl Serialize for ScriptLanguageSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for slang in self {
+            seq.serialize_element(&slang)?;
+        }
+        seq.end()
+    }
+}
+
+struct ScriptLanguageSetVisitor;
+
+impl<'de> Visitor<'de> for ScriptLanguageSetVisitor {
+    type Value = ScriptLanguageSet;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("list of ScriptLanguage")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut res = ScriptLanguageSet::EMPTY;
+        while let Some(slang) = access.next_element::<ScriptLanguage>()? {
+            res.insert(slang);
+        }
+        Ok(res)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScriptLanguageSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ScriptLanguageSetVisitor)
+    }
+}
+
+/// Same as [`script_char_to_slangs`](fn.script_char_to_slangs.html), but returns a set
+#[inline]
+pub fn script_char_to_slangs_set(script: UcdScript, ch: char) -> ScriptLanguageSet {
+    ScriptLanguageSet::from_slice(script_char_to_slangs(script, ch))
+}
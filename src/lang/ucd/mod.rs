@@ -1,6 +1,8 @@
 mod preprocess;
+mod properties;
 mod ranges;
 mod script;
 
+pub use properties::*;
 pub use ranges::*;
 pub use script::*;
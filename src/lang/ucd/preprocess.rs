@@ -6,7 +6,10 @@ const fn char_ranges_count() -> usize {
     let mut i = 0;
     let mut cnt = 0;
     while i < BY_NAME.len() {
-        cnt += BY_NAME[i].1.len();
+        let (script, ranges) = BY_NAME[i];
+        if script.enabled() {
+            cnt += ranges.len();
+        }
         i += 1;
     }
 
@@ -47,6 +50,11 @@ const fn char_ranges_array_sorted() -> [RangeScript; LEN] {
     let mut i = 0;
     while i < BY_NAME.len() {
         let (script, ranges) = BY_NAME[i];
+        // chars of the scripts excluded by cargo features are `Common`
+        if !script.enabled() {
+            i += 1;
+            continue;
+        }
         // foreach charset
         let mut j = 0;
         while j < ranges.len() {
@@ -323,14 +331,17 @@ mod tests {
         assert_eq!(input.binary_search_by_char('j'), UcdScript::Armenian);
         assert_eq!(input.binary_search_by_char('x'), UcdScript::Common);
 
-        assert_eq!(
-            CHAR_RANGES_SORTED.binary_search_by_char('Ύ'),
-            UcdScript::Greek
-        );
-        assert_eq!(
-            CHAR_RANGES_SORTED.binary_search_by_char('Σ'),
-            UcdScript::Greek
-        );
+        #[cfg(feature = "script_greek")]
+        {
+            assert_eq!(
+                CHAR_RANGES_SORTED.binary_search_by_char('Ύ'),
+                UcdScript::Greek
+            );
+            assert_eq!(
+                CHAR_RANGES_SORTED.binary_search_by_char('Σ'),
+                UcdScript::Greek
+            );
+        }
         assert_eq!(
             CHAR_RANGES_SORTED.binary_search_by_char(char::MAX),
             UcdScript::Common
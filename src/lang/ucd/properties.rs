@@ -0,0 +1,273 @@
+use super::UcdScript;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptDirection {
+    LeftToRight,
+    RightToLeft,
+    /// `UcdScript::Common` and `UcdScript::Inherited`
+    Neutral,
+}
+
+/// Writing system type. Featural (Hangul) and semi-syllabic scripts (Bopomofo) are alphabets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptType {
+    Alphabet,
+    /// Consonants only
+    Abjad,
+    /// Consonants with an inherent vowel
+    Abugida,
+    Syllabary,
+    Logographic,
+}
+
+impl UcdScript {
+    /// Horizontal direction (vertical scripts, like Mongolian, are left-to-right).
+    pub const fn direction(self) -> ScriptDirection {
+        use UcdScript::*;
+        match self {
+            Common | Inherited => ScriptDirection::Neutral,
+            Adlam
+            | Arabic
+            | Avestan
+            | Chorasmian
+            | Cypriot
+            | Elymaic
+            | Garay
+            | HanifiRohingya
+            | Hatran
+            | Hebrew
+            | ImperialAramaic
+            | InscriptionalPahlavi
+            | InscriptionalParthian
+            | Kharoshthi
+            | Lydian
+            | Mandaic
+            | Manichaean
+            | MendeKikakui
+            | MeroiticCursive
+            | MeroiticHieroglyphs
+            | Nabataean
+            | Nko
+            | OldHungarian
+            | OldNorthArabian
+            | OldSogdian
+            | OldSouthArabian
+            | OldTurkic
+            | OldUyghur
+            | Palmyrene
+            | Phoenician
+            | PsalterPahlavi
+            | Samaritan
+            | Sidetic
+            | Sogdian
+            | Syriac
+            | Thaana
+            | Yezidi => ScriptDirection::RightToLeft,
+            _ => ScriptDirection::LeftToRight,
+        }
+    }
+
+    /// Has upper and lower case letters.
+    pub const fn is_cased(self) -> bool {
+        use UcdScript::*;
+        matches!(
+            self,
+            Adlam
+                | Armenian
+                | Cherokee
+                | Coptic
+                | Cyrillic
+                | Deseret
+                | Garay
+                | Georgian
+                | Glagolitic
+                | Greek
+                | Latin
+                | Medefaidrin
+                | OldHungarian
+                | Osage
+                | Vithkuqi
+                | WarangCiti
+        )
+    }
+
+    /// Words are (traditionally) separated by spaces.
+    ///
+    /// Scripts which use word dividers (like Runic) are considered as using spaces.
+    pub const fn uses_word_spaces(self) -> bool {
+        use UcdScript::*;
+        !matches!(
+            self,
+            AnatolianHieroglyphs
+                | Balinese
+                | Bhaiksuki
+                | Brahmi
+                | Buginese
+                | Cuneiform
+                | Cypriot
+                | CyproMinoan
+                | EgyptianHieroglyphs
+                | Han
+                | Hiragana
+                | Javanese
+                | Katakana
+                | Kawi
+                | Kharoshthi
+                | KhitanSmallScript
+                | Khmer
+                | Lao
+                | Lepcha
+                | LinearA
+                | LinearB
+                | Makasar
+                | Myanmar
+                | NewTaiLue
+                | Nushu
+                | Siddham
+                | TaiLe
+                | TaiTham
+                | TaiViet
+                | Tangut
+                | Thai
+                | Tibetan
+                | Yi
+        )
+    }
+
+    /// No longer in common use (some are used liturgically or revived).
+    pub const fn is_historic(self) -> bool {
+        use UcdScript::*;
+        matches!(
+            self,
+            Ahom | AnatolianHieroglyphs
+                | Avestan
+                | Bhaiksuki
+                | Brahmi
+                | Carian
+                | CaucasianAlbanian
+                | Chorasmian
+                | Coptic
+                | Cuneiform
+                | Cypriot
+                | CyproMinoan
+                | DivesAkuru
+                | Dogra
+                | EgyptianHieroglyphs
+                | Elbasan
+                | Elymaic
+                | Glagolitic
+                | Gothic
+                | Grantha
+                | Hatran
+                | ImperialAramaic
+                | InscriptionalPahlavi
+                | InscriptionalParthian
+                | Kaithi
+                | Kawi
+                | Kharoshthi
+                | KhitanSmallScript
+                | Khojki
+                | Khudawadi
+                | LinearA
+                | LinearB
+                | Lycian
+                | Lydian
+                | Mahajani
+                | Makasar
+                | Manichaean
+                | Marchen
+                | MeroiticCursive
+                | MeroiticHieroglyphs
+                | Modi
+                | Multani
+                | Nabataean
+                | Nandinagari
+                | Nushu
+                | Ogham
+                | OldHungarian
+                | OldItalic
+                | OldNorthArabian
+                | OldPermic
+                | OldPersian
+                | OldSogdian
+                | OldSouthArabian
+                | OldTurkic
+                | OldUyghur
+                | Palmyrene
+                | PhagsPa
+                | Phoenician
+                | PsalterPahlavi
+                | Rejang
+                | Runic
+                | Sharada
+                | Siddham
+                | Sidetic
+                | Sogdian
+                | Soyombo
+                | Tagalog
+                | Takri
+                | Tangut
+                | Tirhuta
+                | Todhri
+                | TuluTigalari
+                | Ugaritic
+                | Vithkuqi
+                | ZanabazarSquare
+                | Deseret
+        )
+    }
+
+    /// `None` for `UcdScript::Common`, `UcdScript::Inherited` and `UcdScript::SignWriting`.
+    pub const fn script_type(self) -> Option<ScriptType> {
+        use UcdScript::*;
+        let script_type = match self {
+            Common | Inherited | SignWriting => return None,
+
+            Adlam | Armenian | Avestan | BassaVah | BeriaErfe | Bopomofo | Braille | Carian
+            | CaucasianAlbanian | Coptic | Cyrillic | Deseret | Duployan | Elbasan | Garay
+            | Georgian | Glagolitic | Gothic | Greek | Hangul | HanifiRohingya | KayahLi
+            | Latin | Lisu | Lycian | Lydian | Mandaic | Medefaidrin | Mongolian | Mro
+            | NagMundari | Nko | NyiakengPuachueHmong | Ogham | OlChiki | OldHungarian
+            | OldItalic | OldPermic | OldTurkic | OlOnal | Osage | Osmanya | PauCinHau | Runic
+            | Shavian | Sidetic | Sunuwar | Tangsa | Tifinagh | Todhri | TolongSiki | Toto
+            | Vithkuqi | Wancho | Yezidi => ScriptType::Alphabet,
+            Arabic
+            | Chorasmian
+            | Elymaic
+            | Hatran
+            | Hebrew
+            | ImperialAramaic
+            | InscriptionalPahlavi
+            | InscriptionalParthian
+            | Manichaean
+            | Nabataean
+            | OldNorthArabian
+            | OldSogdian
+            | OldSouthArabian
+            | OldUyghur
+            | Palmyrene
+            | Phoenician
+            | PsalterPahlavi
+            | Samaritan
+            | Sogdian
+            | Syriac
+            | Ugaritic => ScriptType::Abjad,
+            Ahom | Balinese | Batak | Bengali | Bhaiksuki | Brahmi | Buginese | Buhid
+            | CanadianAboriginal | Chakma | Cham | Devanagari | DivesAkuru | Dogra | Ethiopic
+            | Grantha | Gujarati | GunjalaGondi | Gurmukhi | GurungKhema | Hanunoo | Javanese
+            | Kaithi | Kannada | Kawi | Kharoshthi | Khmer | Khojki | Khudawadi | KiratRai
+            | Lao | Lepcha | Limbu | Mahajani | Makasar | Malayalam | Marchen | MasaramGondi
+            | MeeteiMayek | MeroiticCursive | MeroiticHieroglyphs | Miao | Modi | Multani
+            | Myanmar | Nandinagari | Newa | NewTaiLue | Oriya | PahawhHmong | PhagsPa | Rejang
+            | Saurashtra | Sharada | Siddham | Sinhala | SoraSompeng | Soyombo | Sundanese
+            | SylotiNagri | Tagalog | Tagbanwa | TaiLe | TaiTham | TaiViet | TaiYo | Takri
+            | Tamil | Telugu | Thaana | Thai | Tibetan | Tirhuta | TuluTigalari | WarangCiti
+            | ZanabazarSquare => ScriptType::Abugida,
+            Bamum | Cherokee | Cypriot | CyproMinoan | Hiragana | Katakana | LinearA | LinearB
+            | MendeKikakui | Nushu | OldPersian | Vai | Yi => ScriptType::Syllabary,
+            AnatolianHieroglyphs | Cuneiform | EgyptianHieroglyphs | Han | KhitanSmallScript
+            | Tangut => ScriptType::Logographic,
+        };
+        Some(script_type)
+    }
+}
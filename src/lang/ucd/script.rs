@@ -1,5 +1,6 @@
 use super::preprocess::{BSearch, CHAR_RANGES_SORTED};
 use alphabet_detector_macros::Script;
+use strum::EnumCount;
 use strum_macros::{EnumCount as EnumCountDerive, EnumIter};
 
 /// Int representation is unstable and can be changed anytime.
@@ -365,6 +366,8 @@ pub enum UcdScript {
 impl_try_from!(UcdScript, u16, u16 i16 u32 i32 usize isize u64 i64 u128 i128);
 impl_serde!(UcdScript, "UcdScript");
 
+pub type UcdScriptArr<T> = [T; UcdScript::COUNT];
+
 impl UcdScript {
     pub fn find(ch: char) -> Self {
         CHAR_RANGES_SORTED.binary_search_by_char(ch)
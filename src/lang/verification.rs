@@ -0,0 +1,40 @@
+use super::{ScriptLanguage, ScriptLanguageArr};
+use debug_unsafe::slice::SliceGetter;
+use strum::IntoEnumIterator;
+
+/// How an alphabet (letters list) was verified, from the least to the most reliable.
+///
+/// Recorded in `alphabet_match!` by `#[verification(Level)]`,
+/// a doubtful level (`#[verification(Level?)]`) is flagged by
+/// [`ScriptLanguage::alphabet_verification_doubtful`](enum.ScriptLanguage.html#method.alphabet_verification_doubtful).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verification {
+    /// No info (generated by chatgpt)
+    Unverified,
+    /// Verified on the internet
+    Internet,
+    /// Also verified by a text frequency analysis (cli_alphabet_intersection)
+    FrequencyAnalysis,
+    /// Verified by a native-level speaker
+    NativeSpeaker,
+    /// Not listed, any letter of the script
+    WholeScript,
+}
+
+/// Counts of `ScriptLanguage`s, which alphabet is verified below `min`, are multiplied by `PERCENT`%.
+///
+/// `ScriptLanguage`s without a listed alphabet ([`Verification::WholeScript`]) are not changed,
+/// unless it's `min`.
+pub fn down_weight_unverified<const PERCENT: u32>(
+    mut langs_cnt: ScriptLanguageArr<u32>,
+    min: Verification,
+) -> ScriptLanguageArr<u32> {
+    assert!(PERCENT <= 100);
+    for slang in ScriptLanguage::iter() {
+        if slang.alphabet_verification() < min {
+            let cnt = langs_cnt.get_safe_unchecked_mut(slang as usize);
+            *cnt = *cnt * PERCENT / 100;
+        }
+    }
+    langs_cnt
+}
@@ -1,6 +1,6 @@
 //! # Natural language alphabet detection library
 //!
-//! ## Detects 429 alphabets of 347 languages in 174 scripts
+//! ## Detects 430 alphabets of 347 languages in 174 scripts
 //!
 //! One language can be written in multiple scripts, so it will be detected as a different [`ScriptLanguage`](enum.ScriptLanguage.html) (language + script).
 //!
@@ -31,21 +31,59 @@
 //!
 //! It will give you all [`Word`](struct.Word.html)s (`Vec<Word<Vec<char>>>`) of `text` and `Vec<(ScriptLanguage, u32)>` filtered with a less then 5% margin for an error.
 //!
+//! If you don't need words at all, just the `ScriptLanguage`s:
+//! ```rust
+//! use alphabet_detector::fulltext_counts_filter_with_margin_sorted;
+//!
+//! let text = "test text";
+//! let (all_langs, _) = fulltext_counts_filter_with_margin_sorted::<95>(text.char_indices());
+//! ```
+//!
 //! Instead of `Vec<char>` you can use [other types](words/trait.WordBuf.html#foreign-impls) of words.
+//!
+//! # Script features
+//! Every script (except `Common` and `Inherited`) has a cargo feature, like `script_latin` or `script_old_italic`,
+//! all of them are enabled by the default feature `all_scripts`.
+//! To include only selected scripts use `default-features = false, features = ["script_latin", "script_cyrillic"]`.
+//!
+//! Chars of excluded scripts are found as [`UcdScript::Common`](enum.UcdScript.html#variant.Common)
+//! and do not match any `ScriptLanguage`, so they separate words like punctuation.
+//! `ScriptLanguage` variants of excluded scripts are left out.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(feature = "files_read", feature(string_into_chars))]
+// `sort_by` comparators are kept as written (lint added in newer clippy)
+#![allow(clippy::unnecessary_sort_by)]
 
+mod accumulator;
+mod aggregate;
+mod budget;
 pub mod ch_norm;
+mod explain;
 mod filter;
 mod fulltext;
 mod lang;
+mod no_diacritics;
+#[cfg(feature = "rayon")]
+mod parallel;
 #[cfg(feature = "files_read")]
 pub mod reader;
+mod registry;
+pub mod translit;
 pub mod words;
 
-pub use ch_norm::{CharData, CharNormalizingIterator};
+pub use accumulator::FulltextAccumulator;
+pub use aggregate::*;
+pub use budget::*;
+pub use ch_norm::{CharData, CharNormalizingIterator, NormalizationAnomaly, NormalizationOptions};
+pub use explain::*;
 pub use filter::*;
 pub use fulltext::*;
 pub use lang::*;
-pub use words::{Word, WordIterator};
+pub use no_diacritics::diacritic_base;
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use registry::*;
+pub use translit::{TranslitError, Transliterator};
+pub use words::{StandardUnicode, Word, WordIterator};
@@ -0,0 +1,333 @@
+use crate::{
+    ch_norm, slangs_count_max, words::WordBuf, LetterTier, Script, ScriptLanguage,
+    ScriptLanguageArr,
+};
+use ::std::sync::LazyLock;
+use ahash::AHashMap;
+use debug_unsafe::slice::SliceGetter;
+use strum::IntoEnumIterator;
+use unicode_normalization::char::decompose_canonical;
+
+/// Letters with marks, which are not decomposable (strokes, dotless i)
+const NOT_DECOMPOSABLE: &[(char, char)] = &[
+    ('ƀ', 'b'),
+    ('đ', 'd'),
+    ('ǥ', 'g'),
+    ('ħ', 'h'),
+    ('ı', 'i'),
+    ('ɨ', 'i'),
+    ('ł', 'l'),
+    ('ø', 'o'),
+    ('ŧ', 't'),
+    ('ʉ', 'u'),
+    ('ƶ', 'z'),
+];
+
+/// Base ASCII letter of a lowercase Latin letter with diacritics: 'ă' -> 'a', 'ł' -> 'l'.
+///
+/// `None` for ASCII letters, and letters without a base ASCII letter (like 'æ' or 'ß').
+///
+/// ```
+/// use alphabet_detector::diacritic_base;
+///
+/// assert_eq!(diacritic_base('ệ'), Some('e'));
+/// assert_eq!(diacritic_base('ı'), Some('i'));
+/// assert_eq!(diacritic_base('e'), None);
+/// assert_eq!(diacritic_base('ß'), None);
+/// ```
+pub fn diacritic_base(ch: char) -> Option<char> {
+    if ch.is_ascii() {
+        return None;
+    }
+    if let Some(&(_, base)) = NOT_DECOMPOSABLE.iter().find(|(c, _)| *c == ch) {
+        return Some(base);
+    }
+    let first = match ch_norm::decompose_custom(ch) {
+        Some(chars) => chars[0],
+        None => {
+            let mut first = None;
+            decompose_canonical(ch, |c| {
+                first.get_or_insert(c);
+            });
+            first.unwrap_or(ch)
+        }
+    };
+    Some(first).filter(char::is_ascii_lowercase)
+}
+
+/// Index of an ASCII lowercase letter
+#[inline(always)]
+const fn ascii_index(ch: char) -> usize {
+    (ch as u8 - b'a') as usize
+}
+
+/// Base-letter projections of the Latin alphabets: bits (by `ascii_index`) of the base letters
+/// of the core letters with diacritics ("ą", "ć" -> 'a', 'c').
+/// These letters of ASCII-only text could have been stripped.
+static PROJECTIONS: LazyLock<Vec<(ScriptLanguage, u32)>> = LazyLock::new(|| {
+    ScriptLanguage::iter()
+        .filter(|&slang| Script::from(slang) == Script::Latin)
+        .filter_map(|slang| {
+            let bases = slang
+                .alphabet()?
+                .iter()
+                .filter(|&&ch| slang.letter_tier(ch) == Some(LetterTier::Core))
+                .filter_map(|&ch| diacritic_base(ch))
+                .fold(0_u32, |acc, base| acc | 1 << ascii_index(base));
+            (bases != 0).then_some((slang, bases))
+        })
+        .collect()
+});
+
+/// Frequent words with diacritics of the languages, which are often typed without them.
+/// Their stripped projections ("dziękuję" -> "dziekuje") are looked up in ASCII-only text,
+/// which fits any alphabet by its projection, to tell it from a text without diacritics.
+///
+/// Stripped words, which are common English words ("în" -> "in", "mě" -> "me"), are left out.
+#[cfg(feature = "script_latin")]
+const WORDS: &[(ScriptLanguage, &[&str])] = &[
+    (
+        ScriptLanguage::Vietnamese,
+        &[
+            "tôi", "không", "của", "được", "người", "những", "có", "là", "và", "với", "này", "một",
+            "các", "cũng", "tiếng", "việt", "bạn", "chào", "yêu", "đã", "sẽ", "nhiều", "học",
+            "nói", "đi", "về", "thì", "viên", "cảm",
+        ],
+    ),
+    (
+        ScriptLanguage::Romanian,
+        &[
+            "și",
+            "să",
+            "că",
+            "astăzi",
+            "mulțumesc",
+            "bună",
+            "față",
+            "după",
+            "până",
+            "când",
+            "această",
+            "acești",
+            "fără",
+            "încă",
+            "între",
+            "țară",
+            "știu",
+            "vă",
+            "mă",
+            "făcut",
+            "sunteți",
+            "ești",
+        ],
+    ),
+    (
+        ScriptLanguage::Polish,
+        &[
+            "dziękuję",
+            "się",
+            "że",
+            "już",
+            "jeśli",
+            "także",
+            "może",
+            "będzie",
+            "są",
+            "też",
+            "również",
+            "więc",
+            "dzień",
+            "proszę",
+            "cześć",
+            "można",
+            "mój",
+            "życie",
+            "ważne",
+            "cię",
+        ],
+    ),
+    (
+        ScriptLanguage::Turkish,
+        &[
+            "için",
+            "çok",
+            "değil",
+            "günaydın",
+            "nasılsın",
+            "bugün",
+            "teşekkür",
+            "şey",
+            "çünkü",
+            "böyle",
+            "şimdi",
+            "güzel",
+            "büyük",
+            "küçük",
+            "hayır",
+            "ağabey",
+            "görüşürüz",
+        ],
+    ),
+    (
+        ScriptLanguage::Czech,
+        &[
+            "děkuji",
+            "že",
+            "máš",
+            "být",
+            "není",
+            "což",
+            "příliš",
+            "děti",
+            "ještě",
+            "už",
+            "když",
+            "protože",
+            "všechno",
+            "čas",
+            "prosím",
+            "dobrý",
+        ],
+    ),
+];
+#[cfg(not(feature = "script_latin"))]
+const WORDS: &[(ScriptLanguage, &[&str])] = &[];
+
+/// Longest stripped word, longer words are not looked up
+const WORD_LEN_MAX: usize = 12;
+
+/// Stripped word -> indices of `WORDS`
+static STRIPPED_WORDS: LazyLock<AHashMap<String, Vec<usize>>> = LazyLock::new(|| {
+    let mut res: AHashMap<String, Vec<usize>> = AHashMap::new();
+    for (i, &(_, words)) in WORDS.iter().enumerate() {
+        for word in words {
+            let stripped: String = word
+                .chars()
+                .map(|ch| diacritic_base(ch).unwrap_or(ch))
+                .collect();
+            debug_assert!(
+                stripped != *word
+                    && stripped.len() <= WORD_LEN_MAX
+                    && stripped.bytes().all(|b| b.is_ascii_lowercase()),
+                "{word}"
+            );
+            res.entry(stripped).or_default().push(i);
+        }
+    }
+    res
+});
+
+/// Word buf collecting the ASCII-only word, its letters counts,
+/// and whether any diacritic letter was found.
+/// Merged, it also collects letters of the stripped words found, per `WORDS` language.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DiacriticsStats {
+    word: [u8; WORD_LEN_MAX],
+    /// `WORD_LEN_MAX` + 1, if the word is not looked up
+    word_len: usize,
+    /// Counts of the ASCII letters, by `ascii_index`
+    letters: [u32; 26],
+    diacritics: bool,
+    not_empty: bool,
+    matched: [u32; WORDS.len()],
+}
+
+impl WordBuf for DiacriticsStats {
+    #[inline]
+    fn push(&mut self, ch: char) {
+        if ch.is_ascii_lowercase() {
+            if let Some(b) = self.word.get_mut(self.word_len) {
+                *b = ch as u8;
+            }
+            *self.letters.get_safe_unchecked_mut(ascii_index(ch)) += 1;
+        } else if diacritic_base(ch).is_some() {
+            self.diacritics = true;
+        }
+        self.word_len = if ch.is_ascii_lowercase() {
+            (self.word_len + 1).min(WORD_LEN_MAX + 1)
+        } else {
+            WORD_LEN_MAX + 1
+        };
+        self.not_empty = true;
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        !self.not_empty
+    }
+}
+
+impl DiacriticsStats {
+    /// Adds the word `other`, looking it up in the stripped words
+    #[inline]
+    pub(crate) fn merge(&mut self, other: &Self) {
+        if let Some(langs) = other
+            .word
+            .get(..other.word_len)
+            .and_then(|w| ::core::str::from_utf8(w).ok())
+            .and_then(|w| STRIPPED_WORDS.get(w))
+        {
+            for &i in langs {
+                *self.matched.get_safe_unchecked_mut(i) += other.word_len as u32;
+            }
+        }
+        for (cnt, other_cnt) in self.matched.iter_mut().zip(other.matched) {
+            *cnt += other_cnt;
+        }
+        for (cnt, other_cnt) in self.letters.iter_mut().zip(other.letters) {
+            *cnt += other_cnt;
+        }
+        self.diacritics |= other.diacritics;
+        self.not_empty |= other.not_empty;
+    }
+
+    /// ASCII-only text is unusual, if the top of `langs_cnt` (a tie or a single language)
+    /// has languages, which need diacritics, and their stripped words are found in the text.
+    /// Then the top languages are scored by their alphabet projections: the letters,
+    /// which could have been stripped, and the letters of the found words are added to their counts.
+    pub(crate) fn apply(&self, langs_cnt: &mut ScriptLanguageArr<u32>) -> bool {
+        if self.diacritics || self.letters.iter().all(|&cnt| cnt == 0) {
+            return false;
+        }
+        let cnt_max = slangs_count_max(langs_cnt);
+        let matched = |slang: ScriptLanguage| -> u32 {
+            WORDS
+                .iter()
+                .zip(&self.matched)
+                .filter(|(&(sl, _), _)| sl == slang)
+                .map(|(_, &m)| m)
+                .sum()
+        };
+        let is_top =
+            |slang: ScriptLanguage| *langs_cnt.get_safe_unchecked(slang as usize) == cnt_max;
+        if !WORDS
+            .iter()
+            .any(|&(slang, _)| is_top(slang) && matched(slang) > 0)
+        {
+            return false;
+        }
+
+        let letters_cnt: u32 = self.letters.iter().sum();
+        let scores: Vec<_> = PROJECTIONS
+            .iter()
+            .filter(|&&(slang, _)| is_top(slang))
+            .map(|&(slang, bases)| {
+                let stripped: u32 = self
+                    .letters
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| bases & (1 << i) != 0)
+                    .map(|(_, &cnt)| cnt)
+                    .sum();
+                // beyond the projection's share of the 26 letters,
+                // so alphabets with diacritics on most letters are not favored
+                let share = letters_cnt * bases.count_ones();
+                let projected = (stripped * 26).saturating_sub(share) / 26;
+                (slang, projected + matched(slang))
+            })
+            .collect();
+        for (slang, score) in scores {
+            *langs_cnt.get_safe_unchecked_mut(slang as usize) += score;
+        }
+        true
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    words::{self, next_word_boundary, WordBuf},
+    FulltextAccumulator, ScriptLanguageArr, Word,
+};
+use rayon::prelude::*;
+
+/// Approximate size of a text piece processed by one thread.
+const PIECE_LEN: usize = 1 << 16;
+
+/// Splits `text` into pieces of about `piece_len` bytes at word boundaries
+/// (each piece, except the first one, starts with a whitespace).
+/// Each piece can be processed separately, with the same result as a whole `text`.
+///
+/// Returns the byte index of each piece start.
+fn split_at_word_boundaries(text: &str, piece_len: usize) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut start = 0;
+    while start + piece_len < text.len() {
+        match next_word_boundary(text, start + piece_len) {
+            Some(boundary) => {
+                starts.push(boundary);
+                start = boundary;
+            }
+            None => break,
+        }
+    }
+    starts
+}
+
+#[inline]
+fn pieces(text: &str) -> impl IndexedParallelIterator<Item = (usize, &str)> {
+    let starts = split_at_word_boundaries(text, PIECE_LEN);
+    let ends: Vec<usize> = starts.iter().skip(1).copied().chain([text.len()]).collect();
+    starts
+        .into_par_iter()
+        .zip(ends)
+        .map(move |(start, end)| (start, &text[start..end]))
+}
+
+/// Same as [`fulltext`](fn.fulltext.html), but processed in parallel
+/// (`text` is split at word boundaries).
+///
+/// The result is identical to the sequential one, including words order and ranges.
+pub fn par_fulltext<B: WordBuf + Send>(text: &str) -> (Vec<Word<B>>, ScriptLanguageArr<u32>) {
+    let results: Vec<(Vec<Word<B>>, FulltextAccumulator)> = pieces(text)
+        .map(|(offset, piece)| {
+            let mut words = Vec::new();
+            let mut acc = FulltextAccumulator::new();
+            let char_indices = piece.char_indices().map(|(i, ch)| (i + offset, ch));
+            for wld in words::from_ch_ind(char_indices) {
+                acc.add_word(&wld);
+                words.push(wld);
+            }
+            (words, acc)
+        })
+        .collect();
+
+    let mut words = Vec::with_capacity(results.iter().map(|(w, _)| w.len()).sum());
+    let mut acc = FulltextAccumulator::new();
+    for (piece_words, piece_acc) in results {
+        words.extend(piece_words);
+        acc += piece_acc;
+    }
+
+    (words, acc.into_langs_count())
+}
+
+/// Same as [`fulltext_counts`](fn.fulltext_counts.html), but processed in parallel
+/// (`text` is split at word boundaries).
+pub fn par_fulltext_counts(text: &str) -> ScriptLanguageArr<u32> {
+    pieces(text)
+        .map(|(offset, piece)| {
+            let mut acc = FulltextAccumulator::new();
+            acc.feed(piece.char_indices().map(|(i, ch)| (i + offset, ch)));
+            acc
+        })
+        .reduce(FulltextAccumulator::new, |a, b| a + b)
+        .into_langs_count()
+}
@@ -0,0 +1,402 @@
+use crate::{
+    ch_norm, slang_arr_default, slangs_count_max,
+    words::{CustomAlphabets, WordBuf},
+    ScriptLanguage, ScriptLanguageArr, UcdScript, Word, WordIterator,
+};
+use ::std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    iter,
+    path::Path,
+};
+use ahash::{AHashMap, AHashSet};
+
+/// Id of an alphabet in an [`AlphabetRegistry`](struct.AlphabetRegistry.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CustomLanguage(u16);
+
+impl CustomLanguage {
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Built-in `ScriptLanguage` or a runtime-registered one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnyLanguage {
+    Builtin(ScriptLanguage),
+    Custom(CustomLanguage),
+}
+
+impl From<ScriptLanguage> for AnyLanguage {
+    #[inline]
+    fn from(slang: ScriptLanguage) -> Self {
+        Self::Builtin(slang)
+    }
+}
+
+impl From<CustomLanguage> for AnyLanguage {
+    #[inline]
+    fn from(clang: CustomLanguage) -> Self {
+        Self::Custom(clang)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomAlphabet {
+    pub name: String,
+    pub script: UcdScript,
+    /// lowercase
+    chars: AHashSet<char>,
+}
+
+impl CustomAlphabet {
+    #[inline]
+    pub fn contains(&self, ch: char) -> bool {
+        self.chars.contains(&ch)
+    }
+}
+
+#[derive(Debug)]
+pub enum AlphabetRegistryError {
+    Io(io::Error),
+    /// Line number (from 1), which is not `name<TAB>script<TAB>chars`
+    InvalidLine(usize),
+    /// Line number (from 1) and an unknown ISO 15924 code
+    UnknownScript(usize, String),
+    /// More alphabets than `u16::MAX`
+    TooMany,
+}
+
+impl fmt::Display for AlphabetRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::InvalidLine(line_num) => {
+                write!(f, "line {line_num}: expected `name<TAB>script<TAB>chars`")
+            }
+            Self::UnknownScript(line_num, script) => {
+                write!(f, "line {line_num}: unknown script {script:?}")
+            }
+            Self::TooMany => write!(f, "more than {} alphabets", u16::MAX),
+        }
+    }
+}
+
+impl ::std::error::Error for AlphabetRegistryError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AlphabetRegistryError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Alphabets of languages not included in the crate, registered at runtime.
+///
+/// They are detected alongside the built-in `ScriptLanguage`s
+/// (see [`AnyLanguage`](enum.AnyLanguage.html)), by the letters used, same as built-in.
+#[derive(Clone, Debug, Default)]
+pub struct AlphabetRegistry {
+    alphabets: Vec<CustomAlphabet>,
+    /// lowercase char -> alphabets containing it
+    char_langs: AHashMap<char, Vec<CustomLanguage>>,
+}
+
+impl AlphabetRegistry {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `chars` are lowercased, chars of other scripts than `script`
+    /// (except `Common` and `Inherited`) are skipped.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        script: UcdScript,
+        chars: impl IntoIterator<Item = char>,
+    ) -> Result<CustomLanguage, AlphabetRegistryError> {
+        let id = u16::try_from(self.alphabets.len()).map_err(|_| AlphabetRegistryError::TooMany)?;
+        let clang = CustomLanguage(id);
+        let alphabet = CustomAlphabet {
+            name: name.into(),
+            script,
+            chars: chars
+                .into_iter()
+                .filter(|&ch| {
+                    let ch_script = UcdScript::find(ch);
+                    ch_script == script
+                        || ch_script == UcdScript::Common
+                        || ch_script == UcdScript::Inherited
+                })
+                .flat_map(char::to_lowercase)
+                .collect(),
+        };
+        for &ch in &alphabet.chars {
+            self.char_langs.entry(ch).or_default().push(clang);
+        }
+        self.alphabets.push(alphabet);
+        Ok(clang)
+    }
+
+    /// From a list of `(name, script, chars)`
+    pub fn from_list<'a>(
+        list: impl IntoIterator<Item = (&'a str, UcdScript, &'a str)>,
+    ) -> Result<Self, AlphabetRegistryError> {
+        let mut res = Self::new();
+        for (name, script, chars) in list {
+            res.add(name, script, chars.chars())?;
+        }
+        Ok(res)
+    }
+
+    /// Each line is `name<TAB>script<TAB>chars`, where script is an ISO 15924 code (like "Latn").
+    /// Empty lines and lines starting with `#` are skipped.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, AlphabetRegistryError> {
+        let mut res = Self::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line_num = i + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(3, '\t');
+            let (Some(name), Some(script), Some(chars)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(AlphabetRegistryError::InvalidLine(line_num));
+            };
+            let script = UcdScript::from_str(script.trim())
+                .ok_or_else(|| AlphabetRegistryError::UnknownScript(line_num, script.to_owned()))?;
+            res.add(
+                name.trim(),
+                script,
+                chars.chars().filter(|ch| !ch.is_whitespace()),
+            )?;
+        }
+        Ok(res)
+    }
+
+    /// See [`from_reader`](#method.from_reader) for the file format.
+    #[inline]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, AlphabetRegistryError> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.alphabets.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.alphabets.is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, clang: CustomLanguage) -> Option<&CustomAlphabet> {
+        self.alphabets.get(clang.index())
+    }
+
+    /// Custom alphabet by name
+    #[inline]
+    pub fn find(&self, name: &str) -> Option<CustomLanguage> {
+        self.alphabets
+            .iter()
+            .position(|a| a.name == name)
+            .map(|i| CustomLanguage(i as u16))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (CustomLanguage, &CustomAlphabet)> {
+        self.alphabets
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (CustomLanguage(i as u16), a))
+    }
+
+    /// Name of a custom alphabet, or a display name of a built-in `ScriptLanguage`
+    #[inline]
+    pub fn name(&self, lang: AnyLanguage) -> Option<&str> {
+        match lang {
+            AnyLanguage::Builtin(slang) => Some(slang.display_name()),
+            AnyLanguage::Custom(clang) => self.get(clang).map(|a| a.name.as_str()),
+        }
+    }
+
+    /// Same as [`words::from_ch_ind`](words/fn.from_ch_ind.html),
+    /// but also detects the custom alphabets: their letters are looked up with the built-in ones.
+    #[inline]
+    pub fn word_iter<B: WordBuf>(
+        &self,
+        char_indices: impl Iterator<Item = (usize, char)>,
+    ) -> WordIterator<impl Iterator<Item = ch_norm::CharData>, B, &Self> {
+        WordIterator::with_custom(ch_norm::from_ch_ind(char_indices), self)
+    }
+
+    /// Words with the counts of the custom alphabets, see [`word_iter`](#method.word_iter).
+    pub fn words<'a, B: WordBuf + 'a>(
+        &'a self,
+        char_indices: impl Iterator<Item = (usize, char)> + 'a,
+    ) -> impl Iterator<Item = AnyWord<B>> + 'a {
+        let mut word_iter = self.word_iter(char_indices);
+        iter::from_fn(move || {
+            let word = word_iter.next()?;
+            Some(AnyWord {
+                word,
+                custom_cnt: word_iter.custom_cnt().to_vec(),
+            })
+        })
+    }
+
+    /// Same as [`fulltext`](fn.fulltext.html), but also detects the custom alphabets.
+    pub fn fulltext<B: WordBuf>(
+        &self,
+        char_indices: impl Iterator<Item = (usize, char)>,
+    ) -> (Vec<AnyWord<B>>, AnyLangsCount) {
+        let mut langs_count = AnyLangsCount::new(self.len());
+        let words = self
+            .words(char_indices)
+            .inspect(|word| langs_count.add_word(word))
+            .collect();
+
+        (words, langs_count)
+    }
+
+    /// Same as [`fulltext_counts`](fn.fulltext_counts.html), but also detects the custom alphabets.
+    pub fn fulltext_counts(
+        &self,
+        char_indices: impl Iterator<Item = (usize, char)>,
+    ) -> AnyLangsCount {
+        let mut langs_count = AnyLangsCount::new(self.len());
+        let mut word_iter = self.word_iter::<bool>(char_indices);
+        while let Some(word) = word_iter.next() {
+            langs_count.add(&word.langs_cnt, word_iter.custom_cnt());
+        }
+        langs_count
+    }
+}
+
+impl CustomAlphabets for &AlphabetRegistry {
+    #[inline]
+    fn count(&self) -> usize {
+        self.alphabets.len()
+    }
+    #[inline]
+    fn char_langs(&self, ch: char) -> &[CustomLanguage] {
+        self.char_langs.get(&ch).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// [`Word`](words/struct.Word.html) with counts of the custom alphabets
+#[derive(Clone, Debug)]
+pub struct AnyWord<B: WordBuf> {
+    pub word: Word<B>,
+    /// Indexed by [`CustomLanguage::index`](struct.CustomLanguage.html#method.index)
+    pub custom_cnt: Vec<u32>,
+}
+
+impl<B: WordBuf> AnyWord<B> {
+    /// Non zero counts
+    #[inline]
+    pub fn langs(&self) -> impl Iterator<Item = (AnyLanguage, u32)> + '_ {
+        any_langs(&self.word.langs_cnt, &self.custom_cnt).filter(|&(_, cnt)| cnt > 0)
+    }
+}
+
+/// Counts of built-in `ScriptLanguage`s and custom alphabets
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnyLangsCount {
+    pub builtin: ScriptLanguageArr<u32>,
+    /// Indexed by [`CustomLanguage::index`](struct.CustomLanguage.html#method.index)
+    pub custom: Vec<u32>,
+}
+
+impl AnyLangsCount {
+    #[inline]
+    pub fn new(custom_len: usize) -> Self {
+        Self {
+            builtin: slang_arr_default(),
+            custom: vec![0; custom_len],
+        }
+    }
+
+    #[inline]
+    pub fn add_word<B: WordBuf>(&mut self, word: &AnyWord<B>) {
+        self.add(&word.word.langs_cnt, &word.custom_cnt);
+    }
+
+    #[inline]
+    fn add(&mut self, builtin: &ScriptLanguageArr<u32>, custom: &[u32]) {
+        for (cnt, word_cnt) in self.builtin.iter_mut().zip(builtin) {
+            *cnt += word_cnt;
+        }
+        for (cnt, word_cnt) in self.custom.iter_mut().zip(custom) {
+            *cnt += word_cnt;
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (AnyLanguage, u32)> + Clone + '_ {
+        any_langs(&self.builtin, &self.custom)
+    }
+
+    #[inline]
+    pub fn count_max(&self) -> u32 {
+        self.custom
+            .iter()
+            .fold(slangs_count_max(&self.builtin), |acc, &cnt| acc.max(cnt))
+    }
+
+    /// Only top languages are retained, see [`filter_max`](fn.filter_max.html).
+    #[inline]
+    pub fn filter_max(&self) -> (impl Iterator<Item = AnyLanguage> + Clone + '_, u32) {
+        let count_max = self.count_max();
+        (
+            self.iter()
+                .filter(move |&(_, cnt)| cnt == count_max)
+                .map(|(lang, _)| lang),
+            count_max,
+        )
+    }
+
+    /// See [`filter_with_margin_sorted`](fn.filter_with_margin_sorted.html).
+    pub fn filter_with_margin_sorted<const PERCENT: u32>(&self) -> (Vec<(AnyLanguage, u32)>, u32) {
+        assert!(PERCENT < 100);
+        let count_margin = self.count_max() * PERCENT / 100;
+        let mut res: Vec<_> = self.iter().filter(|&(_, cnt)| cnt > count_margin).collect();
+        res.sort_unstable_by_key(|v| ::core::cmp::Reverse(v.1));
+
+        (res, count_margin)
+    }
+}
+
+#[inline]
+fn any_langs<'a>(
+    builtin: &'a ScriptLanguageArr<u32>,
+    custom: &'a [u32],
+) -> impl Iterator<Item = (AnyLanguage, u32)> + Clone + 'a {
+    builtin
+        .iter()
+        .enumerate()
+        .map(|(i, &cnt)| {
+            let slang = unsafe { ScriptLanguage::transmute_from_usize(i) };
+            (AnyLanguage::Builtin(slang), cnt)
+        })
+        .chain(
+            custom
+                .iter()
+                .enumerate()
+                .map(|(i, &cnt)| (AnyLanguage::Custom(CustomLanguage(i as u16)), cnt)),
+        )
+}
@@ -0,0 +1,623 @@
+//! Table-driven transliteration between scripts of the same `Language`.
+//!
+//! Standard mappings are included:
+//! - Serbian Cyrillic ↔ Latin (Gaj's alphabet), reversible.
+//! - North Azerbaijani Latin ↔ Cyrillic, reversible.
+//! - Northern Uzbek Cyrillic ↔ Latin (1995), Cyrillic "э", "ц", "ь" are lost in Latin.
+//!   Context rules (word initial "е" is "ye") are not applied.
+//! - Kazakh Cyrillic ↔ Latin (2021), Cyrillic "ё", "й", "һ", "ц", "щ", "ъ", "ь", "э", "ю", "я"
+//!   are lost in Latin.
+//!
+//! Gurmukhi ↔ Shahmukhi (Punjabi) is not included yet ([`TranslitError::NotIncluded`]):
+//! Shahmukhi doesn't write short vowels, so it's not a letter to letter mapping.
+//!
+//! Latin digraphs are converted by the longest match, so two letters, which only look like
+//! a digraph ("in-jekcija"), need an entry in the table's exception list.
+//! Words not in the list are converted as a digraph.
+//!
+//! ```
+//! # #[cfg(all(feature = "script_latin", feature = "script_cyrillic"))] {
+//! use alphabet_detector::{translit::Transliterator, Script, ScriptLanguage};
+//!
+//! let tr = Transliterator::from_slang(ScriptLanguage::Serbian, Script::Latin).unwrap();
+//! assert_eq!(tr.translit("Љубав"), "Ljubav");
+//! assert_eq!(tr.reverse().translit("Ljubav"), "Љубав");
+//! assert_eq!(
+//!     alphabet_detector::translit::translit(ScriptLanguage::SerbianLatin, Script::Cyrillic, "Ljubav"),
+//!     Ok("Љубав".to_owned())
+//! );
+//! # }
+//! ```
+
+use crate::{Language, Script, ScriptLanguage};
+
+struct TranslitTable {
+    lang: Language,
+    scripts: (Script, Script),
+    /// (`scripts.0`, `scripts.1`), both cases
+    pairs: &'static [(&'static str, &'static str)],
+    /// only from `scripts.0` to `scripts.1`, letters without their own counterpart
+    pairs_forward: &'static [(&'static str, &'static str)],
+    /// only from `scripts.1` to `scripts.0`, alternative spellings (like all caps digraphs)
+    pairs_reverse: &'static [(&'static str, &'static str)],
+    /// Lowercase `scripts.1` stems, where a digraph is two letters, split by `|`:
+    /// "in|jekc" (injekcija)
+    digraph_exceptions: &'static [&'static str],
+}
+
+const SERBIAN: TranslitTable = TranslitTable {
+    lang: Language::Serbian,
+    scripts: (Script::Cyrillic, Script::Latin),
+    pairs: &[
+        ("А", "A"),
+        ("а", "a"),
+        ("Б", "B"),
+        ("б", "b"),
+        ("В", "V"),
+        ("в", "v"),
+        ("Г", "G"),
+        ("г", "g"),
+        ("Д", "D"),
+        ("д", "d"),
+        ("Ђ", "Đ"),
+        ("ђ", "đ"),
+        ("Е", "E"),
+        ("е", "e"),
+        ("Ж", "Ž"),
+        ("ж", "ž"),
+        ("З", "Z"),
+        ("з", "z"),
+        ("И", "I"),
+        ("и", "i"),
+        ("Ј", "J"),
+        ("ј", "j"),
+        ("К", "K"),
+        ("к", "k"),
+        ("Л", "L"),
+        ("л", "l"),
+        ("Љ", "Lj"),
+        ("љ", "lj"),
+        ("М", "M"),
+        ("м", "m"),
+        ("Н", "N"),
+        ("н", "n"),
+        ("Њ", "Nj"),
+        ("њ", "nj"),
+        ("О", "O"),
+        ("о", "o"),
+        ("П", "P"),
+        ("п", "p"),
+        ("Р", "R"),
+        ("р", "r"),
+        ("С", "S"),
+        ("с", "s"),
+        ("Т", "T"),
+        ("т", "t"),
+        ("Ћ", "Ć"),
+        ("ћ", "ć"),
+        ("У", "U"),
+        ("у", "u"),
+        ("Ф", "F"),
+        ("ф", "f"),
+        ("Х", "H"),
+        ("х", "h"),
+        ("Ц", "C"),
+        ("ц", "c"),
+        ("Ч", "Č"),
+        ("ч", "č"),
+        ("Џ", "Dž"),
+        ("џ", "dž"),
+        ("Ш", "Š"),
+        ("ш", "š"),
+    ],
+    pairs_forward: &[],
+    pairs_reverse: &[("Љ", "LJ"), ("Њ", "NJ"), ("Џ", "DŽ")],
+    digraph_exceptions: &[
+        "in|jekc",  // injekcija
+        "kon|jug",  // konjugacija
+        "kon|junk", // konjunkcija, konjunktura
+        "tan|jug",  // Tanjug
+        "van|jez",  // vanjezički
+        "nad|živ",  // nadživeti
+        "od|žal",   // odžaliti
+        "pod|žanr", // podžanr
+    ],
+};
+
+const AZERBAIJANI_NORTH: TranslitTable = TranslitTable {
+    lang: Language::AzerbaijaniNorth,
+    scripts: (Script::Latin, Script::Cyrillic),
+    pairs: &[
+        ("A", "А"),
+        ("a", "а"),
+        ("B", "Б"),
+        ("b", "б"),
+        ("C", "Ҹ"),
+        ("c", "ҹ"),
+        ("Ç", "Ч"),
+        ("ç", "ч"),
+        ("D", "Д"),
+        ("d", "д"),
+        ("E", "Е"),
+        ("e", "е"),
+        ("Ə", "Ә"),
+        ("ə", "ә"),
+        ("F", "Ф"),
+        ("f", "ф"),
+        ("G", "Ҝ"),
+        ("g", "ҝ"),
+        ("Ğ", "Ғ"),
+        ("ğ", "ғ"),
+        ("H", "Һ"),
+        ("h", "һ"),
+        ("X", "Х"),
+        ("x", "х"),
+        ("I", "Ы"),
+        ("ı", "ы"),
+        ("İ", "И"),
+        ("i", "и"),
+        ("J", "Ж"),
+        ("j", "ж"),
+        ("K", "К"),
+        ("k", "к"),
+        ("Q", "Г"),
+        ("q", "г"),
+        ("L", "Л"),
+        ("l", "л"),
+        ("M", "М"),
+        ("m", "м"),
+        ("N", "Н"),
+        ("n", "н"),
+        ("O", "О"),
+        ("o", "о"),
+        ("Ö", "Ө"),
+        ("ö", "ө"),
+        ("P", "П"),
+        ("p", "п"),
+        ("R", "Р"),
+        ("r", "р"),
+        ("S", "С"),
+        ("s", "с"),
+        ("Ş", "Ш"),
+        ("ş", "ш"),
+        ("T", "Т"),
+        ("t", "т"),
+        ("U", "У"),
+        ("u", "у"),
+        ("Ü", "Ү"),
+        ("ü", "ү"),
+        ("V", "В"),
+        ("v", "в"),
+        ("Y", "Ј"),
+        ("y", "ј"),
+        ("Z", "З"),
+        ("z", "з"),
+    ],
+    pairs_forward: &[],
+    pairs_reverse: &[],
+    digraph_exceptions: &[],
+};
+
+const UZBEK_NORTHERN: TranslitTable = TranslitTable {
+    lang: Language::UzbekNorthern,
+    scripts: (Script::Cyrillic, Script::Latin),
+    pairs: &[
+        ("А", "A"),
+        ("а", "a"),
+        ("Б", "B"),
+        ("б", "b"),
+        ("В", "V"),
+        ("в", "v"),
+        ("Г", "G"),
+        ("г", "g"),
+        ("Д", "D"),
+        ("д", "d"),
+        ("Е", "E"),
+        ("е", "e"),
+        ("Ё", "Yo"),
+        ("ё", "yo"),
+        ("Ж", "J"),
+        ("ж", "j"),
+        ("З", "Z"),
+        ("з", "z"),
+        ("И", "I"),
+        ("и", "i"),
+        ("Й", "Y"),
+        ("й", "y"),
+        ("К", "K"),
+        ("к", "k"),
+        ("Л", "L"),
+        ("л", "l"),
+        ("М", "M"),
+        ("м", "m"),
+        ("Н", "N"),
+        ("н", "n"),
+        ("О", "O"),
+        ("о", "o"),
+        ("П", "P"),
+        ("п", "p"),
+        ("Р", "R"),
+        ("р", "r"),
+        ("С", "S"),
+        ("с", "s"),
+        ("Т", "T"),
+        ("т", "t"),
+        ("У", "U"),
+        ("у", "u"),
+        ("Ф", "F"),
+        ("ф", "f"),
+        ("Х", "X"),
+        ("х", "x"),
+        ("Ч", "Ch"),
+        ("ч", "ch"),
+        ("Ш", "Sh"),
+        ("ш", "sh"),
+        ("ъ", "ʼ"),
+        ("Ю", "Yu"),
+        ("ю", "yu"),
+        ("Я", "Ya"),
+        ("я", "ya"),
+        ("Ў", "Oʻ"),
+        ("ў", "oʻ"),
+        ("Қ", "Q"),
+        ("қ", "q"),
+        ("Ғ", "Gʻ"),
+        ("ғ", "gʻ"),
+        ("Ҳ", "H"),
+        ("ҳ", "h"),
+        // not a digraph
+        ("Сҳ", "Sʼh"),
+        ("сҳ", "sʼh"),
+    ],
+    pairs_forward: &[("Э", "E"), ("э", "e"), ("Ц", "Ts"), ("ц", "ts"), ("ь", "")],
+    pairs_reverse: &[
+        ("Ё", "YO"),
+        ("Ч", "CH"),
+        ("Ш", "SH"),
+        ("Ю", "YU"),
+        ("Я", "YA"),
+        // apostrophe spellings
+        ("Ў", "O'"),
+        ("ў", "o'"),
+        ("Ў", "O‘"),
+        ("ў", "o‘"),
+        ("Ғ", "G'"),
+        ("ғ", "g'"),
+        ("Ғ", "G‘"),
+        ("ғ", "g‘"),
+        ("Сҳ", "S'h"),
+        ("сҳ", "s'h"),
+    ],
+    digraph_exceptions: &[
+        "y|oʻ", // yoʻl
+        "y|o'", "y|o‘",
+    ],
+};
+
+const KAZAKH: TranslitTable = TranslitTable {
+    lang: Language::Kazakh,
+    scripts: (Script::Cyrillic, Script::Latin),
+    pairs: &[
+        ("А", "A"),
+        ("а", "a"),
+        ("Ә", "Ä"),
+        ("ә", "ä"),
+        ("Б", "B"),
+        ("б", "b"),
+        ("В", "V"),
+        ("в", "v"),
+        ("Г", "G"),
+        ("г", "g"),
+        ("Ғ", "Ğ"),
+        ("ғ", "ğ"),
+        ("Д", "D"),
+        ("д", "d"),
+        ("Е", "E"),
+        ("е", "e"),
+        ("Ж", "J"),
+        ("ж", "j"),
+        ("З", "Z"),
+        ("з", "z"),
+        ("И", "İ"),
+        ("и", "i"),
+        ("К", "K"),
+        ("к", "k"),
+        ("Қ", "Q"),
+        ("қ", "q"),
+        ("Л", "L"),
+        ("л", "l"),
+        ("М", "M"),
+        ("м", "m"),
+        ("Н", "N"),
+        ("н", "n"),
+        ("Ң", "Ñ"),
+        ("ң", "ñ"),
+        ("О", "O"),
+        ("о", "o"),
+        ("Ө", "Ö"),
+        ("ө", "ö"),
+        ("П", "P"),
+        ("п", "p"),
+        ("Р", "R"),
+        ("р", "r"),
+        ("С", "S"),
+        ("с", "s"),
+        ("Т", "T"),
+        ("т", "t"),
+        ("У", "U"),
+        ("у", "u"),
+        ("Ұ", "Ū"),
+        ("ұ", "ū"),
+        ("Ү", "Ü"),
+        ("ү", "ü"),
+        ("Ф", "F"),
+        ("ф", "f"),
+        ("Х", "H"),
+        ("х", "h"),
+        ("Ч", "Ç"),
+        ("ч", "ç"),
+        ("Ш", "Ş"),
+        ("ш", "ş"),
+        ("Ы", "Y"),
+        ("ы", "y"),
+        ("І", "I"),
+        ("і", "ı"),
+    ],
+    pairs_forward: &[
+        ("Ё", "İo"),
+        ("ё", "io"),
+        ("Й", "İ"),
+        ("й", "i"),
+        ("Һ", "H"),
+        ("һ", "h"),
+        ("Ц", "Ts"),
+        ("ц", "ts"),
+        ("Щ", "Şş"),
+        ("щ", "şş"),
+        ("Ъ", ""),
+        ("ъ", ""),
+        ("Ь", ""),
+        ("ь", ""),
+        ("Э", "E"),
+        ("э", "e"),
+        ("Ю", "İu"),
+        ("ю", "iu"),
+        ("Я", "İa"),
+        ("я", "ia"),
+    ],
+    pairs_reverse: &[],
+    digraph_exceptions: &[],
+};
+
+const TABLES: &[TranslitTable] = &[SERBIAN, AZERBAIJANI_NORTH, UZBEK_NORTHERN, KAZAKH];
+
+/// Pairs of scripts of the same `Language`, which need a table not included yet
+const NOT_INCLUDED: &[(Language, (Script, Script))] =
+    &[(Language::PunjabiEastern, (Script::Gurmukhi, Script::Arabic))];
+
+/// Why there is no [`Transliterator`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranslitError {
+    /// No table for the language and scripts
+    NoTable,
+    /// The table for the language and scripts is not included yet
+    NotIncluded,
+}
+
+impl ::core::fmt::Display for TranslitError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::NoTable => write!(f, "no transliteration table"),
+            Self::NotIncluded => write!(f, "transliteration table is not included yet"),
+        }
+    }
+}
+
+impl ::std::error::Error for TranslitError {}
+
+/// Transliterator of a `Language` from one script to another
+#[derive(Clone, Copy)]
+pub struct Transliterator {
+    table: &'static TranslitTable,
+    reverse: bool,
+}
+
+impl ::core::fmt::Debug for Transliterator {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Transliterator")
+            .field("lang", &self.language())
+            .field("from", &self.from())
+            .field("to", &self.to())
+            .finish()
+    }
+}
+
+impl Transliterator {
+    /// Error if there is no table for the `lang` and scripts
+    pub fn new(lang: Language, from: Script, to: Script) -> Result<Self, TranslitError> {
+        if NOT_INCLUDED
+            .iter()
+            .any(|&(l, scripts)| l == lang && (scripts == (from, to) || scripts == (to, from)))
+        {
+            return Err(TranslitError::NotIncluded);
+        }
+        TABLES
+            .iter()
+            .find_map(|table| {
+                if table.lang != lang {
+                    return None;
+                }
+                if table.scripts == (from, to) {
+                    Some(Self {
+                        table,
+                        reverse: false,
+                    })
+                } else if table.scripts == (to, from) {
+                    Some(Self {
+                        table,
+                        reverse: true,
+                    })
+                } else {
+                    None
+                }
+            })
+            .ok_or(TranslitError::NoTable)
+    }
+
+    /// From the script of a (detected) `ScriptLanguage` to the script `to`
+    #[inline]
+    pub fn from_slang(slang: ScriptLanguage, to: Script) -> Result<Self, TranslitError> {
+        let (lang, from) = slang.into_parts();
+        Self::new(lang, from, to)
+    }
+
+    /// All transliterators, both directions
+    pub fn all() -> impl Iterator<Item = Self> {
+        TABLES.iter().flat_map(|table| {
+            [false, true]
+                .into_iter()
+                .map(move |reverse| Self { table, reverse })
+        })
+    }
+
+    #[inline]
+    pub fn language(&self) -> Language {
+        self.table.lang
+    }
+
+    #[inline]
+    pub fn from(&self) -> Script {
+        if self.reverse {
+            self.table.scripts.1
+        } else {
+            self.table.scripts.0
+        }
+    }
+
+    #[inline]
+    pub fn to(&self) -> Script {
+        if self.reverse {
+            self.table.scripts.0
+        } else {
+            self.table.scripts.1
+        }
+    }
+
+    /// `ScriptLanguage` of the result, if it exists
+    #[inline]
+    pub fn to_slang(&self) -> Option<ScriptLanguage> {
+        ScriptLanguage::from_parts((self.language(), self.to()))
+    }
+
+    /// Opposite direction
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Self {
+            table: self.table,
+            reverse: !self.reverse,
+        }
+    }
+
+    /// Longest (source, target) pair matching the start of `chars`
+    fn find(&self, chars: &[char]) -> Option<(usize, &'static str)> {
+        let reverse = self.reverse;
+        self.table
+            .pairs
+            .iter()
+            .map(|&(a, b)| if reverse { (b, a) } else { (a, b) })
+            .chain(
+                self.table
+                    .pairs_forward
+                    .iter()
+                    .filter(|_| !reverse)
+                    .copied(),
+            )
+            .chain(
+                self.table
+                    .pairs_reverse
+                    .iter()
+                    .filter(|_| reverse)
+                    .map(|&(a, b)| (b, a)),
+            )
+            .filter_map(|(src, dst)| {
+                let len = src.chars().count();
+                chars
+                    .get(..len)
+                    .filter(|c| src.chars().eq(c.iter().copied()))
+                    .map(|_| (len, dst))
+            })
+            .max_by_key(|&(len, _)| len)
+    }
+
+    /// Positions in `chars`, where no source pair can continue over, from the digraph exceptions
+    fn digraph_breaks(&self, chars: &[char]) -> Vec<usize> {
+        if !self.reverse {
+            return Vec::new();
+        }
+        let mut breaks = Vec::new();
+        for start in 0..chars.len() {
+            for (head, tail) in self
+                .table
+                .digraph_exceptions
+                .iter()
+                .filter_map(|e| e.split_once('|'))
+            {
+                let mut rest = chars[start..]
+                    .iter()
+                    .map(|ch| ch.to_lowercase().next().unwrap_or(*ch));
+                if head
+                    .chars()
+                    .chain(tail.chars())
+                    .all(|c| rest.next() == Some(c))
+                {
+                    breaks.push(start + head.chars().count());
+                }
+            }
+        }
+        breaks.sort_unstable();
+        breaks
+    }
+
+    /// Chars without a mapping are left as is.
+    ///
+    /// A capital letter mapped to several letters is in all caps
+    /// if the neighbor letter is capital: "ЉУБАВ" -> "LJUBAV", else "Љубав" -> "Ljubav".
+    ///
+    /// Latin digraphs are always converted as one letter ("ljubav" -> "љубав"),
+    /// except for the stems in the table's exception list ("injekcija" -> "инјекција").
+    pub fn translit(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let breaks = self.digraph_breaks(&chars);
+        let mut res = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let end = breaks.iter().find(|&&b| b > i).map_or(chars.len(), |&b| b);
+            let Some((len, dst)) = self.find(&chars[i..end]) else {
+                res.push(chars[i]);
+                i += 1;
+                continue;
+            };
+
+            let neighbor = chars
+                .get(i + len)
+                .filter(|c| c.is_alphabetic())
+                .or_else(|| i.checked_sub(1).map(|p| &chars[p]));
+            if dst.chars().nth(1).is_some()
+                && chars[i].is_uppercase()
+                && neighbor.is_some_and(|c| c.is_uppercase())
+            {
+                res.extend(dst.chars().flat_map(char::to_uppercase));
+            } else {
+                res.push_str(dst);
+            }
+            i += len;
+        }
+        res
+    }
+}
+
+/// Shortcut for [`Transliterator::from_slang`](struct.Transliterator.html#method.from_slang)
+#[inline]
+pub fn translit(slang: ScriptLanguage, to: Script, text: &str) -> Result<String, TranslitError> {
+    Transliterator::from_slang(slang, to).map(|tr| tr.translit(text))
+}
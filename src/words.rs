@@ -1,11 +1,16 @@
 use crate::{
     ch_norm::{self, CharData},
-    lang::{script_char_to_slangs, UcdScript, WORD_COMMON_FIRST_CHAR_NOT_SKIPPABLE},
-    slang_arr_default, CharNormalizingIterator, ScriptLanguage, ScriptLanguageArr,
+    lang::{
+        script_char_to_slangs, script_char_to_tiered_slangs, LetterTier, UcdScript,
+        WORD_COMMON_FIRST_CHAR_NOT_SKIPPABLE,
+    },
+    slang_arr_default, CharNormalizingIterator, CustomLanguage, NormalizationAnomaly,
+    ScriptLanguage, ScriptLanguageArr, ScriptLanguageSet,
 };
 use ::core::ops::Range;
 use debug_unsafe::slice::SliceGetter;
 use strum::IntoEnumIterator;
+use unicode_normalization::char::canonical_combining_class;
 
 pub trait WordBuf: Default {
     fn push(&mut self, ch: char);
@@ -47,6 +52,48 @@ impl WordBuf for compact_str::CompactString {
     }
 }
 
+/// pushes to both
+impl<A: WordBuf, C: WordBuf> WordBuf for (A, C) {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        self.0.push(ch);
+        self.1.push(ch);
+    }
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty() && self.1.is_empty()
+    }
+}
+
+/// Word buf with only standard Unicode chars: custom private use area chars
+/// are pushed as their standard sequences ([`ch_norm::decompose_custom`](../ch_norm/fn.decompose_custom.html)),
+/// while the detection still uses the custom chars.
+///
+/// ```
+/// use alphabet_detector::{words, StandardUnicode};
+///
+/// let word = words::from_ch_ind::<StandardUnicode<String>>("Ɛ̌".char_indices())
+///     .next()
+///     .unwrap();
+/// assert_eq!(word.buf.0, "ɛ\u{30c}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StandardUnicode<B: WordBuf>(pub B);
+
+impl<B: WordBuf> WordBuf for StandardUnicode<B> {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        match ch_norm::decompose_custom(ch) {
+            Some(chars) => chars.iter().for_each(|&c| self.0.push(c)),
+            None => self.0.push(ch),
+        }
+    }
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 /// if you don't need word buf
 impl WordBuf for bool {
     #[inline(always)]
@@ -59,7 +106,28 @@ impl WordBuf for bool {
     }
 }
 
-pub struct WordIterator<I: Iterator<Item = CharData>, B: WordBuf> {
+/// Alphabets looked up by the `WordIterator` alongside the built-in `ScriptLanguage`s,
+/// like the [`AlphabetRegistry`](../struct.AlphabetRegistry.html) ones
+pub trait CustomAlphabets {
+    /// Count of the alphabets
+    fn count(&self) -> usize;
+    /// Alphabets containing the char (lowercase)
+    fn char_langs(&self, ch: char) -> &[CustomLanguage];
+}
+
+/// no custom alphabets
+impl CustomAlphabets for () {
+    #[inline(always)]
+    fn count(&self) -> usize {
+        0
+    }
+    #[inline(always)]
+    fn char_langs(&self, _ch: char) -> &[CustomLanguage] {
+        &[]
+    }
+}
+
+pub struct WordIterator<I: Iterator<Item = CharData>, B: WordBuf, A: CustomAlphabets = ()> {
     norm_iter: CharNormalizingIterator<I>,
     word_buf: B,
     word_start_index: usize,
@@ -68,6 +136,25 @@ pub struct WordIterator<I: Iterator<Item = CharData>, B: WordBuf> {
     word_langs_cnt: ScriptLanguageArr<u32>,
     word_common_langs_cnt: ScriptLanguageArr<u32>,
     res: Option<Word<B>>,
+    lookups: Option<Vec<CharLookup>>,
+    tier_weighted: bool,
+    custom: A,
+    word_custom_cnt: Vec<u32>,
+    word_common_custom_cnt: Vec<u32>,
+    /// of the last returned word
+    custom_cnt: Vec<u32>,
+}
+
+/// Lookup of a char made by the `WordIterator`, recorded for [`explain`](../fn.explain.html)
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CharLookup {
+    pub(crate) idx: usize,
+    pub(crate) ch: char,
+    /// `UcdScript::Inherited` is resolved to the previous char script
+    pub(crate) script: UcdScript,
+    pub(crate) langs: &'static [ScriptLanguage],
+    /// Split the word from the previous one, because their alphabets (or scripts) do not intersect
+    pub(crate) split: bool,
 }
 
 impl<I: Iterator<Item = CharData>, B: WordBuf> From<CharNormalizingIterator<I>>
@@ -75,16 +162,7 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> From<CharNormalizingIterator<I>>
 {
     #[inline]
     fn from(norm_iter: CharNormalizingIterator<I>) -> WordIterator<I, B> {
-        Self {
-            norm_iter,
-            word_buf: Default::default(),
-            word_start_index: Default::default(),
-            not_saved_word_end_index: Default::default(),
-            prev_char_script: UcdScript::Common,
-            word_langs_cnt: slang_arr_default(),
-            word_common_langs_cnt: slang_arr_default(),
-            res: None,
-        }
+        Self::with_custom(norm_iter, ())
     }
 }
 
@@ -92,6 +170,8 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> From<CharNormalizingIterator<I>>
     for WordIterator<I>
 { */
 // impl<I: Iterator<Item = CharData>> WordIterator<I> {
+/// Words can contain custom private use area chars,
+/// use [`StandardUnicode`](struct.StandardUnicode.html) buf to get only standard Unicode.
 #[inline]
 pub fn from_ch_ind<B: WordBuf>(
     char_indices: impl Iterator<Item = (usize, char)>,
@@ -104,10 +184,78 @@ pub fn from_ch_ind<B: WordBuf>(
 pub struct Word<B: WordBuf> {
     pub buf: B,
     pub range: Range<usize>,
+    /// Letters counts, or sums of the letters weights for
+    /// [`WordIterator::tier_weighted`](struct.WordIterator.html#method.tier_weighted).
     pub langs_cnt: ScriptLanguageArr<u32>,
 }
 
-impl<I: Iterator<Item = CharData>, B: WordBuf> WordIterator<I, B> {
+impl<B: WordBuf> Word<B> {
+    /// `ScriptLanguage`s found in the word
+    #[inline]
+    pub fn langs_set(&self) -> ScriptLanguageSet {
+        ScriptLanguageSet::from_counts(&self.langs_cnt)
+    }
+}
+
+impl<I: Iterator<Item = CharData>, B: WordBuf, A: CustomAlphabets> WordIterator<I, B, A> {
+    /// Also detects the `custom` alphabets, their counts of each word are
+    /// [`custom_cnt`](#method.custom_cnt).
+    #[inline]
+    pub fn with_custom(norm_iter: CharNormalizingIterator<I>, custom: A) -> Self {
+        let custom_len = custom.count();
+        Self {
+            norm_iter,
+            word_buf: Default::default(),
+            word_start_index: Default::default(),
+            not_saved_word_end_index: Default::default(),
+            prev_char_script: UcdScript::Common,
+            word_langs_cnt: slang_arr_default(),
+            word_common_langs_cnt: slang_arr_default(),
+            res: None,
+            lookups: None,
+            tier_weighted: false,
+            custom,
+            word_custom_cnt: vec![0; custom_len],
+            word_common_custom_cnt: vec![0; custom_len],
+            custom_cnt: vec![0; custom_len],
+        }
+    }
+
+    /// Counts the letters by their [`LetterTier::weight`](../enum.LetterTier.html#method.weight)
+    /// instead of 1. Extended and loanword letters are counted only then, and support
+    /// a `ScriptLanguage` less than core ones.
+    #[inline]
+    pub fn tier_weighted(mut self) -> Self {
+        self.tier_weighted = true;
+        self
+    }
+
+    /// Counts of the custom alphabets in the last returned word,
+    /// indexed by [`CustomLanguage::index`](../struct.CustomLanguage.html#method.index)
+    #[inline]
+    pub fn custom_cnt(&self) -> &[u32] {
+        &self.custom_cnt
+    }
+
+    /// Chars dropped by the normalization so far, see
+    /// [`CharNormalizingIterator::anomalies`](../struct.CharNormalizingIterator.html#method.anomalies).
+    #[inline]
+    pub fn anomalies(&self) -> &[NormalizationAnomaly] {
+        self.norm_iter.anomalies()
+    }
+
+    /// Records the lookups of chars, see [`take_lookups`](#method.take_lookups)
+    #[inline]
+    pub(crate) fn record_lookups(mut self) -> Self {
+        self.lookups = Some(Vec::new());
+        self
+    }
+
+    #[inline]
+    pub(crate) fn take_lookups(&mut self) -> Vec<CharLookup> {
+        self.lookups.take().unwrap_or_default()
+    }
+
     fn save_word(&mut self) {
         if !self.word_buf.is_empty() {
             ::core::mem::replace(&mut self.word_common_langs_cnt, slang_arr_default())
@@ -121,11 +269,23 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> WordIterator<I, B> {
                 langs_cnt: ::core::mem::replace(&mut self.word_langs_cnt, slang_arr_default()),
             });
             // resets temp variables by taking
+
+            for ((cnt, common_cnt), word_cnt) in self
+                .word_custom_cnt
+                .iter_mut()
+                .zip(self.word_common_custom_cnt.iter_mut())
+                .zip(self.custom_cnt.iter_mut())
+            {
+                *word_cnt = *cnt + ::core::mem::take(common_cnt);
+                *cnt = 0;
+            }
         }
     }
 }
 
-impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
+impl<I: Iterator<Item = CharData>, B: WordBuf, A: CustomAlphabets> Iterator
+    for WordIterator<I, B, A>
+{
     type Item = Word<B>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -134,6 +294,7 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
                 mut script,
                 ccc: _,
                 idx,
+                len,
                 ch,
             }) = self.norm_iter.next()
             else {
@@ -149,17 +310,31 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
             }
 
             let langs = script_char_to_slangs(script, ch);
+            // lowercase
+            let ch_lower = if let Some(c) = ch.to_lowercase().next() {
+                c
+            } else {
+                #[cfg(debug_assertions)]
+                unreachable!("No lowercase for {:?}", ch);
+                #[allow(unreachable_code)]
+                ch
+            };
 
             let langs_not_intersect = if self.prev_char_script != script {
                 !(ch == '-' || {
-                    let langs_cnt = if self.prev_char_script == UcdScript::Common {
-                        &self.word_common_langs_cnt
+                    let (langs_cnt, custom_cnt) = if self.prev_char_script == UcdScript::Common {
+                        (&self.word_common_langs_cnt, &self.word_common_custom_cnt)
                     } else {
-                        &self.word_langs_cnt
+                        (&self.word_langs_cnt, &self.word_custom_cnt)
                     };
                     langs
                         .iter()
                         .any(|&l| *langs_cnt.get_safe_unchecked(l as usize) > 0)
+                        || self
+                            .custom
+                            .char_langs(ch_lower)
+                            .iter()
+                            .any(|l| *custom_cnt.get_safe_unchecked(l.index()) > 0)
                 })
             } else {
                 false
@@ -186,9 +361,19 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
                 false
             };
 
+            if let Some(lookups) = &mut self.lookups {
+                lookups.push(CharLookup {
+                    idx,
+                    ch,
+                    script,
+                    langs,
+                    split: langs_not_intersect && !ch_skip && !self.word_buf.is_empty(),
+                });
+            }
+
             if ch_skip {
                 self.save_word();
-                self.word_start_index = idx + ch.len_utf8();
+                self.word_start_index = idx + len;
             } else {
                 if langs_not_intersect {
                     self.save_word();
@@ -196,29 +381,41 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
                 }
 
                 // saving char
-                self.not_saved_word_end_index = idx + ch.len_utf8();
-                // lowercase
-                let ch = if let Some(c) = ch.to_lowercase().next() {
-                    c
-                } else {
-                    #[cfg(debug_assertions)]
-                    unreachable!("No lowercase for {:?}", ch);
-                    #[allow(unreachable_code)]
-                    ch
-                };
+                self.not_saved_word_end_index = idx + len;
+                let ch = ch_lower;
                 self.word_buf.push(ch);
 
-                let langs_cnt = if script == UcdScript::Common {
-                    &mut self.word_common_langs_cnt
+                let (langs_cnt, custom_cnt) = if script == UcdScript::Common {
+                    (
+                        &mut self.word_common_langs_cnt,
+                        &mut self.word_common_custom_cnt,
+                    )
                 } else {
-                    &mut self.word_langs_cnt
+                    (&mut self.word_langs_cnt, &mut self.word_custom_cnt)
                 };
+                let incr = if self.tier_weighted {
+                    LetterTier::Core.weight()
+                } else {
+                    1
+                };
+                if ch == '-' {
+                    custom_cnt.iter_mut().for_each(|cnt| *cnt += incr);
+                } else {
+                    for lang in self.custom.char_langs(ch) {
+                        *custom_cnt.get_safe_unchecked_mut(lang.index()) += incr;
+                    }
+                }
                 let langs_cnt_incr =
-                    |lang: ScriptLanguage| *langs_cnt.get_safe_unchecked_mut(lang as usize) += 1;
+                    |lang: ScriptLanguage| *langs_cnt.get_safe_unchecked_mut(lang as usize) += incr;
                 if ch == '-' {
                     ScriptLanguage::iter().for_each(langs_cnt_incr);
                 } else {
                     langs.iter().copied().for_each(langs_cnt_incr);
+                    if self.tier_weighted {
+                        for &(lang, tier) in script_char_to_tiered_slangs(script, ch) {
+                            *langs_cnt.get_safe_unchecked_mut(lang as usize) += tier.weight();
+                        }
+                    }
                 }
             }
             self.prev_char_script = script;
@@ -227,3 +424,31 @@ impl<I: Iterator<Item = CharData>, B: WordBuf> Iterator for WordIterator<I, B> {
         self.res.take()
     }
 }
+
+/// Whitespace, which is not a part of a combining sequence.
+/// Words are always split at it, and no state is carried through it.
+#[inline]
+fn is_word_boundary(ch: char, next_ch: Option<char>) -> bool {
+    ch.is_whitespace()
+        && UcdScript::find(ch) == UcdScript::Common
+        && next_ch.is_none_or(|c| {
+            canonical_combining_class(c) == 0 && UcdScript::find(c) != UcdScript::Inherited
+        })
+}
+
+/// Finds the first word boundary at or after `from` byte index.
+pub(crate) fn next_word_boundary(text: &str, mut from: usize) -> Option<usize> {
+    if from >= text.len() {
+        return None;
+    }
+    while !text.is_char_boundary(from) {
+        from += 1;
+    }
+    let mut chars = text[from..].char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        if is_word_boundary(ch, chars.peek().map(|&(_, c)| c)) {
+            return Some(from + i);
+        }
+    }
+    None
+}
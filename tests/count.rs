@@ -1,11 +1,12 @@
-use alphabet_detector::{Language, ScriptLanguage, UcdScript};
+use alphabet_detector::{Language, UcdScript};
 use strum::EnumCount;
 
+#[cfg(feature = "all_scripts")]
 #[test]
 fn count_alphabets() {
     assert_eq!(
-        429 + 2,
-        ScriptLanguage::COUNT,
+        430 + 5,
+        alphabet_detector::ScriptLanguage::COUNT,
         "Change alphabets count in docs"
     );
 }
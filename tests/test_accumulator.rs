@@ -0,0 +1,80 @@
+use alphabet_detector::*;
+
+const TEXT: &str =
+    "Natürlich war sie kein Pferd, dachte sie. То есть присяжные не сочли возможным осудить. 经济";
+
+#[test]
+fn test_feed_chunks_same_as_fulltext() {
+    let (words, langs_cnt) = fulltext::<bool>(TEXT.char_indices());
+
+    let mut acc = FulltextAccumulator::new();
+    for chunk in TEXT.split_inclusive(' ') {
+        acc.feed(chunk.char_indices());
+    }
+
+    assert_eq!(acc.langs_count(), &langs_cnt);
+    assert_eq!(acc.words_count(), words.len() as u64);
+}
+
+#[test]
+fn test_extend_and_merge() {
+    let (words, langs_cnt) = fulltext::<bool>(TEXT.char_indices());
+    let (first, second) = words.split_at(words.len() / 2);
+
+    let mut acc1 = FulltextAccumulator::new();
+    acc1.extend(first);
+    let mut acc2 = FulltextAccumulator::new();
+    acc2.extend(second.iter().cloned());
+
+    acc1 += &acc2;
+    assert_eq!(acc1.langs_count(), &langs_cnt);
+    assert_eq!(acc1.words_count(), words.len() as u64);
+    assert_eq!(acc1.clone() + FulltextAccumulator::new(), acc1);
+}
+
+#[test]
+fn test_merge_saturates() {
+    let mut langs_cnt = slang_arr_default::<u32>();
+    langs_cnt[0] = u32::MAX - 1;
+    let mut acc = FulltextAccumulator::new();
+    acc.add_langs_count(&langs_cnt);
+    acc += acc.clone();
+    assert_eq!(acc.langs_count()[0], u32::MAX);
+    assert_eq!(acc.langs_count()[1], 0);
+}
+
+#[test]
+fn test_reset() {
+    let mut acc = FulltextAccumulator::new();
+    acc.feed(TEXT.char_indices());
+    assert!(!acc.is_empty());
+
+    acc.reset();
+    assert!(acc.is_empty());
+    assert_eq!(acc, FulltextAccumulator::default());
+}
+
+#[cfg(feature = "script_han")]
+#[test]
+fn test_query() {
+    let mut acc = FulltextAccumulator::new();
+    acc.feed("经济".char_indices());
+    let langs: Vec<_> = acc.filter_max().0.collect();
+    assert_eq!(langs, vec![ScriptLanguage::ChineseMandarinSimplified]);
+}
+
+#[cfg(feature = "script_han")]
+#[test]
+fn test_serde() {
+    let mut acc = FulltextAccumulator::new();
+    acc.feed("経済".char_indices());
+
+    let serialized = serde_json::to_string(&acc).unwrap();
+    assert_eq!(
+        serialized,
+        "{\"words_count\":1,\"langs_count\":{\"jpnJpan\":2}}"
+    );
+
+    let deserialized: FulltextAccumulator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, acc);
+}
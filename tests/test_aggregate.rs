@@ -0,0 +1,80 @@
+#![cfg(feature = "all_scripts")]
+
+use alphabet_detector::{ScriptLanguage::*, *};
+use rstest::*;
+
+#[rstest(
+    text,
+    expected_script,
+    expected_language,
+    case("经济", UcdScript::Han, Language::ChineseMandarin),
+    case("наслеђивања", UcdScript::Cyrillic, Language::Serbian),
+    case("Natürlich war sie kein Pferd", UcdScript::Latin, Language::German)
+)]
+fn test_hierarchy_top(text: &str, expected_script: UcdScript, expected_language: Language) {
+    let (_, langs_cnt) = fulltext::<bool>(text.char_indices());
+    let hierarchy = slangs_count_hierarchy(&langs_cnt);
+
+    let top = hierarchy.first().unwrap();
+    assert_eq!(top.script, expected_script, "text '{}'", text);
+    assert_eq!(
+        top.count,
+        slangs_count_by_ucd_script(&langs_cnt)[expected_script as usize]
+    );
+    assert!(
+        top.languages
+            .iter()
+            .take_while(|(_, cnt)| *cnt == top.count)
+            .any(|(l, _)| *l == expected_language),
+        "text '{}', got {:?}",
+        text,
+        top.languages
+    );
+}
+
+#[test]
+fn test_by_language_same_script_not_summed() {
+    let mut langs_cnt = slang_arr_default();
+    langs_cnt[ChineseMandarinSimplified as usize] = 2;
+    langs_cnt[ChineseMandarinTraditional as usize] = 1;
+    langs_cnt[Japanese as usize] = 1;
+
+    let by_lang = slangs_count_by_language(&langs_cnt);
+    assert_eq!(by_lang[Language::ChineseMandarin as usize], 2);
+    assert_eq!(by_lang[Language::Japanese as usize], 1);
+
+    let by_script = slangs_count_by_script(&langs_cnt);
+    assert_eq!(by_script[Script::HanSimplified as usize], 2);
+    assert_eq!(by_script[Script::HanTraditional as usize], 1);
+    assert_eq!(by_script[Script::Japanese as usize], 1);
+
+    let by_ucd_script = slangs_count_by_ucd_script(&langs_cnt);
+    assert_eq!(by_ucd_script[UcdScript::Han as usize], 2);
+}
+
+#[test]
+fn test_by_language_different_scripts_summed() {
+    let mut langs_cnt = slang_arr_default();
+    langs_cnt[PunjabiEastern as usize] = 5;
+    langs_cnt[PunjabiEasternShahmukhi as usize] = 3;
+
+    let by_lang = slangs_count_by_language(&langs_cnt);
+    assert_eq!(by_lang[Language::PunjabiEastern as usize], 8);
+
+    let hierarchy = slangs_count_hierarchy(&langs_cnt);
+    assert_eq!(
+        hierarchy,
+        vec![
+            ScriptLanguagesCount {
+                script: UcdScript::Gurmukhi,
+                count: 5,
+                languages: vec![(Language::PunjabiEastern, 5)],
+            },
+            ScriptLanguagesCount {
+                script: UcdScript::Arabic,
+                count: 3,
+                languages: vec![(Language::PunjabiEastern, 3)],
+            },
+        ]
+    );
+}
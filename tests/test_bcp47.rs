@@ -0,0 +1,111 @@
+use ahash::AHashMap;
+use alphabet_detector::{Language, Script, ScriptLanguage};
+#[cfg(feature = "all_scripts")]
+use rstest::*;
+use strum::IntoEnumIterator;
+
+#[cfg(feature = "all_scripts")]
+#[rstest]
+#[case(ScriptLanguage::English, "en")]
+#[case(ScriptLanguage::EnglishDeseret, "en-Dsrt")]
+#[case(ScriptLanguage::Serbian, "sr")]
+#[case(ScriptLanguage::SerbianLatin, "sr-Latn")]
+#[case(ScriptLanguage::ChineseMandarinSimplified, "zh")]
+#[case(ScriptLanguage::ChineseMandarinTraditional, "zh-Hant")]
+#[case(ScriptLanguage::ChineseCantoneseTraditional, "yue")]
+#[case(ScriptLanguage::Japanese, "ja")]
+#[case(ScriptLanguage::PunjabiEastern, "pa")]
+#[case(ScriptLanguage::PunjabiEasternShahmukhi, "pa-Arab")]
+#[case(ScriptLanguage::KurdishCentral, "ckb")]
+#[case(ScriptLanguage::Filipino, "tl")]
+#[case(ScriptLanguage::Cebuano, "ceb")]
+#[case(ScriptLanguage::UnknownLatin, "und-Latn")]
+#[case(ScriptLanguage::MathBraille, "zxx-Brai")]
+fn test_to_bcp47(#[case] slang: ScriptLanguage, #[case] tag: &str) {
+    assert_eq!(slang.to_bcp47(), tag);
+}
+
+#[test]
+fn test_default_script() {
+    assert_eq!(
+        Language::PunjabiEastern.default_script(),
+        Some(Script::Gurmukhi)
+    );
+    assert_eq!(Language::Unknown.default_script(), None);
+    for lang in Language::iter() {
+        if let Some(script) = lang.default_script() {
+            assert!(
+                !script.into_str().is_empty(),
+                "{lang:?} default script {script:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_to_bcp47_unique() {
+    let mut tags = AHashMap::new();
+    for slang in ScriptLanguage::iter() {
+        if let Some(prev) = tags.insert(slang.to_bcp47(), slang) {
+            panic!(
+                "Same BCP 47 tag {} for {prev:?} and {slang:?}",
+                slang.to_bcp47()
+            );
+        }
+    }
+}
+
+#[cfg(feature = "all_scripts")]
+#[rstest]
+#[case("en", &[ScriptLanguage::English])]
+#[case("en-US", &[ScriptLanguage::English])]
+#[case("EN_us", &[ScriptLanguage::English])]
+#[case("pt_BR", &[ScriptLanguage::Portuguese])]
+#[case("uz", &[ScriptLanguage::UzbekNorthern])]
+#[case("zh", &[ScriptLanguage::ChineseMandarinSimplified])]
+#[case("zh-TW", &[ScriptLanguage::ChineseMandarinTraditional])]
+#[case("zh-hant", &[ScriptLanguage::ChineseMandarinTraditional])]
+#[case("zho", &[ScriptLanguage::ChineseMandarinSimplified])]
+#[case("pa-PK", &[ScriptLanguage::PunjabiEasternShahmukhi])]
+#[case("pa-Arab-IN", &[ScriptLanguage::PunjabiEasternShahmukhi])]
+#[case("en-Dsrt", &[ScriptLanguage::EnglishDeseret])]
+#[case("sr", &[ScriptLanguage::Serbian])]
+#[case("sr-Latn", &[ScriptLanguage::SerbianLatin])]
+#[case("sr-Latn-RS", &[ScriptLanguage::SerbianLatin])]
+#[case("xx", &[])]
+#[case("en-Xxxx", &[])]
+#[case("ku", &[ScriptLanguage::KurdishNorthern])]
+#[case("fa", &[ScriptLanguage::PersianFarsi])]
+#[case("und-Arab", &[ScriptLanguage::UnknownArabic])]
+fn test_from_bcp47(#[case] tag: &str, #[case] expected: &[ScriptLanguage]) {
+    assert_eq!(ScriptLanguage::from_bcp47(tag), expected);
+}
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_from_bcp47_und() {
+    let slangs = ScriptLanguage::from_bcp47("und");
+    let unknowns: &[ScriptLanguage] = Language::Unknown.into();
+    assert_eq!(slangs, unknowns);
+    assert!(slangs.contains(&ScriptLanguage::UnknownLatin));
+    assert!(slangs.contains(&ScriptLanguage::UnknownBraille));
+}
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_from_bcp47_ambiguous() {
+    let slangs = ScriptLanguage::from_bcp47("sq");
+    assert!(slangs.contains(&ScriptLanguage::AlbanianTosk));
+    assert!(slangs.contains(&ScriptLanguage::AlbanianElbasan));
+}
+
+#[test]
+fn test_bcp47_roundtrip() {
+    for slang in ScriptLanguage::iter() {
+        assert!(
+            ScriptLanguage::from_bcp47(&slang.to_bcp47()).contains(&slang),
+            "{slang:?} {}",
+            slang.to_bcp47()
+        );
+    }
+}
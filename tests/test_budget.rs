@@ -0,0 +1,84 @@
+use alphabet_detector::*;
+use rstest::*;
+
+const TEXT: &str = "Dizer que não estou, significaria explicar porquê e não me apetece nada desfiar o rosário das minhas lamentações.";
+
+#[rstest]
+#[case(FulltextBudget::Words(3), 3)]
+#[case(FulltextBudget::Words(1000), 18)]
+#[case(FulltextBudget::Words(18), 18)]
+#[case(FulltextBudget::Words(17), 17)]
+#[case(FulltextBudget::Chars(20), 4)]
+fn test_budget_words(#[case] budget: FulltextBudget, #[case] words: u64) {
+    let (_, consumed) = fulltext_counts_budget::<95>(TEXT.char_indices(), budget);
+    assert_eq!(consumed.words, words);
+    assert_eq!(consumed.budget_reached, words < 18);
+}
+
+#[test]
+fn test_budget_reached_on_last_word() {
+    let text = "one two three. ";
+    let (_, consumed) = fulltext_counts_budget::<95>(text.char_indices(), FulltextBudget::Words(3));
+    assert_eq!(consumed.words, 3);
+    assert!(!consumed.budget_reached);
+
+    let (_, consumed) =
+        fulltext_counts_budget::<95>(text.char_indices(), FulltextBudget::Chars(text.len()));
+    assert!(!consumed.budget_reached);
+
+    let (_, consumed) = fulltext_counts_budget::<95>(text.char_indices(), FulltextBudget::Words(2));
+    assert!(consumed.budget_reached);
+    assert_eq!(consumed.bytes, 7);
+}
+
+#[test]
+fn test_budget_prefix_same_as_fulltext() {
+    let (langs_cnt, consumed) =
+        fulltext_counts_budget::<95>(TEXT.char_indices(), FulltextBudget::Words(3));
+    assert_eq!(consumed.bytes, 14);
+    assert_eq!(
+        langs_cnt,
+        fulltext_counts(TEXT[..consumed.bytes].char_indices())
+    );
+}
+
+#[test]
+fn test_budget_unlimited_same_as_fulltext() {
+    let (langs_cnt, consumed) =
+        fulltext_counts_budget::<95>(TEXT.char_indices(), FulltextBudget::Stable(usize::MAX));
+    assert!(!consumed.budget_reached);
+    assert_eq!(consumed.chars, TEXT.chars().count());
+    assert_eq!(langs_cnt, fulltext_counts(TEXT.char_indices()));
+}
+
+#[test]
+fn test_budget_stable() {
+    let text = [TEXT; 100].join(" ");
+    let (langs_cnt, consumed) =
+        fulltext_counts_budget::<95>(text.char_indices(), FulltextBudget::Stable(10));
+    assert!(consumed.budget_reached);
+    assert!(consumed.bytes < text.len() / 10);
+    assert_eq!(
+        filter_with_margin_sorted::<95>(langs_cnt).0[0].0,
+        ScriptLanguage::Portuguese
+    );
+}
+
+#[test]
+fn test_sampled() {
+    let text = [TEXT; 100].join(" ");
+    let (langs_cnt, consumed) =
+        fulltext_counts_sampled::<95>(&text, 4, 100, FulltextBudget::Words(usize::MAX));
+    assert!(consumed.budget_reached);
+    assert_eq!(consumed.words, 68);
+    assert!(consumed.bytes < text.len() / 10);
+    assert_eq!(
+        filter_with_margin_sorted::<95>(langs_cnt).0[0].0,
+        ScriptLanguage::Portuguese
+    );
+
+    let (langs_cnt, consumed) =
+        fulltext_counts_sampled::<95>(TEXT, 4, 100, FulltextBudget::Words(usize::MAX));
+    assert!(!consumed.budget_reached);
+    assert_eq!(langs_cnt, fulltext_counts(TEXT.char_indices()));
+}
@@ -40,3 +40,170 @@ fn test_ch_norm_iter_chars(text: &str, expected_chars: Vec<char>) {
 
     assert_eq!(chars, expected_chars, "text: {}", text);
 }
+
+#[rstest(text, options, expected_chars,
+    case("ﬁ’‐", NormalizationOptions::new(), vec!['f', 'i', '\'', '-']),
+    case("ﬁ’‐", NormalizationOptions::none(), vec!['ﬁ', '’', '‐']),
+    case("ﬁ", NormalizationOptions::new().decompose_ligatures(false), vec!['ﬁ']),
+    case("a’b", NormalizationOptions::new().fold_apostrophes(false), vec!['a', '’', 'b']),
+    case("a‑b", NormalizationOptions::new().fold_hyphens(false), vec!['a', '‑', 'b']),
+    case("\u{628}\u{6e1}", NormalizationOptions::new().fold_arabic_sukun(false), vec!['\u{628}', '\u{6e1}']),
+    case("\u{fe91}", NormalizationOptions::new(), vec!['\u{628}']),
+    case("\u{fe91}", NormalizationOptions::new().decompose_arabic_presentation_forms(false), vec!['\u{fe91}']),
+    case("Ɛ̌", NormalizationOptions::new().compose_custom(false), vec!['Ɛ', '\u{30c}']),
+    case("Ñ", NormalizationOptions::none(), vec!['Ñ']),
+    case("ſa", NormalizationOptions::new().map('ſ', 's'), vec!['s', 'a']),
+    case("ſa", NormalizationOptions::new().map('ſ', 'x').map('ſ', 's'), vec!['s', 'a']),
+    case("a\u{301}", NormalizationOptions::new().map('a', 'o'), vec!['ó']),
+    case("x", NormalizationOptions::new().map('x', 'ж'), vec!['ж']),
+    case("ＡⒷ𝐂🅳ᵉ", NormalizationOptions::new(), vec!['A', 'B', 'C', 'D', 'e']),
+    case("ＡⒷ𝐂🅳ᵉ", NormalizationOptions::new().fold_compatibility(false), vec!['Ａ', 'Ⓑ', '𝐂', '🅳', 'ᵉ']),
+    case("℃™", NormalizationOptions::new(), vec!['℃', '™']),
+)]
+fn test_ch_norm_options(text: &str, options: NormalizationOptions, expected_chars: Vec<char>) {
+    let chars: Vec<_> = ch_norm::from_ch_ind_with(text.char_indices(), options)
+        .map(|data| data.ch)
+        .collect();
+
+    assert_eq!(chars, expected_chars, "text: {}", text);
+}
+
+#[cfg(feature = "script_cyrillic")]
+#[test]
+fn test_ch_norm_options_script() {
+    let data: Vec<_> = ch_norm::from_ch_ind_with(
+        "x".char_indices(),
+        NormalizationOptions::new().map('x', 'ж'),
+    )
+    .collect();
+    assert_eq!(data[0].script, UcdScript::Cyrillic);
+}
+
+#[test]
+fn test_decompose_custom() {
+    assert_eq!(ch_norm::decompose_custom('a'), None);
+    assert_eq!(
+        ch_norm::decompose_custom('\u{f0367}'),
+        Some(&['g', '\u{303}'][..])
+    );
+
+    // round trip, except '\u{f3190}': marks are reordered by ccc, so 'ɛ' + '\u{331}' is composed first
+    for ch in ('\u{f0000}'..='\u{f3fff}')
+        .filter(|&ch| ch != '\u{f3190}' && ch_norm::decompose_custom(ch).is_some())
+    {
+        let seq: String = ch_norm::decompose_custom(ch).unwrap().iter().collect();
+        let recomposed: Vec<_> = ch_norm::from_ch_ind(seq.char_indices())
+            .map(|data| data.ch)
+            .collect();
+        assert_eq!(recomposed, [ch], "sequence: {seq:?}");
+    }
+}
+
+#[test]
+fn test_decompose_custom_str() {
+    assert!(matches!(
+        ch_norm::decompose_custom_str("abc"),
+        ::std::borrow::Cow::Borrowed("abc")
+    ));
+    assert_eq!(ch_norm::decompose_custom_str("b\u{f0c5b}d"), "bɛ\u{30c}d");
+}
+
+#[test]
+fn test_words_standard_unicode() {
+    let text = "Ɛ̌ɔ̌ ẹ̀";
+    let words: Vec<_> =
+        words::from_ch_ind::<(StandardUnicode<String>, String)>(text.char_indices()).collect();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0].buf.0 .0, "ɛ\u{30c}ɔ\u{30c}");
+    assert_eq!(words[0].buf.1, "\u{f0c5b}\u{f0c54}");
+    assert_eq!(words[1].buf.0 .0, "ẹ\u{300}");
+
+    let words_custom: Vec<_> = words::from_ch_ind::<String>(text.char_indices()).collect();
+    for (w, wc) in words.iter().zip(&words_custom) {
+        assert_eq!(w.langs_cnt, wc.langs_cnt);
+        assert_eq!(w.range, wc.range);
+    }
+}
+
+/// adds combining marks U+0300..U+034E (all with ccc > 0) after each letter
+fn zalgo(text: &str, marks: usize) -> String {
+    let mut res = String::new();
+    for (i, ch) in text.chars().enumerate() {
+        res.push(ch);
+        if ch.is_alphabetic() {
+            res.extend(
+                (0..marks)
+                    .map(|m| char::from_u32(0x300 + ((i * 7 + m * 13) % 0x4f) as u32).unwrap()),
+            );
+        }
+    }
+    res
+}
+
+#[test]
+fn test_combining_marks_overflow() {
+    let text = format!("q{}", "\u{301}".repeat(100));
+    let mut iter = ch_norm::from_ch_ind(text.char_indices());
+    let chars: Vec<_> = iter.by_ref().map(|data| data.ch).collect();
+    assert_eq!(chars.len(), 1 + ch_norm::MAX_COMBINING_MARKS);
+    assert_eq!(chars[0], 'q');
+    assert_eq!(
+        iter.anomalies(),
+        [NormalizationAnomaly::CombiningMarksOverflow {
+            idx: 1 + ch_norm::MAX_COMBINING_MARKS * 2,
+            dropped: 100 - ch_norm::MAX_COMBINING_MARKS,
+        }]
+    );
+
+    // composed char, then the next chars are kept
+    let text = format!("a{} b", "\u{301}".repeat(100));
+    let mut iter = ch_norm::from_ch_ind(text.char_indices());
+    let chars: Vec<_> = iter.by_ref().map(|data| data.ch).collect();
+    assert_eq!(chars.first(), Some(&'á'));
+    assert_eq!(&chars[chars.len() - 2..], [' ', 'b']);
+    assert_eq!(iter.anomalies().len(), 1);
+    assert_eq!(
+        iter.anomalies()[0].dropped(),
+        100 - ch_norm::MAX_COMBINING_MARKS
+    );
+
+    let mut iter = ch_norm::from_ch_ind("normal text ﷽".char_indices());
+    iter.by_ref().for_each(drop);
+    assert!(iter.anomalies().is_empty());
+}
+
+#[test]
+fn test_zalgo() {
+    for marks in [
+        ch_norm::MAX_COMBINING_MARKS,
+        ch_norm::MAX_COMBINING_MARKS + 1,
+        50,
+        500,
+    ] {
+        let text = zalgo("zalgo text here", marks);
+        let mut iter = words::from_ch_ind::<String>(text.char_indices());
+        let words: Vec<_> = iter.by_ref().collect();
+        assert_eq!(words.len(), 3, "marks: {marks}");
+        assert!(words[0].buf.starts_with('z'));
+        assert!(words[2].range.end <= text.len());
+
+        let anomalies = iter.anomalies();
+        if marks <= ch_norm::MAX_COMBINING_MARKS {
+            assert!(anomalies.is_empty());
+            continue;
+        }
+        assert_eq!(anomalies.len(), "zalgotexthere".len(), "marks: {marks}");
+        let mut prev_idx = 0;
+        for anomaly in anomalies {
+            assert!(anomaly.idx() > prev_idx);
+            assert!(text.is_char_boundary(anomaly.idx()));
+            prev_idx = anomaly.idx();
+        }
+    }
+
+    // marks only
+    let text = "\u{301}".repeat(1000) + &"\u{94d}".repeat(1000);
+    let mut iter = ch_norm::from_ch_ind(text.char_indices());
+    assert!(iter.by_ref().count() <= ch_norm::MAX_COMBINING_MARKS + 1);
+    assert_eq!(iter.anomalies().len(), 1);
+}
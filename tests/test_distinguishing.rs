@@ -0,0 +1,50 @@
+use alphabet_detector::*;
+
+#[cfg(feature = "script_cyrillic")]
+#[test]
+fn test_distinguishing_chars_pair() {
+    let res = distinguishing_chars(&[ScriptLanguage::Russian, ScriptLanguage::Ukrainian]);
+    let ru = ScriptLanguageSet::from(ScriptLanguage::Russian);
+    let uk = ScriptLanguageSet::from(ScriptLanguage::Ukrainian);
+    assert!(res.contains(&('ї', uk)));
+    assert!(res.contains(&('ы', ru)));
+    assert!(!res.iter().any(|&(ch, _)| ch == 'Ї'));
+    assert!(!res.iter().any(|&(ch, _)| ch == 'а'));
+    assert!(res.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_distinguishing_chars_group() {
+    let slangs = [
+        ScriptLanguage::Spanish,
+        ScriptLanguage::Portuguese,
+        ScriptLanguage::English,
+    ];
+    let all = ScriptLanguageSet::from_slice(&slangs);
+    for (ch, kept) in distinguishing_chars(&slangs) {
+        assert!(!kept.is_empty() && kept != all, "{ch:?}");
+        assert!(kept.is_subset(&all));
+        for slang in slangs {
+            assert_eq!(kept.contains(slang), slang.alphabet_contains(ch), "{ch:?}");
+        }
+    }
+    assert!(distinguishing_chars(&slangs)
+        .iter()
+        .any(|&(ch, kept)| ch == 'ñ' && kept == ScriptLanguageSet::from(ScriptLanguage::Spanish)));
+}
+
+#[test]
+fn test_distinguishing_chars_same() {
+    assert!(distinguishing_chars(&[ScriptLanguage::English]).is_empty());
+    assert!(distinguishing_chars(&[]).is_empty());
+}
+
+#[cfg(feature = "script_greek")]
+#[test]
+fn test_distinguishing_chars_not_listed() {
+    let res = distinguishing_chars(&[ScriptLanguage::English, ScriptLanguage::Greek]);
+    let en = ScriptLanguageSet::from(ScriptLanguage::English);
+    assert!(ScriptLanguage::Greek.alphabet().is_none());
+    assert!(res.contains(&('a', en)));
+    assert!(res.iter().all(|&(_, kept)| kept == en));
+}
@@ -0,0 +1,74 @@
+use alphabet_detector::*;
+use rstest::*;
+
+const TEXT: &str = "Dizer que não estou";
+
+#[rstest]
+#[case(ScriptLanguage::Portuguese, true, &[])]
+#[case(ScriptLanguage::Spanish, false, &[(11, 'ã')])]
+#[case(ScriptLanguage::English, false, &[(11, 'ã')])]
+fn test_explain_missing_chars(
+    #[case] slang: ScriptLanguage,
+    #[case] is_top: bool,
+    #[case] missing_chars: &[(usize, char)],
+) {
+    let expl = explain(TEXT, slang);
+    assert_eq!(expl.slang, slang);
+    assert_eq!(expl.is_top(), is_top);
+    assert_eq!(expl.missing_chars, missing_chars);
+    assert!(expl.words.is_empty());
+}
+
+#[test]
+fn test_explain_supporting_letters() {
+    let expl = explain(TEXT, ScriptLanguage::Spanish);
+    assert_eq!(expl.supporting_letters, 15);
+    assert_eq!(expl.max_letters, 16);
+}
+
+#[cfg(feature = "script_cyrillic")]
+#[test]
+fn test_explain_other_script() {
+    let expl = explain("привет hello", ScriptLanguage::English);
+    assert!(expl.missing_chars.is_empty());
+    assert_eq!(
+        expl.words,
+        [WordExplanation {
+            range: 0..12,
+            word: "привет".into(),
+            supporting_letters: 0,
+            reasons: vec![WordReason::OtherScript(UcdScript::Cyrillic)],
+        }]
+    );
+}
+
+#[cfg(feature = "script_han")]
+#[test]
+fn test_explain_split() {
+    let expl = explain("hello世界", ScriptLanguage::English);
+    assert_eq!(expl.words.len(), 1);
+    assert_eq!(expl.words[0].range, 5..11);
+    assert_eq!(
+        expl.words[0].reasons,
+        [WordReason::Split, WordReason::OtherScript(UcdScript::Han)]
+    );
+}
+
+#[rstest]
+#[case("Dizer que não estou")]
+#[case("hello世界 привет")]
+#[case("ÑầƐ̌ a\u{fe0f}b")]
+fn test_explain_matches_detection(#[case] text: &str) {
+    let langs_cnt = fulltext_counts(text.char_indices());
+    for slang in [
+        ScriptLanguage::English,
+        ScriptLanguage::Portuguese,
+        #[cfg(feature = "script_cyrillic")]
+        ScriptLanguage::Russian,
+        ScriptLanguage::Lingala,
+    ] {
+        let expl = explain(text, slang);
+        assert_eq!(expl.supporting_letters, langs_cnt[slang as usize]);
+        assert_eq!(expl.max_letters, slangs_count_max(&langs_cnt));
+    }
+}
@@ -2,10 +2,14 @@ mod shared;
 #[allow(unused_imports)]
 use shared::*;
 
+#[cfg(feature = "script_han")]
 use ahash::AHashSet;
-use alphabet_detector::{ScriptLanguage::*, *};
+#[cfg(feature = "script_han")]
+use alphabet_detector::ScriptLanguage::*;
+use alphabet_detector::*;
 use rstest::*;
 
+#[cfg(feature = "script_han")]
 #[rstest(expected_language, text, expected_languages,
     case(ChineseMandarinTraditional, "也有越來越多的人開始飼養寵物", ahashset!(ChineseCantoneseTraditional, ChineseMandarinTraditional, Japanese, Korean)),
     case(Japanese, "昨日、東京で大切な友達に会いました。", ahashset!(Japanese)), // Kanji (Han) + Hiragana
@@ -34,3 +38,28 @@ fn test_fulltext_filter_max(
         expected_language, text
     );
 }
+
+#[rstest(
+    text,
+    case("Natürlich war sie kein Pferd, dachte sie"),
+    case("То есть присяжные не сочли возможным"),
+    case("昨日、東京で大切な友達に会いました。"),
+    case("")
+)]
+fn test_fulltext_counts(text: &str) {
+    let (words, langs_cnt) = fulltext::<Vec<char>>(text.char_indices());
+    assert_eq!(fulltext_counts(text.char_indices()), langs_cnt);
+
+    let mut words_each = Vec::new();
+    for_each_word::<Vec<char>>(text.char_indices(), |word| words_each.push(word.buf));
+    assert_eq!(
+        words_each,
+        words.into_iter().map(|w| w.buf).collect::<Vec<_>>()
+    );
+
+    let (_, langs, margin) = fulltext_filter_with_margin_sorted::<bool, 95>(text.char_indices());
+    assert_eq!(
+        fulltext_counts_filter_with_margin_sorted::<95>(text.char_indices()),
+        (langs, margin)
+    );
+}
@@ -1,4 +1,4 @@
-use alphabet_detector::Language;
+use alphabet_detector::{filter_living, slang_arr_default, Language, ScriptLanguage};
 use strum::IntoEnumIterator;
 use Language::*;
 
@@ -90,3 +90,81 @@ fn test_deserialize() {
     let deserialized = serde_json::from_str::<Language>("\"eng\"").unwrap();
     assert_eq!(deserialized, English);
 }
+
+#[test]
+fn test_names() {
+    assert_eq!(German.english_name(), "German");
+    assert_eq!(German.native_name(), Some("Deutsch"));
+    assert_eq!(Japanese.native_name(), Some("日本語"));
+    assert_eq!(ArabicEgyptian.english_name(), "Egyptian Arabic");
+    assert_eq!(AncientNorthArabian.english_name(), "Ancient North Arabian");
+    assert_eq!(Akkadian.native_name(), None);
+
+    for lang in Language::iter() {
+        let name = lang.english_name();
+        assert!(
+            !name.is_empty() && !name.starts_with(' ') && !name.ends_with(' '),
+            "Language {lang:?} wrong name: {name:?}"
+        );
+        if let Some(native) = lang.native_name() {
+            assert!(!native.is_empty(), "Language {lang:?} empty native name");
+        }
+    }
+}
+
+#[test]
+fn test_metadata() {
+    use alphabet_detector::{LanguageFamily, LanguageStatus};
+
+    assert_eq!(Spanish.family(), Some(LanguageFamily::IndoEuropean));
+    assert_eq!(Japanese.family(), Some(LanguageFamily::Japonic));
+    assert_eq!(Unknown.family(), None);
+    // ordered by name
+    assert!(LanguageFamily::AfroAsiatic < LanguageFamily::IndoEuropean);
+    assert!(LanguageFamily::IndoEuropean < LanguageFamily::Uralic);
+
+    assert_eq!(Spanish.status(), LanguageStatus::Living);
+    assert_eq!(Latin.status(), LanguageStatus::Ancient);
+    assert_eq!(OldEnglish.status(), LanguageStatus::Extinct);
+    assert_eq!(Esperanto.status(), LanguageStatus::Constructed);
+
+    assert_eq!(ArabicEgyptian.macrolanguage(), Some("ara"));
+    assert_eq!(ChineseCantonese.macrolanguage(), Some("zho"));
+    assert_eq!(Spanish.macrolanguage(), None);
+
+    let speakers = Spanish.speakers().unwrap();
+    assert_eq!(speakers.total, 558.0);
+    assert!(speakers.l1.unwrap() < speakers.total);
+    assert_eq!(Akkadian.speakers(), None);
+
+    for lang in Language::iter() {
+        if let Some(speakers) = lang.speakers() {
+            assert!(speakers.total > 0.0, "Language {lang:?} no speakers");
+            assert!(
+                speakers.l1.is_none_or(|l1| l1 <= speakers.total),
+                "Language {lang:?} more L1 speakers than total"
+            );
+            assert!(
+                matches!(
+                    lang.status(),
+                    LanguageStatus::Living | LanguageStatus::Constructed
+                ),
+                "Language {lang:?} has speakers"
+            );
+        }
+        if let Some(code) = lang.macrolanguage() {
+            assert_eq!(code.len(), 3, "Language {lang:?}");
+            assert_ne!(code, lang.into_str(), "Language {lang:?}");
+        }
+    }
+}
+
+#[test]
+fn test_filter_living() {
+    let mut langs_cnt = slang_arr_default::<u32>();
+    langs_cnt[ScriptLanguage::Latin as usize] = 5;
+    langs_cnt[ScriptLanguage::Italian as usize] = 4;
+    let langs_cnt = filter_living(langs_cnt);
+    assert_eq!(langs_cnt[ScriptLanguage::Latin as usize], 0);
+    assert_eq!(langs_cnt[ScriptLanguage::Italian as usize], 4);
+}
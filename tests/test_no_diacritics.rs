@@ -0,0 +1,108 @@
+use alphabet_detector::*;
+use rstest::*;
+
+fn no_diacritics() -> FulltextOptions {
+    FulltextOptions::new().no_diacritics(true)
+}
+
+#[rstest(
+    ch,
+    expected,
+    case('ă', Some('a')),
+    case('ș', Some('s')),
+    case('ệ', Some('e')),
+    case('ğ', Some('g')),
+    case('ı', Some('i')),
+    case('ł', Some('l')),
+    case('ż', Some('z')),
+    case('a', None),
+    case('ß', None),
+    case('æ', None),
+    case('й', None)
+)]
+fn test_diacritic_base(ch: char, expected: Option<char>) {
+    assert_eq!(diacritic_base(ch), expected);
+}
+
+#[rstest(
+    text,
+    expected,
+    case("Toi yeu tieng Viet", ScriptLanguage::Vietnamese),
+    case("Buna ziua, ce mai faci astazi", ScriptLanguage::Romanian),
+    case("Dziekuje bardzo za pomoc", ScriptLanguage::Polish),
+    case("Gunaydin, nasilsin bugun", ScriptLanguage::Turkish),
+    case("Dekuji, mas cas?", ScriptLanguage::Czech),
+    case("Nu stiu daca pot sa vin maine", ScriptLanguage::Romanian),
+    case("Nie wiem, czy to jest wazne", ScriptLanguage::Polish),
+    case("Bugun hava cok guzel", ScriptLanguage::Turkish)
+)]
+fn test_no_diacritics(text: &str, expected: ScriptLanguage) {
+    let plain = fulltext_counts(text.char_indices());
+    let (langs_cnt, flags) = fulltext_counts_with(text.char_indices(), no_diacritics());
+    assert!(flags.no_diacritics);
+
+    let english = ScriptLanguage::English as usize;
+    assert_eq!(plain[english], langs_cnt[english]);
+    assert!(langs_cnt[expected as usize] > plain[expected as usize]);
+    // ranks first
+    let (top, _) = filter_max(langs_cnt);
+    assert_eq!(top.collect::<Vec<_>>(), [expected]);
+}
+
+#[test]
+fn test_no_diacritics_projection() {
+    let text = "Toi yeu tieng Viet";
+    let plain = fulltext_counts(text.char_indices());
+    let (langs_cnt, _) = fulltext_counts_with(text.char_indices(), no_diacritics());
+    let spanish = ScriptLanguage::Spanish as usize;
+    // projection of "á", "é", "í", "ó", "ú", "ñ", without frequent words
+    assert!(langs_cnt[spanish] > plain[spanish]);
+    assert!(langs_cnt[spanish] < langs_cnt[ScriptLanguage::Vietnamese as usize]);
+}
+
+#[rstest(
+    text,
+    case("Tôi yêu tiếng Việt"),
+    case("Bună ziua"),
+    case("The quick brown fox jumps over the lazy dog"),
+    case("I have a book and I like it"),
+    case("Hello world, how are you today?"),
+    case("Привет"),
+    case("123 !"),
+    case("")
+)]
+fn test_no_diacritics_not_applied(text: &str) {
+    let plain = fulltext_counts(text.char_indices());
+    let (langs_cnt, flags) = fulltext_counts_with(text.char_indices(), no_diacritics());
+    assert!(!flags.no_diacritics);
+    assert_eq!(langs_cnt, plain);
+}
+
+#[test]
+fn test_no_diacritics_off() {
+    let text = "Toi yeu tieng Viet";
+    let (langs_cnt, flags) = fulltext_counts_with(text.char_indices(), FulltextOptions::new());
+    assert!(!flags.no_diacritics);
+    assert_eq!(langs_cnt, fulltext_counts(text.char_indices()));
+}
+
+#[test]
+fn test_no_diacritics_words() {
+    let text = "Dziekuje bardzo";
+    let (words, langs_cnt, flags) = fulltext_with::<String>(text.char_indices(), no_diacritics());
+    let (words_plain, langs_cnt_plain) = fulltext::<String>(text.char_indices());
+    assert!(flags.no_diacritics);
+    assert_eq!(
+        words.iter().map(|w| &w.buf).collect::<Vec<_>>(),
+        ["dziekuje", "bardzo"]
+    );
+    assert!(words
+        .iter()
+        .zip(&words_plain)
+        .all(|(w, p)| w.range == p.range && w.langs_cnt == p.langs_cnt));
+    assert_eq!(
+        langs_cnt,
+        fulltext_counts_with(text.char_indices(), no_diacritics()).0
+    );
+    assert_ne!(langs_cnt, langs_cnt_plain);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "rayon")]
+
+use alphabet_detector::*;
+
+const SENTENCES: &[&str] = &[
+    "Natürlich war sie kein Pferd, dachte sie, aber warum wurde sie dann geritten?",
+    "То есть присяжные не сочли возможным осудить за соучастие в убийстве.",
+    "昨日、東京で大切な友達に会いました。",
+    "و في نفس الوقت أقول بأن الشيخ صالح لم يشر إلى مسؤولية الدولة",
+    "a \u{301}\u{302} zalgo ̸̢͎ ̸a\u{301} ¿qué?\n\t",
+];
+
+#[test]
+fn test_par_fulltext_same_as_sequential() {
+    let mut text = String::new();
+    let mut i = 0;
+    while text.len() < 1 << 19 {
+        text.push_str(SENTENCES[i % SENTENCES.len()]);
+        text.push(if i % 3 == 0 { '\n' } else { ' ' });
+        i += 1;
+    }
+
+    let (words, langs_cnt) = fulltext::<String>(text.char_indices());
+    let (par_words, par_langs_cnt) = par_fulltext::<String>(&text);
+
+    assert_eq!(par_langs_cnt, langs_cnt);
+    assert_eq!(par_words.len(), words.len());
+    for (par_word, word) in par_words.iter().zip(words.iter()) {
+        assert_eq!(par_word.buf, word.buf);
+        assert_eq!(par_word.range, word.range);
+        assert_eq!(par_word.langs_cnt, word.langs_cnt);
+    }
+
+    assert_eq!(par_fulltext_counts(&text), langs_cnt);
+}
+
+#[test]
+fn test_par_fulltext_small() {
+    let text = SENTENCES[0];
+    let (words, langs_cnt) = fulltext::<Vec<char>>(text.char_indices());
+    let (par_words, par_langs_cnt) = par_fulltext::<Vec<char>>(text);
+
+    assert_eq!(par_langs_cnt, langs_cnt);
+    assert_eq!(
+        par_words.into_iter().map(|w| w.buf).collect::<Vec<_>>(),
+        words.into_iter().map(|w| w.buf).collect::<Vec<_>>()
+    );
+    assert_eq!(par_fulltext_counts(""), slang_arr_default());
+}
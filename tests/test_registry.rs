@@ -0,0 +1,131 @@
+use alphabet_detector::*;
+
+fn registry() -> (AlphabetRegistry, CustomLanguage) {
+    let mut registry = AlphabetRegistry::new();
+    let clang = registry
+        .add("Testish", UcdScript::Latin, "ABCŋƐ".chars())
+        .unwrap();
+    (registry, clang)
+}
+
+#[test]
+fn test_add() {
+    let (registry, clang) = registry();
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.find("Testish"), Some(clang));
+    let alphabet = registry.get(clang).unwrap();
+    assert_eq!(alphabet.script, UcdScript::Latin);
+    assert!(alphabet.contains('a'));
+    assert!(alphabet.contains('ɛ'));
+    assert!(!alphabet.contains('A'));
+    assert_eq!(registry.name(clang.into()), Some("Testish"));
+    assert_eq!(
+        registry.name(ScriptLanguage::English.into()),
+        Some(ScriptLanguage::English.display_name())
+    );
+}
+
+#[test]
+fn test_words() {
+    let (registry, clang) = registry();
+    let words: Vec<_> = registry
+        .words::<String>("Abɛŋ cab".char_indices())
+        .collect();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0].word.buf, "abɛŋ");
+    assert_eq!(words[0].custom_cnt, [4]);
+    assert_eq!(words[1].custom_cnt, [3]);
+    assert!(words[0]
+        .langs()
+        .any(|(lang, cnt)| lang == AnyLanguage::Custom(clang) && cnt == 4));
+}
+
+#[test]
+fn test_fulltext() {
+    let (registry, clang) = registry();
+    let text = "ŋab ɛba cŋɛ";
+    let (words, langs_count) = registry.fulltext::<bool>(text.char_indices());
+    assert_eq!(words.len(), 3);
+    assert_eq!(langs_count, registry.fulltext_counts(text.char_indices()));
+    assert_eq!(langs_count.builtin, fulltext_counts(text.char_indices()));
+    assert_eq!(langs_count.custom, [9]);
+
+    let (langs, _) = langs_count.filter_with_margin_sorted::<95>();
+    assert!(langs.contains(&(AnyLanguage::Custom(clang), 9)));
+    assert!(!langs.contains(&(AnyLanguage::Builtin(ScriptLanguage::English), 6)));
+    assert!(langs_count
+        .filter_max()
+        .0
+        .any(|lang| lang == AnyLanguage::Custom(clang)));
+}
+
+#[test]
+fn test_from_reader() {
+    let data = "# name\tscript\tchars\n\nTestish\tLatn\tabc ŋ\nOther\tCyrl\tабв\n";
+    let registry = AlphabetRegistry::from_reader(data.as_bytes()).unwrap();
+    assert_eq!(registry.len(), 2);
+    let other = registry.get(registry.find("Other").unwrap()).unwrap();
+    assert_eq!(other.script, UcdScript::Cyrillic);
+    assert!(other.contains('б'));
+    assert!(registry
+        .get(registry.find("Testish").unwrap())
+        .unwrap()
+        .contains('ŋ'));
+
+    assert!(matches!(
+        AlphabetRegistry::from_reader("Bad\tXxxx\tabc".as_bytes()),
+        Err(AlphabetRegistryError::UnknownScript(1, _))
+    ));
+    assert!(matches!(
+        AlphabetRegistry::from_reader("\nBad abc".as_bytes()),
+        Err(AlphabetRegistryError::InvalidLine(2))
+    ));
+}
+
+#[test]
+fn test_from_list() {
+    let registry =
+        AlphabetRegistry::from_list([("A", UcdScript::Latin, "ab"), ("B", UcdScript::Greek, "αβ")])
+            .unwrap();
+
+    assert_eq!(
+        registry
+            .iter()
+            .map(|(_, a)| a.name.as_str())
+            .collect::<Vec<_>>(),
+        ["A", "B"]
+    );
+}
+
+#[cfg(feature = "script_greek")]
+#[test]
+fn test_other_script_skipped() {
+    let registry = AlphabetRegistry::from_list([("C", UcdScript::Latin, "abγ")]).unwrap();
+    let (_, alphabet) = registry.iter().next().unwrap();
+    assert!(alphabet.contains('a'));
+    assert!(!alphabet.contains('γ'));
+}
+
+#[test]
+fn test_custom_letters_do_not_split() {
+    let text = "naˀab";
+    let words: Vec<_> = words::from_ch_ind::<String>(text.char_indices())
+        .map(|w| w.buf)
+        .collect();
+    assert_eq!(words, ["na", "ab"]);
+
+    let registry = AlphabetRegistry::from_list([("Glottal", UcdScript::Latin, "abnˀ")]).unwrap();
+    let words: Vec<_> = registry.words::<String>(text.char_indices()).collect();
+    assert_eq!(words.len(), 1);
+    assert_eq!(words[0].word.buf, "naˀab");
+    assert_eq!(words[0].word.range, 0..text.len());
+    assert_eq!(words[0].custom_cnt, [5]);
+}
+
+#[test]
+fn test_error_display() {
+    let err = AlphabetRegistry::from_reader("Bad\tXxxx\tabc".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "line 1: unknown script \"Xxxx\"");
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.source().is_none());
+}
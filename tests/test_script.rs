@@ -67,3 +67,13 @@ fn test_deserialize() {
     let deserialized = serde_json::from_str::<Script>("\"Latn\"").unwrap();
     assert_eq!(deserialized, Latin);
 }
+
+#[test]
+fn test_english_name() {
+    assert_eq!(Script::Latin.english_name(), "Latin");
+    assert_eq!(Script::OldHungarian.english_name(), "Old Hungarian");
+    assert_eq!(Script::HanTraditional.english_name(), "Traditional Han");
+    for script in Script::iter() {
+        assert!(!script.english_name().is_empty());
+    }
+}
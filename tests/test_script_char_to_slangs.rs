@@ -22,7 +22,7 @@ fn test_each_char() {
     // let decomp_nfd = icu_normalizer::DecomposingNormalizerBorrowed::new_nfd();
     // let composer = CanonicalCompositionBorrowed::new();
     for &(script, ranges) in BY_NAME {
-        if script == UcdScript::Inherited {
+        if script == UcdScript::Inherited || !script.enabled() {
             continue;
         }
         for ch in ranges.iter().flat_map(|range| range.0..=range.1) {
@@ -135,7 +135,7 @@ fn test_alphabets() {
         .enumerate()
         .filter(|(_, scrs)| scrs.len() > 1)
         .map(|(sl, _)| unsafe { ScriptLanguage::transmute_from_usize(sl) })
-        .filter(|sl| ![ScriptLanguage::Japanese, ScriptLanguage::Korean].contains(sl))
+        .filter(|&sl| ![Language::Japanese, Language::Korean].contains(&Language::from(sl)))
         .collect();
 
     if !slangs_used.is_empty() {
@@ -0,0 +1,44 @@
+//! Excluded scripts run with `--no-default-features --features script_latin`
+
+#[cfg(not(feature = "script_greek"))]
+#[test]
+fn test_excluded_script_fallback() {
+    use alphabet_detector::*;
+
+    assert!(!UcdScript::Greek.enabled());
+    assert!(UcdScript::Latin.enabled());
+    assert_eq!(UcdScript::find('π'), UcdScript::Common);
+    assert!(script_char_to_slangs(UcdScript::Greek, 'π').is_empty());
+
+    let words: Vec<_> = words::from_ch_ind::<String>("abcπβγ xyz".char_indices())
+        .map(|w| (w.buf, w.range))
+        .collect();
+    assert_eq!(words, [("abc".into(), 0..3), ("xyz".into(), 10..13)]);
+}
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_all_scripts() {
+    use alphabet_detector::*;
+    use strum::IntoEnumIterator;
+
+    assert!(UcdScript::iter().all(UcdScript::enabled));
+    assert!(Script::iter().all(Script::enabled));
+    assert_eq!(UcdScript::find('π'), UcdScript::Greek);
+    assert_eq!(
+        script_char_to_slangs(UcdScript::Greek, 'π'),
+        [ScriptLanguage::Greek]
+    );
+
+    let words: Vec<_> = words::from_ch_ind::<String>("abcπβγ xyz".char_indices())
+        .map(|w| (w.buf, w.range))
+        .collect();
+    assert_eq!(
+        words,
+        [
+            ("abc".into(), 0..3),
+            ("πβγ".into(), 3..9),
+            ("xyz".into(), 10..13)
+        ]
+    );
+}
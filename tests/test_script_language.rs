@@ -1,5 +1,8 @@
 use ahash::AHashSet;
-use alphabet_detector::{script_char_to_slangs, Language, Script, ScriptLanguage, UcdScript};
+use alphabet_detector::{
+    script_char_to_slangs, script_char_to_tiered_slangs, Language, LetterTier, Script,
+    ScriptLanguage, UcdScript,
+};
 use strum::{EnumCount, IntoEnumIterator};
 use ScriptLanguage::*;
 
@@ -49,6 +52,7 @@ fn test_correct_map_to_script() {
         assert!(
             ScriptLanguage::all_with_script(ucd).contains(&slang)
                 || script_char_to_slangs(ucd, char::default()).contains(&slang) // Language::Unknown
+                || ScriptLanguage::unknown(script) == Some(slang)
                 || Language::from(slang) == Language::Math, // Skip `Language::Math`
             "ScriptLanguage {slang:?} wrong script: {script:?}",
         );
@@ -100,3 +104,191 @@ fn test_deserialize() {
     let deserialized = serde_json::from_str::<ScriptLanguage>("\"engLatn\"").unwrap();
     assert_eq!(deserialized, English);
 }
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_display_name() {
+    assert_eq!(Serbian.display_name(), "Serbian (Cyrillic)");
+    assert_eq!(
+        ChineseMandarinTraditional.display_name(),
+        "Mandarin Chinese (Traditional Han)"
+    );
+    assert_eq!(PunjabiEasternShahmukhi.display_name(), "Punjabi (Arabic)");
+}
+
+#[cfg(all(feature = "script_cyrillic", feature = "script_greek"))]
+#[test]
+fn test_alphabet() {
+    let en = ScriptLanguage::English.alphabet().unwrap();
+    assert_eq!(en.len(), 52);
+    assert_eq!(&en[..4], ['A', 'a', 'B', 'b']);
+    assert!(ScriptLanguage::Portuguese.alphabet_contains('ã'));
+    assert!(!ScriptLanguage::English.alphabet_contains('ã'));
+    assert!(ScriptLanguage::Ukrainian.alphabet_contains('ї'));
+    assert!(!ScriptLanguage::Russian.alphabet_contains('ї'));
+
+    assert!(ScriptLanguage::Greek.alphabet().is_none());
+    assert!(ScriptLanguage::UnknownLatin.alphabet().is_none());
+    assert!(ScriptLanguage::Greek.alphabet_contains('α'));
+    assert!(!ScriptLanguage::Greek.alphabet_contains('a'));
+}
+
+#[test]
+fn test_alphabet_detected() {
+    for slang in ScriptLanguage::iter() {
+        for &ch in slang.alphabet().into_iter().flatten() {
+            let script = UcdScript::find(ch);
+            if matches!(script, UcdScript::Common | UcdScript::Inherited) {
+                continue;
+            }
+            // extended and loanword letters are looked up separately
+            let detected = if slang.letter_tier(ch) == Some(LetterTier::Core) {
+                script_char_to_slangs(script, ch).contains(&slang)
+            } else {
+                script_char_to_tiered_slangs(script, ch)
+                    .iter()
+                    .any(|&(l, _)| l == slang)
+            };
+            assert!(detected, "{slang:?} {ch:?}");
+        }
+    }
+}
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_alphabet_verification() {
+    use alphabet_detector::Verification;
+
+    assert_eq!(English.alphabet_verification(), Verification::NativeSpeaker);
+    assert_eq!(Ukrainian.alphabet_verification(), Verification::Internet);
+    assert_eq!(Belarusian.alphabet_verification(), Verification::Unverified);
+    // doubtful `Internet?`
+    assert_eq!(AcehneseJawi.alphabet_verification(), Verification::Internet);
+    assert!(AcehneseJawi.alphabet_verification_doubtful());
+    assert!(!Ukrainian.alphabet_verification_doubtful());
+    assert_eq!(Greek.alphabet_verification(), Verification::WholeScript);
+    assert_eq!(
+        UnknownLatin.alphabet_verification(),
+        Verification::WholeScript
+    );
+
+    for slang in ScriptLanguage::iter() {
+        let whole_script = slang.alphabet_verification() == Verification::WholeScript;
+        assert_eq!(whole_script, slang.alphabet().is_none(), "{slang:?}");
+        if whole_script {
+            // detected by any not listed letter of its script
+            let fallback = script_char_to_slangs(UcdScript::from(slang), char::default());
+            assert!(fallback.contains(&slang), "{slang:?}");
+            assert!(!slang.alphabet_verification_doubtful());
+        }
+        assert!(slang
+            .alphabet_sources()
+            .iter()
+            .all(|s| s.starts_with("https://")));
+    }
+}
+
+#[cfg(all(feature = "script_cyrillic", feature = "script_greek"))]
+#[test]
+fn test_down_weight_unverified() {
+    use alphabet_detector::{down_weight_unverified, slang_arr_default, Verification};
+
+    let mut langs_cnt = slang_arr_default::<u32>();
+    langs_cnt[English as usize] = 10;
+    langs_cnt[Belarusian as usize] = 10;
+    langs_cnt[Greek as usize] = 10;
+    let langs_cnt = down_weight_unverified::<50>(langs_cnt, Verification::Internet);
+    assert_eq!(langs_cnt[English as usize], 10);
+    assert_eq!(langs_cnt[Belarusian as usize], 5);
+    assert_eq!(langs_cnt[Greek as usize], 10);
+}
+
+#[cfg(feature = "script_arabic")]
+#[test]
+fn test_letter_tier() {
+    use alphabet_detector::{fulltext_counts, fulltext_counts_with, FulltextOptions};
+
+    assert_eq!(ArabicEgyptian.letter_tier('ب'), Some(LetterTier::Core));
+    assert_eq!(ArabicEgyptian.letter_tier('ڤ'), Some(LetterTier::Loanword));
+    assert_eq!(Arabic.letter_tier('ڤ'), None);
+    assert!(script_char_to_tiered_slangs(UcdScript::Arabic, 'ڤ')
+        .contains(&(ArabicEgyptian, LetterTier::Loanword)));
+
+    // loanword letter isn't counted by default
+    let langs_cnt = fulltext_counts("ڤيلا".char_indices());
+    assert_eq!(langs_cnt[ArabicEgyptian as usize], 3);
+
+    // but counts less with tier weights
+    let options = FulltextOptions::new().tier_weights(true);
+    let (langs_cnt, _) = fulltext_counts_with("ڤيلا".char_indices(), options);
+    let core = LetterTier::Core.weight();
+    assert_eq!(langs_cnt[ArabicEgyptian as usize], 3 * core + 1);
+    assert_eq!(
+        fulltext_counts_with("بيت".char_indices(), options).0[ArabicEgyptian as usize],
+        3 * core
+    );
+}
+
+#[test]
+fn test_letter_tier_latin() {
+    assert_eq!(Cebuano.letter_tier('c'), Some(LetterTier::Loanword));
+    assert_eq!(Icelandic.letter_tier('z'), Some(LetterTier::Extended));
+    assert_eq!(Maori.letter_tier('ḵ'), Some(LetterTier::Extended));
+    assert_eq!(Maori.letter_tier('k'), Some(LetterTier::Core));
+    assert_eq!(English.letter_tier('ñ'), None);
+
+    // loanword letters are left out of the default lookup
+    let langs = script_char_to_slangs(UcdScript::Latin, 'w');
+    assert!(langs.contains(&English));
+    assert!(!langs.contains(&Galician));
+    assert!(script_char_to_tiered_slangs(UcdScript::Latin, 'w')
+        .contains(&(Galician, LetterTier::Loanword)));
+}
+
+#[cfg(feature = "all_scripts")]
+#[test]
+fn test_unknown() {
+    assert_eq!(ScriptLanguage::unknown(Script::Latin), Some(UnknownLatin));
+    assert_eq!(ScriptLanguage::unknown(Script::Batak), None);
+    assert_eq!(ScriptLanguage::unknown(Script::Armenian), None);
+    for slang in ScriptLanguage::iter() {
+        let (lang, script) = slang.into_parts();
+        if lang == Language::Unknown {
+            assert_eq!(ScriptLanguage::unknown(script), Some(slang));
+        }
+    }
+    // `Unknown` variant exists only for the scripts with a fallback
+    for script in Script::iter() {
+        let fallback = script_char_to_slangs(script.into(), char::default());
+        assert_eq!(
+            ScriptLanguage::unknown(script).is_some(),
+            fallback
+                .iter()
+                .any(|&sl| Language::from(sl) == Language::Unknown),
+            "{script:?}",
+        );
+    }
+
+    // letters, which no alphabet claims
+    assert_eq!(
+        script_char_to_slangs(UcdScript::Arabic, 'ݐ'),
+        &[UnknownArabic]
+    );
+    assert_eq!(
+        script_char_to_slangs(UcdScript::Hebrew, '׳'),
+        &[UnknownHebrew]
+    );
+    // final letter forms
+    for ch in ['ך', 'ם', 'ן', 'ף', 'ץ'] {
+        assert_eq!(
+            script_char_to_slangs(UcdScript::Hebrew, ch),
+            &[Hebrew, YiddishEastern]
+        );
+    }
+
+    // combining marks are shared by the alphabets
+    let matra = script_char_to_slangs(UcdScript::Devanagari, '\u{948}');
+    assert!(matra.contains(&Hindi) && !matra.contains(&UnknownDevanagari));
+    let mark = script_char_to_slangs(UcdScript::Arabic, '\u{6e1}');
+    assert!(mark.contains(&Urdu) && !mark.contains(&UnknownArabic));
+}
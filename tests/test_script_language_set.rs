@@ -0,0 +1,92 @@
+#![cfg(all(feature = "script_latin", feature = "script_cyrillic"))]
+
+use alphabet_detector::*;
+
+const CYRL: ScriptLanguageSet = ScriptLanguageSet::from_slice(&[
+    ScriptLanguage::Russian,
+    ScriptLanguage::Ukrainian,
+    ScriptLanguage::Belarusian,
+]);
+
+#[test]
+fn test_algebra() {
+    let east: ScriptLanguageSet = [ScriptLanguage::Russian, ScriptLanguage::Ukrainian]
+        .into_iter()
+        .collect();
+    let other = ScriptLanguageSet::from_slice(&[ScriptLanguage::Russian, ScriptLanguage::English]);
+
+    assert_eq!(CYRL.len(), 3);
+    assert!(east.is_subset(&CYRL));
+    assert_eq!((CYRL | other).len(), 4);
+    assert_eq!(
+        (CYRL & other).iter().collect::<Vec<_>>(),
+        [ScriptLanguage::Russian]
+    );
+    assert_eq!(
+        CYRL - east,
+        ScriptLanguageSet::from(ScriptLanguage::Belarusian)
+    );
+    assert_eq!((CYRL ^ other).len(), 3);
+    assert!((CYRL - east).is_disjoint(&east));
+    assert_eq!((!CYRL).len(), ScriptLanguage::COUNT - 3);
+    assert_eq!(ScriptLanguageSet::ALL.len(), ScriptLanguage::COUNT);
+    assert!(ScriptLanguageSet::new().is_empty());
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut set = ScriptLanguageSet::EMPTY;
+    let last = ScriptLanguage::iter().next_back().unwrap();
+    assert!(set.insert(last));
+    assert!(!set.insert(last));
+    assert!(set.contains(last));
+    assert_eq!(set.first(), Some(last));
+    assert!(set.remove(last));
+    assert!(!set.remove(last));
+    assert_eq!(set.first(), None);
+}
+
+#[test]
+fn test_iter_ordered() {
+    let set: ScriptLanguageSet = ScriptLanguage::iter().rev().step_by(3).collect();
+    let mut expected: Vec<_> = ScriptLanguage::iter().rev().step_by(3).collect();
+    expected.reverse();
+    assert_eq!(set.iter().len(), expected.len());
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_serde() {
+    let json = serde_json::to_string(&CYRL).unwrap();
+    let expected = format!(
+        "[{}]",
+        CYRL.iter()
+            .map(|sl| format!("\"{}\"", sl.into_str()))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    assert_eq!(json, expected);
+    assert_eq!(
+        serde_json::from_str::<ScriptLanguageSet>(&json).unwrap(),
+        CYRL
+    );
+}
+
+#[test]
+fn test_script_char_to_slangs_set() {
+    let set = script_char_to_slangs_set(UcdScript::Cyrillic, 'ї');
+    let slangs = script_char_to_slangs(UcdScript::Cyrillic, 'ї');
+    assert_eq!(set.len(), slangs.len());
+    assert!(slangs.iter().all(|&sl| set.contains(sl)));
+}
+
+#[test]
+fn test_word_langs_set() {
+    let word = words::from_ch_ind::<String>("ї".char_indices())
+        .next()
+        .unwrap();
+    assert_eq!(
+        word.langs_set(),
+        script_char_to_slangs_set(UcdScript::Cyrillic, 'ї')
+    );
+}
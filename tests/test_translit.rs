@@ -0,0 +1,193 @@
+#![cfg(all(feature = "script_latin", feature = "script_cyrillic"))]
+
+use alphabet_detector::{
+    translit, Language, Script, ScriptLanguage, TranslitError, Transliterator,
+};
+use rstest::*;
+
+#[rstest(
+    slang,
+    to,
+    text,
+    expected,
+    case(ScriptLanguage::Serbian, Script::Latin, "Љубав", "Ljubav"),
+    case(ScriptLanguage::Serbian, Script::Latin, "ЉУБАВ", "LJUBAV"),
+    case(ScriptLanguage::Serbian, Script::Latin, "ПРИЈАТЕЉ", "PRIJATELJ"),
+    case(ScriptLanguage::Serbian, Script::Latin, "Џеп и џем", "Džep i džem"),
+    case(ScriptLanguage::Serbian, Script::Latin, "Ђорђе, 12!", "Đorđe, 12!"),
+    case(
+        ScriptLanguage::AzerbaijaniNorth,
+        Script::Cyrillic,
+        "Azərbaycan",
+        "Азәрбајҹан"
+    ),
+    case(ScriptLanguage::AzerbaijaniNorth, Script::Cyrillic, "İraq", "Ираг"),
+    case(ScriptLanguage::AzerbaijaniNorth, Script::Cyrillic, "Işıq", "Ышыг"),
+    case(ScriptLanguage::SerbianLatin, Script::Cyrillic, "Ljubav", "Љубав"),
+    case(
+        ScriptLanguage::UzbekNorthern,
+        Script::Cyrillic,
+        "Oʻzbekiston",
+        "Ўзбекистон"
+    ),
+    case(
+        ScriptLanguage::UzbekNorthern,
+        Script::Cyrillic,
+        "O'zbekiston",
+        "Ўзбекистон"
+    ),
+    case(ScriptLanguage::UzbekNorthern, Script::Cyrillic, "shahar", "шаҳар"),
+    case(ScriptLanguage::UzbekNorthern, Script::Cyrillic, "Isʼhoq", "Исҳоқ"),
+    case(ScriptLanguage::UzbekNorthern, Script::Cyrillic, "CHOʻL", "ЧЎЛ"),
+    case(ScriptLanguage::Kazakh, Script::Latin, "Қазақ тілі", "Qazaq tılı"),
+    case(ScriptLanguage::Kazakh, Script::Latin, "Шымкент", "Şymkent"),
+    case(ScriptLanguage::Kazakh, Script::Latin, "Әлия", "Äliia")
+)]
+fn test_translit(slang: ScriptLanguage, to: Script, text: &str, expected: &str) {
+    let tr = Transliterator::from_slang(slang, to).unwrap();
+    assert_eq!(tr.translit(text), expected);
+    assert_eq!(translit::translit(slang, to, text).unwrap(), expected);
+}
+
+#[rstest(
+    lang,
+    from,
+    to,
+    text,
+    expected,
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "Ljubav", "Љубав"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "LJUBAV", "ЉУБАВ"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "NJegoš", "Његош"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "DŽEP", "ЏЕП"),
+    case(
+        Language::AzerbaijaniNorth,
+        Script::Cyrillic,
+        Script::Latin,
+        "Азәрбајҹан",
+        "Azərbaycan"
+    ),
+    case(
+        Language::UzbekNorthern,
+        Script::Cyrillic,
+        Script::Latin,
+        "Чўл",
+        "Choʻl"
+    ),
+    case(Language::Kazakh, Script::Cyrillic, Script::Latin, "Алматы", "Almaty")
+)]
+fn test_translit_reverse(lang: Language, from: Script, to: Script, text: &str, expected: &str) {
+    let tr = Transliterator::new(lang, from, to).unwrap();
+    assert_eq!(tr.translit(text), expected);
+    assert_eq!(tr.reverse().translit(expected), text.replace("NJ", "Nj"));
+}
+
+#[rstest(
+    slang,
+    to,
+    case(ScriptLanguage::English, Script::Cyrillic),
+    case(ScriptLanguage::Serbian, Script::Cyrillic),
+    case(ScriptLanguage::Kazakh, Script::Arabic),
+    case(ScriptLanguage::AzerbaijaniNorth, Script::Arabic)
+)]
+fn test_translit_none(slang: ScriptLanguage, to: Script) {
+    assert_eq!(
+        Transliterator::from_slang(slang, to).unwrap_err(),
+        TranslitError::NoTable
+    );
+    assert_eq!(
+        translit::translit(slang, to, "text"),
+        Err(TranslitError::NoTable)
+    );
+}
+
+#[test]
+fn test_translit_not_included() {
+    let (lang, gurmukhi, shahmukhi) = (Language::PunjabiEastern, Script::Gurmukhi, Script::Arabic);
+    assert_eq!(
+        Transliterator::new(lang, gurmukhi, shahmukhi).unwrap_err(),
+        TranslitError::NotIncluded
+    );
+    assert_eq!(
+        Transliterator::new(lang, shahmukhi, gurmukhi).unwrap_err(),
+        TranslitError::NotIncluded
+    );
+    assert_eq!(
+        TranslitError::NotIncluded.to_string(),
+        "transliteration table is not included yet"
+    );
+}
+
+#[test]
+fn test_translit_all() {
+    for tr in Transliterator::all() {
+        assert_ne!(tr.from(), tr.to());
+        let back = tr.reverse();
+        assert_eq!((back.from(), back.to()), (tr.to(), tr.from()));
+        assert_eq!(
+            Transliterator::new(tr.language(), tr.from(), tr.to()).map(|t| (t.from(), t.to())),
+            Ok((tr.from(), tr.to()))
+        );
+
+        // every letter of the source script round trips,
+        // Kazakh Cyrillic letters without a Latin counterpart are lost
+        let slang = ScriptLanguage::from_parts((tr.language(), tr.from()));
+        let Some(slang) = slang.filter(|&sl| sl != ScriptLanguage::Kazakh) else {
+            continue;
+        };
+        for ch in slang.alphabet().unwrap() {
+            let s = ch.to_string();
+            assert_eq!(back.translit(&tr.translit(&s)), s, "{tr:?} {ch}");
+        }
+    }
+}
+
+#[test]
+fn test_translit_to_slang() {
+    let tr = Transliterator::from_slang(ScriptLanguage::Serbian, Script::Latin).unwrap();
+    assert_eq!(tr.to_slang(), Some(ScriptLanguage::SerbianLatin));
+    assert_eq!(tr.reverse().to_slang(), Some(ScriptLanguage::Serbian));
+}
+
+#[rstest(
+    lang,
+    from,
+    to,
+    text,
+    expected,
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "injekcija", "инјекција"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "Injekcija", "Инјекција"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "KONJUGACIJA", "КОНЈУГАЦИЈА"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "konj", "коњ"),
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "nadživeti", "надживети"),
+    case(Language::Serbian, Script::Cyrillic, Script::Latin, "инјекција", "injekcija"),
+    // not in the exception list, converted as a digraph
+    case(Language::Serbian, Script::Latin, Script::Cyrillic, "injunkcija", "ињункција"),
+    case(Language::UzbekNorthern, Script::Latin, Script::Cyrillic, "yoʻl", "йўл"),
+    case(Language::UzbekNorthern, Script::Latin, Script::Cyrillic, "yo'l", "йўл"),
+    case(Language::UzbekNorthern, Script::Latin, Script::Cyrillic, "yosh", "ёш")
+)]
+fn test_translit_digraph_exceptions(
+    lang: Language,
+    from: Script,
+    to: Script,
+    text: &str,
+    expected: &str,
+) {
+    let tr = Transliterator::new(lang, from, to).unwrap();
+    assert_eq!(tr.translit(text), expected);
+}
+
+#[rstest(
+    lang,
+    text,
+    expected,
+    case(Language::UzbekNorthern, "Эшик", "Eshik"),
+    case(Language::UzbekNorthern, "цирк", "tsirk"),
+    case(Language::Kazakh, "Һиджра", "Hidjra"),
+    case(Language::Kazakh, "ұйым", "ūiym")
+)]
+fn test_translit_lossy(lang: Language, text: &str, expected: &str) {
+    let tr = Transliterator::new(lang, Script::Cyrillic, Script::Latin).unwrap();
+    assert_eq!(tr.translit(text), expected);
+    assert_ne!(tr.reverse().translit(expected), text);
+}
@@ -67,3 +67,44 @@ fn test_deserialize() {
     let deserialized = serde_json::from_str::<UcdScript>("\"Latn\"").unwrap();
     assert_eq!(deserialized, Latin);
 }
+
+#[test]
+fn test_properties() {
+    use alphabet_detector::{ScriptDirection, ScriptType};
+
+    assert_eq!(UcdScript::Latin.direction(), ScriptDirection::LeftToRight);
+    assert_eq!(UcdScript::Arabic.direction(), ScriptDirection::RightToLeft);
+    assert_eq!(UcdScript::Hebrew.direction(), ScriptDirection::RightToLeft);
+    assert_eq!(UcdScript::Common.direction(), ScriptDirection::Neutral);
+
+    assert!(UcdScript::Cyrillic.is_cased());
+    assert!(!UcdScript::Arabic.is_cased());
+
+    assert!(UcdScript::Latin.uses_word_spaces());
+    assert!(!UcdScript::Thai.uses_word_spaces());
+    assert!(!UcdScript::Han.uses_word_spaces());
+
+    assert!(UcdScript::Runic.is_historic());
+    assert!(!UcdScript::Devanagari.is_historic());
+
+    assert_eq!(UcdScript::Latin.script_type(), Some(ScriptType::Alphabet));
+    assert_eq!(UcdScript::Hebrew.script_type(), Some(ScriptType::Abjad));
+    assert_eq!(
+        UcdScript::Devanagari.script_type(),
+        Some(ScriptType::Abugida)
+    );
+    assert_eq!(
+        UcdScript::Katakana.script_type(),
+        Some(ScriptType::Syllabary)
+    );
+    assert_eq!(UcdScript::Han.script_type(), Some(ScriptType::Logographic));
+    assert_eq!(UcdScript::Inherited.script_type(), None);
+
+    for script in UcdScript::iter() {
+        assert_eq!(
+            script.script_type().is_none(),
+            matches!(script, Common | Inherited | SignWriting),
+            "{script:?}"
+        );
+    }
+}
@@ -1,3 +1,6 @@
+//! Cases span most of the scripts
+#![cfg(feature = "all_scripts")]
+
 mod shared;
 #[allow(unused_imports)]
 use shared::*;
@@ -15,7 +18,9 @@ use rstest::*;
     case(Esperanto, "monaĥinoj"),
     case(Esperanto, "nesufiĉecon"),
     case(Esperanto, "ŝpinante"),
+    case::math_script(Esperanto, "𝓂𝑜𝓃𝒶ĥ𝒾𝓃𝑜𝒿"),
     case(German, "groß"),
+    case::fullwidth(German, "ＧＲＯß"),
     case(Hungarian, "fekvő"),
     case(Hungarian, "meggyűrűzni"),
     case(Icelandic, "þagnarskyldu"),
@@ -23,6 +28,7 @@ use rstest::*;
     case(Japanese, "経済"),
     case(Japanese, "自動販売機"),
     case(Japanese, "関西国際空港"),
+    case::halfwidth(Japanese, "ｶﾞｲｼﾞﾝ"),
     case(Kazakh, "шұрайлы"),
     case(Lao, "\u{e82}\u{ec8}\u{ec9}"),
     case(Macedonian, "ќерка"),
@@ -115,6 +121,8 @@ fn test_word_uniq(expected_language: ScriptLanguage, word: &str) {
     expected_language,
     word,
     case::eng_like(Slovak, "šefčovič's"),
+    case::math_bold(English, "𝐁𝐨𝐥𝐝"),
+    case::circled(English, "ⓒⓘⓡⓒⓛⓔⓓ"),
     case(AlbanianTosk, "hashemidëve"),
     case(Arabic, "والموضوع"),
     case(AzerbaijaniNorth, "məhərrəm"),
@@ -182,6 +190,11 @@ fn test_word_uniq(expected_language: ScriptLanguage, word: &str) {
     case(Macedonian, "набљудувач"),
     case(Macedonian, "ректасцензија"),
     case(Macedonian, "џамиите"),
+    case(Hebrew, "שלום"),
+    case(Hebrew, "ארץ"),
+    case(Hindi, "मैं"),
+    case(Hindi, "हैं"),
+    case(Hindi, "में"),
     case(Marathi, "मिळते"),
     case(MongolianKhalkha, "дөхөж"),
     case(MongolianKhalkha, "үндсэн"),
@@ -201,6 +214,7 @@ fn test_word_uniq(expected_language: ScriptLanguage, word: &str) {
     case(Russian, "огнём"),
     case(Russian, "сопротивление"),
     case(Russian, "этот"),
+    case(SerbianLatin, "đačić"),
     case(Slovak, "rozohňuje"),
     case(Spanish, "¿que?"),
     case(Spanish, "años"),
@@ -249,7 +263,12 @@ fn test_word_multiple_langs(expected_language: ScriptLanguage, word: &str) {
 #[rstest(
     unexpected_language,
     word,
-    case(UnknownLatin, "indi\u{307}vi\u{307}si\u{307}bi\u{307}li\u{307}ty")
+    case(UnknownLatin, "indi\u{307}vi\u{307}si\u{307}bi\u{307}li\u{307}ty"),
+    case(UnknownDevanagari, "मैं"),
+    case(UnknownDevanagari, "हैं"),
+    case(UnknownDevanagari, "में"),
+    case(UnknownHebrew, "שלום"),
+    case(UnknownHebrew, "ארץ")
 )]
 fn test_word_not_langs(unexpected_language: ScriptLanguage, word: &str) {
     let found_words: Vec<_> = words::from_ch_ind::<String>(word.char_indices()).collect();